@@ -0,0 +1,378 @@
+// Minimal ANSI/VT parser: SGR sequences become span styles; every other escape or control
+// sequence (cursor movement, OSC titles/hyperlinks, charset switches, ...) is dropped.
+// Input is decoded lossily, so invalid UTF-8 never fails the whole append.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use std::iter::Peekable;
+use std::str::Chars;
+use unicode_width::UnicodeWidthChar;
+
+const TAB_STOP: usize = 8;
+
+struct LineBuilder {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    text: String,
+    style: Style,
+    col: usize,
+}
+
+impl LineBuilder {
+    fn flush_span(&mut self) {
+        if !self.text.is_empty() {
+            self.spans
+                .push(Span::styled(std::mem::take(&mut self.text), self.style));
+        }
+    }
+
+    fn set_style(&mut self, style: Style) {
+        if style != self.style {
+            self.flush_span();
+            self.style = style;
+        }
+    }
+
+    // `col` counts display columns so tab stops line up after wide or combining characters.
+    fn push(&mut self, ch: char) {
+        self.text.push(ch);
+        self.col += ch.width().unwrap_or(0);
+    }
+
+    fn end_line(&mut self) {
+        self.flush_span();
+        self.lines.push(Line::from(std::mem::take(&mut self.spans)));
+        self.col = 0;
+    }
+}
+
+// Raw byte buffer from the host (need not be NUL-terminated).
+pub(crate) fn ansi_lines_from_ffi(bytes: *const u8, len: usize) -> Option<Vec<Line<'static>>> {
+    if bytes.is_null() || len == 0 {
+        return None;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_text_len(len) {
+            return None;
+        }
+    }
+    Some(parse_ansi(unsafe {
+        std::slice::from_raw_parts(bytes, len)
+    }))
+}
+
+// Splits on '\n' (a trailing newline does not produce an extra empty line). SGR state carries
+// across lines within one call and starts from the default style on every call.
+pub(crate) fn parse_ansi(bytes: &[u8]) -> Vec<Line<'static>> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut b = LineBuilder {
+        lines: Vec::new(),
+        spans: Vec::new(),
+        text: String::new(),
+        style: Style::default(),
+        col: 0,
+    };
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            // the byte after ESC is only taken when it belongs to the sequence, so a stray ESC
+            // never eats a newline or the start of the next sequence
+            '\x1b' => match chars.peek().copied() {
+                Some('[') => {
+                    chars.next();
+                    csi(&mut chars, &mut b);
+                }
+                Some(']') | Some('P') | Some('X') | Some('^') | Some('_') => {
+                    chars.next();
+                    skip_string(&mut chars);
+                }
+                // nF escapes (e.g. ESC ( B): intermediates then one final byte
+                Some('\x20'..='\x2f') => {
+                    while chars.next_if(|c| ('\x20'..='\x2f').contains(c)).is_some() {}
+                    chars.next_if(|c| ('\x30'..='\x7e').contains(c));
+                }
+                // two-byte escapes (ESC 7, ESC =, ESC M, ...)
+                Some('\x30'..='\x7e') => {
+                    chars.next();
+                }
+                _ => {}
+            },
+            '\u{9b}' => csi(&mut chars, &mut b),
+            '\u{9d}' => skip_string(&mut chars),
+            '\n' => b.end_line(),
+            '\t' => {
+                let n = TAB_STOP - b.col % TAB_STOP;
+                for _ in 0..n {
+                    b.push(' ');
+                }
+            }
+            c if c.is_control() => {}
+            c => b.push(c),
+        }
+    }
+    if !b.text.is_empty() || !b.spans.is_empty() {
+        b.end_line();
+    }
+    b.lines
+}
+
+fn csi(chars: &mut Peekable<Chars>, b: &mut LineBuilder) {
+    let mut params = String::new();
+    let mut intermediate = false;
+    while let Some(&c) = chars.peek() {
+        match c {
+            '\x30'..='\x3f' => params.push(c),
+            '\x20'..='\x2f' => intermediate = true,
+            '\x40'..='\x7e' => {
+                chars.next();
+                if c == 'm' && !intermediate {
+                    let style = apply_sgr(b.style, &params);
+                    b.set_style(style);
+                }
+                return;
+            }
+            // malformed sequence: abandon it, leaving the aborting character to the caller
+            _ => return,
+        }
+        chars.next();
+    }
+}
+
+// OSC/DCS/PM/APC: terminated by BEL or ST (ESC \).
+fn skip_string(chars: &mut Peekable<Chars>) {
+    while let Some(c) = chars.next() {
+        match c {
+            '\x07' | '\u{9c}' => return,
+            '\x1b' => {
+                if chars.peek() == Some(&'\\') {
+                    chars.next();
+                }
+                return;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn named(idx: u16) -> Color {
+    match idx {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+// Extended color after 38/48/58: `5;n` (256 palette) or `2;r;g;b`. Colon sub-parameters
+// (`38:2::r:g:b`, with optional colorspace id) arrive in `sub`.
+fn extended_color(sub: &[u16], rest: &mut std::slice::Iter<Vec<u16>>) -> Option<Color> {
+    if sub.len() > 1 {
+        return match sub[1] {
+            5 => sub.get(2).map(|&n| Color::Indexed(n as u8)),
+            2 => {
+                let rgb = if sub.len() >= 6 {
+                    &sub[3..6]
+                } else {
+                    sub.get(2..5)?
+                };
+                Some(Color::Rgb(rgb[0] as u8, rgb[1] as u8, rgb[2] as u8))
+            }
+            _ => None,
+        };
+    }
+    let mut next = || rest.next().and_then(|p| p.first().copied());
+    match next()? {
+        5 => next().map(|n| Color::Indexed(n as u8)),
+        2 => {
+            let (r, g, b) = (next()?, next()?, next()?);
+            Some(Color::Rgb(r as u8, g as u8, b as u8))
+        }
+        _ => None,
+    }
+}
+
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    // Private-mode sequences (e.g. `ESC[?25m`) are not SGR.
+    if params.starts_with(['<', '=', '>', '?']) {
+        return style;
+    }
+    let groups: Vec<Vec<u16>> = params
+        .split(';')
+        .map(|g| {
+            g.split(':')
+                .map(|n| n.parse::<u16>().unwrap_or(0))
+                .collect()
+        })
+        .collect();
+    let mut it = groups.iter();
+    while let Some(g) = it.next() {
+        let code = g.first().copied().unwrap_or(0);
+        style = match code {
+            0 => Style::default(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => match g.get(1) {
+                Some(0) => style.remove_modifier(Modifier::UNDERLINED),
+                _ => style.add_modifier(Modifier::UNDERLINED),
+            },
+            5 => style.add_modifier(Modifier::SLOW_BLINK),
+            6 => style.add_modifier(Modifier::RAPID_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            8 => style.add_modifier(Modifier::HIDDEN),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            21 => style.add_modifier(Modifier::UNDERLINED),
+            22 => style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style.remove_modifier(Modifier::ITALIC),
+            24 => style.remove_modifier(Modifier::UNDERLINED),
+            25 => style.remove_modifier(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27 => style.remove_modifier(Modifier::REVERSED),
+            28 => style.remove_modifier(Modifier::HIDDEN),
+            29 => style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37 => style.fg(named(code - 30)),
+            38 => match extended_color(g, &mut it) {
+                Some(c) => style.fg(c),
+                None => style,
+            },
+            39 => Style { fg: None, ..style },
+            40..=47 => style.bg(named(code - 40)),
+            48 => match extended_color(g, &mut it) {
+                Some(c) => style.bg(c),
+                None => style,
+            },
+            49 => Style { bg: None, ..style },
+            58 => match extended_color(g, &mut it) {
+                Some(c) => style.underline_color(c),
+                None => style,
+            },
+            59 => Style {
+                underline_color: None,
+                ..style
+            },
+            90..=97 => style.fg(named(code - 90 + 8)),
+            100..=107 => style.bg(named(code - 100 + 8)),
+            _ => style,
+        };
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(line: &Line) -> Vec<(String, Style)> {
+        line.spans
+            .iter()
+            .map(|s| (s.content.to_string(), s.style))
+            .collect()
+    }
+
+    #[test]
+    fn sgr_colors_and_modifiers() {
+        let lines = parse_ansi(b"\x1b[1;31merror\x1b[0m: \x1b[4;92mok\x1b[24m!");
+        assert_eq!(lines.len(), 1);
+        let red = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        let green = Style::default().fg(Color::LightGreen);
+        assert_eq!(
+            spans(&lines[0]),
+            vec![
+                ("error".to_string(), red),
+                (": ".to_string(), Style::default()),
+                ("ok".to_string(), green.add_modifier(Modifier::UNDERLINED)),
+                (
+                    "!".to_string(),
+                    green
+                        .add_modifier(Modifier::UNDERLINED)
+                        .remove_modifier(Modifier::UNDERLINED)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_colors_in_both_forms() {
+        let lines = parse_ansi(b"\x1b[38;5;208ma\x1b[48;2;1;2;3mb\x1b[38:2::4:5:6;58:5:9mc");
+        let st: Vec<Style> = lines[0].spans.iter().map(|s| s.style).collect();
+        assert_eq!(st[0].fg, Some(Color::Indexed(208)));
+        assert_eq!(st[1].bg, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(st[2].fg, Some(Color::Rgb(4, 5, 6)));
+        assert_eq!(st[2].underline_color, Some(Color::Indexed(9)));
+    }
+
+    #[test]
+    fn state_carries_across_lines_but_not_calls() {
+        let lines = parse_ansi(b"\x1b[33ma\nb\x1b[39m\nc\n");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1].spans[0].style.fg, Some(Color::Yellow));
+        assert_eq!(lines[2].spans[0].style.fg, None);
+        let again = parse_ansi(b"d");
+        assert_eq!(again[0].spans[0].style, Style::default());
+    }
+
+    #[test]
+    fn other_sequences_are_dropped() {
+        let src =
+            b"\x1b]0;title\x07\x1b]8;;http://x\x1b\\link\x1b]8;;\x1b\\\x1b(B\x1b[2K\x1b[?25hok\r";
+        let lines = parse_ansi(src);
+        assert_eq!(lines.len(), 1);
+        let text: String = lines[0].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "linkok");
+        assert!(lines[0].spans.iter().all(|s| s.style == Style::default()));
+    }
+
+    #[test]
+    fn tabs_expand_and_invalid_utf8_is_replaced() {
+        let lines = parse_ansi(b"ab\tc\xff");
+        assert_eq!(lines[0].spans[0].content, "ab      c\u{fffd}");
+        assert!(parse_ansi(b"").is_empty());
+    }
+
+    fn texts(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn stray_escape_keeps_the_next_character() {
+        assert_eq!(texts(&parse_ansi(b"a\x1b\nb")), ["a", "b"]);
+        assert_eq!(texts(&parse_ansi(b"a\x1b(\nb")), ["a", "b"]);
+        assert_eq!(texts(&parse_ansi(b"\x1b7ok\x1b8\x1b(Bx")), ["okx"]);
+        let lines = parse_ansi(b"\x1b\x1b[31mred");
+        assert_eq!(texts(&lines), ["red"]);
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn aborted_csi_keeps_the_aborting_character() {
+        assert_eq!(texts(&parse_ansi(b"a\x1b[31\nb")), ["a", "b"]);
+        assert_eq!(texts(&parse_ansi("\x1b[2é!".as_bytes())), ["é!"]);
+        let lines = parse_ansi(b"\x1b[1\x1b[32mg");
+        assert_eq!(lines[0].spans[0].style, Style::default().fg(Color::Green));
+    }
+
+    #[test]
+    fn tabs_stop_by_display_width() {
+        assert_eq!(texts(&parse_ansi("世\tx".as_bytes())), ["世      x"]);
+        assert_eq!(
+            texts(&parse_ansi("e\u{301}\tx".as_bytes())),
+            ["e\u{301}       x"]
+        );
+        assert_eq!(texts(&parse_ansi(b"12345678\tx")), ["12345678        x"]);
+    }
+}
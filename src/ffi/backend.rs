@@ -0,0 +1,198 @@
+// Backend wrapper that downsamples cell colors to what the terminal can show before they are
+// written, so truecolor themes degrade to the nearest 256/16 color (or to no color at all).
+
+use crate::ffi::color::{nearest_indexed, nearest_named, to_rgb};
+use crate::{color_from_u32, color_to_u32, FfiTerminal};
+use ratatui::backend::{Backend, ClearType, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::{Position, Size};
+use ratatui::style::Color;
+use std::io;
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FfiColorMode {
+    // Only valid as an argument: detect from the environment.
+    Auto = 0,
+    TrueColor = 1,
+    Ansi256 = 2,
+    Ansi16 = 3,
+    // Colors dropped, modifiers kept.
+    Mono = 4,
+}
+
+impl FfiColorMode {
+    pub(crate) fn from_u32(v: u32) -> Option<Self> {
+        Some(match v {
+            0 => Self::Auto,
+            1 => Self::TrueColor,
+            2 => Self::Ansi256,
+            3 => Self::Ansi16,
+            4 => Self::Mono,
+            _ => return None,
+        })
+    }
+
+    fn resolved(self) -> Self {
+        match self {
+            Self::Auto => detect_color_mode(),
+            m => m,
+        }
+    }
+}
+
+// `NO_COLOR` (non-empty) wins, then `COLORTERM=truecolor|24bit`, then `TERM`.
+pub(crate) fn detect_color_mode() -> FfiColorMode {
+    let var = |k: &str| std::env::var(k).unwrap_or_default().to_ascii_lowercase();
+    if !var("NO_COLOR").is_empty() {
+        return FfiColorMode::Mono;
+    }
+    let colorterm = var("COLORTERM");
+    if colorterm == "truecolor" || colorterm == "24bit" {
+        return FfiColorMode::TrueColor;
+    }
+    let term = var("TERM");
+    if term == "dumb" {
+        FfiColorMode::Mono
+    } else if term.contains("truecolor") || term.contains("24bit") || term.contains("direct") {
+        FfiColorMode::TrueColor
+    } else if term.contains("256") {
+        FfiColorMode::Ansi256
+    } else if term.is_empty() && cfg!(windows) {
+        // Windows consoles run without TERM and support truecolor since Windows 10.
+        FfiColorMode::TrueColor
+    } else {
+        FfiColorMode::Ansi16
+    }
+}
+
+pub(crate) fn downsample_color(c: Color, mode: FfiColorMode) -> Color {
+    let packed = color_to_u32(c);
+    let mapped = match mode {
+        FfiColorMode::Auto | FfiColorMode::TrueColor => return c,
+        FfiColorMode::Mono => return Color::Reset,
+        FfiColorMode::Ansi256 => match c {
+            Color::Rgb(r, g, b) => 0x4000_0000 | nearest_indexed(r, g, b) as u32,
+            _ => return c,
+        },
+        FfiColorMode::Ansi16 => match c {
+            Color::Indexed(i) if i < 16 => i as u32 + 1,
+            Color::Rgb(..) | Color::Indexed(_) => match to_rgb(packed) {
+                Some((r, g, b)) => nearest_named(r, g, b),
+                None => return c,
+            },
+            _ => return c,
+        },
+    };
+    color_from_u32(mapped).unwrap_or(c)
+}
+
+pub struct ColorModeBackend<B: Backend> {
+    inner: B,
+    pub(crate) mode: FfiColorMode,
+}
+
+impl<B: Backend> ColorModeBackend<B> {
+    pub fn new(inner: B, mode: FfiColorMode) -> Self {
+        Self {
+            inner,
+            mode: mode.resolved(),
+        }
+    }
+}
+
+impl<B: Backend> Backend for ColorModeBackend<B> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        if self.mode == FfiColorMode::TrueColor {
+            return self.inner.draw(content);
+        }
+        let mode = self.mode;
+        let cells: Vec<(u16, u16, Cell)> = content
+            .map(|(x, y, cell)| {
+                let mut cell = cell.clone();
+                cell.fg = downsample_color(cell.fg, mode);
+                cell.bg = downsample_color(cell.bg, mode);
+                cell.underline_color = downsample_color(cell.underline_color, mode);
+                (x, y, cell)
+            })
+            .collect();
+        self.inner.draw(cells.iter().map(|(x, y, c)| (*x, *y, c)))
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        self.inner.append_lines(n)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        self.inner.get_cursor_position()
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.inner.set_cursor_position(position)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.inner.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        self.inner.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.inner.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_detect_color_mode() -> u32 {
+    detect_color_mode() as u32
+}
+
+// `mode` is an `FfiColorMode`; `Auto` re-detects. A change clears the screen so the next draw
+// repaints every cell in the new mode.
+#[no_mangle]
+pub extern "C" fn ratatui_terminal_set_color_mode(term: *mut FfiTerminal, mode: u32) -> bool {
+    if term.is_null() {
+        return false;
+    }
+    let Some(mode) = FfiColorMode::from_u32(mode) else {
+        return false;
+    };
+    let t = unsafe { &mut *term };
+    let mode = mode.resolved();
+    if t.terminal.backend().mode != mode {
+        t.terminal.backend_mut().mode = mode;
+        let _ = t.terminal.clear();
+    }
+    true
+}
+
+// Resolved mode in use (never `Auto`).
+#[no_mangle]
+pub extern "C" fn ratatui_terminal_color_mode(term: *const FfiTerminal) -> u32 {
+    if term.is_null() {
+        return FfiColorMode::Auto as u32;
+    }
+    let t = unsafe { &*term };
+    t.terminal.backend().mode as u32
+}
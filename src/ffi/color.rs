@@ -0,0 +1,509 @@
+// Color helpers over the packed `FfiStyle` encoding: parsing/formatting config strings and
+// HSL-based adjustments for deriving hover/selected variants from a theme color.
+//
+// Named and indexed colors have no fixed RGB value; conversions use the xterm default palette.
+
+use crate::color_u32_from_str;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+const NAMED_RGB: [u32; 16] = [
+    0x000000, 0x800000, 0x008000, 0x808000, 0x000080, 0x800080, 0x008080, 0xc0c0c0, 0x808080,
+    0xff0000, 0x00ff00, 0xffff00, 0x0000ff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+const NAMED: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "gray",
+    "dark-gray",
+    "light-red",
+    "light-green",
+    "light-yellow",
+    "light-blue",
+    "light-magenta",
+    "light-cyan",
+    "white",
+];
+
+// CSS Color Module 4 named colors (the X11 set), sorted for binary search.
+const CSS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+// `name` already lowercased with separators stripped.
+pub(crate) fn css_rgb(name: &str) -> Option<u32> {
+    CSS.binary_search_by(|(n, _)| n.cmp(&name))
+        .ok()
+        .map(|i| CSS[i].1)
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+pub struct FfiColorHsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+fn args<const N: usize>(s: &str, func: &str) -> Option<[f32; N]> {
+    let inner = s
+        .strip_prefix(func)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    let mut out = [0.0; N];
+    let mut parts = inner.split(',');
+    for v in out.iter_mut() {
+        let p = parts.next()?.trim();
+        *v = p.strip_suffix('%').unwrap_or(p).trim().parse().ok()?;
+    }
+    parts.next().is_none().then_some(out)
+}
+
+// Function forms: `rgb(r, g, b)` (0..=255), `hsl(h, s%, l%)`, `indexed(n)`.
+pub(crate) fn parse_function(s: &str) -> Option<u32> {
+    let lower = s.to_ascii_lowercase();
+    if let Some([r, g, b]) = args::<3>(&lower, "rgb") {
+        let ch = |v: f32| (0.0..=255.0).contains(&v).then_some(v.round() as u32);
+        return Some(0x8000_0000 | ch(r)? << 16 | ch(g)? << 8 | ch(b)?);
+    }
+    if let Some([h, s, l]) = args::<3>(&lower, "hsl") {
+        let pct = |v: f32| (0.0..=100.0).contains(&v).then_some(v / 100.0);
+        return Some(hsl_to_u32(h, pct(s)?, pct(l)?));
+    }
+    if let Some([idx]) = args::<1>(&lower, "indexed") {
+        if idx.fract() == 0.0 && (0.0..=255.0).contains(&idx) {
+            return Some(0x4000_0000 | idx as u32);
+        }
+    }
+    None
+}
+
+// Canonical string for a packed color (`light-blue`, `indexed(42)`, `#rrggbb`); `None` for 0.
+pub(crate) fn format_color(c: u32) -> Option<String> {
+    if c & 0x8000_0000 != 0 {
+        return Some(format!("#{:06x}", c & 0xFF_FFFF));
+    }
+    if c & 0x4000_0000 != 0 {
+        return Some(format!("indexed({})", c & 0xFF));
+    }
+    NAMED
+        .get((c as usize).checked_sub(1)?)
+        .map(|n| n.to_string())
+}
+
+pub(crate) fn to_rgb(c: u32) -> Option<(u8, u8, u8)> {
+    let rgb = if c & 0x8000_0000 != 0 {
+        c & 0xFF_FFFF
+    } else if c & 0x4000_0000 != 0 {
+        indexed_rgb((c & 0xFF) as u8)
+    } else {
+        *NAMED_RGB.get((c as usize).checked_sub(1)?)?
+    };
+    Some(((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+}
+
+fn indexed_rgb(i: u8) -> u32 {
+    match i {
+        0..=15 => NAMED_RGB[i as usize],
+        16..=231 => {
+            let i = i as u32 - 16;
+            let level = |v: u32| if v == 0 { 0 } else { 55 + v * 40 };
+            level(i / 36) << 16 | level(i / 6 % 6) << 8 | level(i % 6)
+        }
+        _ => {
+            let v = 8 + (i as u32 - 232) * 10;
+            v << 16 | v << 8 | v
+        }
+    }
+}
+
+fn dist2(a: u32, r: u8, g: u8, b: u8) -> u32 {
+    let d = |x: u32, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a >> 16 & 0xFF, r) + d(a >> 8 & 0xFF, g) + d(a & 0xFF, b)
+}
+
+// Nearest xterm-256 entry from the 6x6x6 cube or the gray ramp (16..=255).
+pub(crate) fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (v as u32 - 35) / 40,
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    let avg = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + (avg.saturating_sub(3) / 10).min(23);
+    let rgb = |i: u32| indexed_rgb(i as u8);
+    if dist2(rgb(gray), r, g, b) < dist2(rgb(cube), r, g, b) {
+        gray as u8
+    } else {
+        cube as u8
+    }
+}
+
+// Nearest of the 16 named colors (packed code 1..=16).
+pub(crate) fn nearest_named(r: u8, g: u8, b: u8) -> u32 {
+    let mut best = (u32::MAX, 0);
+    for (i, &c) in NAMED_RGB.iter().enumerate() {
+        let d = dist2(c, r, g, b);
+        if d < best.0 {
+            best = (d, i);
+        }
+    }
+    best.1 as u32 + 1
+}
+
+pub(crate) fn rgb_to_hsl(r: u8, g: u8, b: u8) -> FfiColorHsl {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+    if d == 0.0 {
+        return FfiColorHsl { h: 0.0, s: 0.0, l };
+    }
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    FfiColorHsl { h: h * 60.0, s, l }
+}
+
+// `h` in degrees (wrapped), `s`/`l` in 0..=1 (clamped); always yields an RGB color.
+pub(crate) fn hsl_to_u32(h: f32, s: f32, l: f32) -> u32 {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let s = s.clamp(0.0, 1.0);
+    let l = l.clamp(0.0, 1.0);
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    let ch = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u32;
+    0x8000_0000 | ch(r) << 16 | ch(g) << 8 | ch(b)
+}
+
+fn adjust_lightness(c: u32, delta: f32) -> u32 {
+    match to_rgb(c) {
+        Some((r, g, b)) => {
+            let hsl = rgb_to_hsl(r, g, b);
+            hsl_to_u32(hsl.h, hsl.s, hsl.l + delta)
+        }
+        None => c,
+    }
+}
+
+// ----- FFI -----
+
+#[no_mangle]
+pub extern "C" fn ratatui_color_parse(text_utf8: *const c_char, out_color: *mut u32) -> bool {
+    if text_utf8.is_null() || out_color.is_null() {
+        return false;
+    }
+    let Ok(s) = unsafe { CStr::from_ptr(text_utf8) }.to_str() else {
+        return false;
+    };
+    match color_u32_from_str(s) {
+        Some(c) => {
+            unsafe {
+                *out_color = c;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+// Inverse of `ratatui_color_parse`; false for 0 (no color). Free with `ratatui_string_free`.
+#[no_mangle]
+pub extern "C" fn ratatui_color_format(color: u32, out_text_utf8: *mut *mut c_char) -> bool {
+    if out_text_utf8.is_null() {
+        return false;
+    }
+    match format_color(color).and_then(|s| CString::new(s).ok()) {
+        Some(cstr) => {
+            unsafe {
+                *out_text_utf8 = cstr.into_raw();
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_color_to_rgb(
+    color: u32,
+    out_r: *mut u8,
+    out_g: *mut u8,
+    out_b: *mut u8,
+) -> bool {
+    if out_r.is_null() || out_g.is_null() || out_b.is_null() {
+        return false;
+    }
+    let Some((r, g, b)) = to_rgb(color) else {
+        return false;
+    };
+    unsafe {
+        *out_r = r;
+        *out_g = g;
+        *out_b = b;
+    }
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_color_hsl(h: f32, s: f32, l: f32) -> u32 {
+    hsl_to_u32(h, s, l)
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_color_to_hsl(color: u32, out_hsl: *mut FfiColorHsl) -> bool {
+    if out_hsl.is_null() {
+        return false;
+    }
+    let Some((r, g, b)) = to_rgb(color) else {
+        return false;
+    };
+    unsafe {
+        *out_hsl = rgb_to_hsl(r, g, b);
+    }
+    true
+}
+
+// Raise/lower HSL lightness by `amount` (0..=1); the result is always RGB. 0 is returned as-is.
+#[no_mangle]
+pub extern "C" fn ratatui_color_lighten(color: u32, amount: f32) -> u32 {
+    adjust_lightness(color, amount)
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_color_darken(color: u32, amount: f32) -> u32 {
+    adjust_lightness(color, -amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RGB: u32 = 0x8000_0000;
+    const IDX: u32 = 0x4000_0000;
+
+    #[test]
+    fn names_hex_and_indices() {
+        assert_eq!(color_u32_from_str("red"), Some(2));
+        assert_eq!(color_u32_from_str(" Light_Blue "), Some(13));
+        assert_eq!(color_u32_from_str("dark-grey"), Some(9));
+        assert_eq!(color_u32_from_str("#1a2B3c"), Some(RGB | 0x1a2b3c));
+        assert_eq!(color_u32_from_str("#f80"), Some(RGB | 0xff8800));
+        assert_eq!(color_u32_from_str("0"), Some(IDX));
+        assert_eq!(color_u32_from_str("255"), Some(IDX | 255));
+        for bad in ["", "#12", "#12345g", "256", "-1", "nocolor"] {
+            assert_eq!(color_u32_from_str(bad), None, "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn css_names_lose_to_ansi_names() {
+        assert_eq!(color_u32_from_str("tomato"), Some(RGB | 0xff6347));
+        assert_eq!(color_u32_from_str("Rebecca-Purple"), Some(RGB | 0x663399));
+        // `green` and `gray` are ANSI, not CSS #008000 / #808080
+        assert_eq!(color_u32_from_str("green"), Some(3));
+        assert_eq!(color_u32_from_str("gray"), Some(8));
+        assert!(CSS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn function_forms() {
+        assert_eq!(parse_function("rgb(255, 128, 0)"), Some(RGB | 0xff8000));
+        assert_eq!(parse_function("RGB (1,2,3)"), Some(RGB | 0x010203));
+        assert_eq!(parse_function("hsl(0, 100%, 50%)"), Some(RGB | 0xff0000));
+        assert_eq!(parse_function("hsl(120,100,25)"), Some(RGB | 0x008000));
+        assert_eq!(parse_function("indexed(42)"), Some(IDX | 42));
+        assert_eq!(color_u32_from_str("rgb(0,0,255)"), Some(RGB | 0x0000ff));
+        for bad in [
+            "rgb(256,0,0)",
+            "rgb(1,2)",
+            "rgb(1,2,3,4)",
+            "rgb(a,b,c)",
+            "hsl(0,101%,50%)",
+            "indexed(1.5)",
+            "indexed(300)",
+            "cmyk(0,0,0,0)",
+        ] {
+            assert_eq!(parse_function(bad), None, "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn format_round_trips() {
+        for src in ["light-blue", "indexed(42)", "#0a0b0c"] {
+            let c = color_u32_from_str(src).unwrap();
+            assert_eq!(format_color(c).as_deref(), Some(src));
+        }
+        assert_eq!(format_color(0), None);
+    }
+}
@@ -6,7 +6,8 @@ macro_rules! ratatui_block_title_alignment_fn {
             if ptr.is_null() {
                 return;
             }
-            let obj = unsafe { &mut *ptr };
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
             let base = obj
                 .block
                 .take()
@@ -34,7 +35,9 @@ macro_rules! ratatui_block_adv_fn {
             if ptr.is_null() {
                 return;
             }
-            let obj = unsafe { &mut *ptr };
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            obj.block_extras = Default::default();
             obj.block = Some(crate::build_block_from_adv(
                 borders_bits,
                 border_type,
@@ -122,7 +125,8 @@ macro_rules! ratatui_block_title_fn {
             if ptr.is_null() {
                 return;
             }
-            let obj = unsafe { &mut *ptr };
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
             let mut block = if show_border {
                 ratatui::widgets::Block::default().borders(ratatui::widgets::Borders::ALL)
             } else {
@@ -135,6 +139,7 @@ macro_rules! ratatui_block_title_fn {
                 }
             }
             obj.block = Some(block);
+            obj.block_extras = Default::default();
         }
     };
 }
@@ -153,7 +158,8 @@ macro_rules! ratatui_block_title_spans_fn {
             if ptr.is_null() {
                 return;
             }
-            let obj = unsafe { &mut *ptr };
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
             let mut block = if show_border {
                 ratatui::widgets::Block::default().borders(ratatui::widgets::Borders::ALL)
             } else {
@@ -163,6 +169,284 @@ macro_rules! ratatui_block_title_spans_fn {
                 block = block.title(ratatui::text::Line::from(sp));
             }
             obj.block = Some(block);
+            obj.block_extras = Default::default();
+        }
+    };
+}
+
+// Block title setter from inline markup (see ffi::markup)
+#[macro_export]
+macro_rules! ratatui_block_title_markup_fn {
+    ($fn_name:ident, $ffi_ty:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(
+            ptr: *mut $ffi_ty,
+            title_markup_utf8: *const ::std::os::raw::c_char,
+            show_border: bool,
+        ) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let mut block = if show_border {
+                ratatui::widgets::Block::default().borders(ratatui::widgets::Borders::ALL)
+            } else {
+                ratatui::widgets::Block::default()
+            };
+            if let Some(m) = $crate::ffi::markup::markup_str_from_c(title_markup_utf8) {
+                block = block.title($crate::ffi::markup::parse_markup_line(m));
+            }
+            obj.block = Some(block);
+            obj.block_extras = Default::default();
+        }
+    };
+}
+
+// Block title from a shared FfiText handle (one title per line), resolved at render time; a null
+// text detaches it.
+#[macro_export]
+macro_rules! ratatui_block_title_text_fn {
+    ($fn_name:ident, $ffi_ty:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(
+            ptr: *mut $ffi_ty,
+            txt: *const $crate::ffi::text::FfiText,
+            show_border: bool,
+        ) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            obj.block = Some(if show_border {
+                ratatui::widgets::Block::default().borders(ratatui::widgets::Borders::ALL)
+            } else {
+                ratatui::widgets::Block::default()
+            });
+            obj.block_extras = $crate::ffi::widgets::block::BlockExtras {
+                title_text: $crate::ffi::text::shared_from_ffi(txt),
+                ..Default::default()
+            };
+        }
+    };
+}
+
+// Adds a title to the widget's block (created borderless if missing) at a given position
+// (0 = Top, 1 = Bottom) and alignment (0 = Left, 1 = Center, 2 = Right). Titles accumulate;
+// the other block title setters start a fresh block.
+#[macro_export]
+macro_rules! ratatui_block_add_title_fn {
+    ($spans_fn:ident, $markup_fn:ident, $ffi_ty:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $spans_fn(
+            ptr: *mut $ffi_ty,
+            title_spans: *const $crate::FfiSpan,
+            title_len: usize,
+            position: u32,
+            align: u32,
+        ) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let Some(sp) = $crate::spans_from_ffi(title_spans, title_len) else {
+                return;
+            };
+            let block = obj.block.take().unwrap_or_default();
+            obj.block = Some($crate::block_add_title(
+                block,
+                ratatui::text::Line::from(sp),
+                position,
+                align,
+            ));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $markup_fn(
+            ptr: *mut $ffi_ty,
+            title_markup_utf8: *const ::std::os::raw::c_char,
+            position: u32,
+            align: u32,
+        ) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let Some(m) = $crate::ffi::markup::markup_str_from_c(title_markup_utf8) else {
+                return;
+            };
+            let block = obj.block.take().unwrap_or_default();
+            obj.block = Some($crate::block_add_title(
+                block,
+                $crate::ffi::markup::parse_markup_line(m),
+                position,
+                align,
+            ));
+        }
+    };
+}
+
+// `FfiSpanEx` forms of `ratatui_block_title_spans_fn` (fresh block) and the spans setter of
+// `ratatui_block_add_title_fn` (accumulating).
+#[macro_export]
+macro_rules! ratatui_block_title_spans_ex_fn {
+    ($set_fn:ident, $add_fn:ident, $ffi_ty:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $set_fn(
+            ptr: *mut $ffi_ty,
+            title_spans: *const $crate::FfiSpanEx,
+            title_len: usize,
+            show_border: bool,
+        ) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let mut block = if show_border {
+                ratatui::widgets::Block::default().borders(ratatui::widgets::Borders::ALL)
+            } else {
+                ratatui::widgets::Block::default()
+            };
+            if let Some(sp) = $crate::spans_from_ffi_ex(title_spans, title_len) {
+                block = block.title(ratatui::text::Line::from(sp));
+            }
+            obj.block = Some(block);
+            obj.block_extras = Default::default();
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $add_fn(
+            ptr: *mut $ffi_ty,
+            title_spans: *const $crate::FfiSpanEx,
+            title_len: usize,
+            position: u32,
+            align: u32,
+        ) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let Some(sp) = $crate::spans_from_ffi_ex(title_spans, title_len) else {
+                return;
+            };
+            let block = obj.block.take().unwrap_or_default();
+            obj.block = Some($crate::block_add_title(
+                block,
+                ratatui::text::Line::from(sp),
+                position,
+                align,
+            ));
+        }
+    };
+}
+
+// Style setter on the widget's block (`style`, `border_style` or `title_style`).
+#[macro_export]
+macro_rules! ratatui_block_style_fn {
+    ($fn_name:ident, $ffi_ty:ty, $method:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, style: $crate::FfiStyle) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let block = obj.block.take().unwrap_or_default();
+            obj.block = Some(block.$method($crate::style_from_ffi(style)));
+            obj.block_extras.slots.$method = 0;
+        }
+    };
+}
+
+// `ratatui_block_style_fn` taking `FfiStyleEx` (underline color, removed modifiers, reset).
+#[macro_export]
+macro_rules! ratatui_block_style_ex_fn {
+    ($fn_name:ident, $ffi_ty:ty, $method:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, style: $crate::FfiStyleEx) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let block = obj.block.take().unwrap_or_default();
+            obj.block = Some(block.$method($crate::style_from_ffi_ex(style)));
+            obj.block_extras.slots.$method = 0;
+        }
+    };
+}
+
+// Binds a block style (`style`, `border_style` or `title_style`) to a theme slot, resolved at
+// draw time (0 unbinds; the style falls back to the fixed one).
+#[macro_export]
+macro_rules! ratatui_block_style_slot_fn {
+    ($fn_name:ident, $ffi_ty:ty, $method:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, slot: u32) {
+            if ptr.is_null() {
+                return;
+            }
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            obj.block_extras.slots.$method = slot;
+        }
+    };
+}
+
+// Border glyphs for the widget's block (created borderless if missing); false on invalid UTF-8.
+#[macro_export]
+macro_rules! ratatui_block_border_set_fn {
+    ($fn_name:ident, $ffi_ty:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, set: $crate::FfiSymbolsBorderSet) -> bool {
+            if ptr.is_null() {
+                return false;
+            }
+            let Some(set) = $crate::ffi::symbols::border_set_from_ffi(set) else {
+                return false;
+            };
+            use $crate::ffi::widgets::block::BlockHost;
+            let obj = &mut *unsafe { &mut *ptr }.block_host();
+            let block = obj.block.take().unwrap_or_default();
+            obj.block = Some(block.border_set(set));
+            true
+        }
+    };
+}
+
+// Attaches an `FfiBlock` by reference (null removes the block): edits to the handle show up on
+// the next draw. The widget's own `*_set_block_*` setters detach it, editing a copy.
+#[macro_export]
+macro_rules! ratatui_block_set_fn {
+    ($fn_name:ident, $ffi_ty:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(
+            ptr: *mut $ffi_ty,
+            block: *const $crate::ffi::widgets::block::FfiBlock,
+        ) {
+            if ptr.is_null() {
+                return;
+            }
+            let obj = unsafe { &mut *ptr };
+            obj.block = None;
+            obj.block_extras = $crate::ffi::widgets::block::BlockExtras {
+                shared: (!block.is_null()).then(|| unsafe { &*block }.block.clone()),
+                ..Default::default()
+            };
+        }
+
+        impl $crate::ffi::widgets::block::BlockHost for $ffi_ty {
+            type Guard<'a> = &'a mut $ffi_ty;
+
+            fn block_host(&mut self) -> &mut $ffi_ty {
+                $crate::ffi::widgets::block::detach_shared(&mut self.block, &mut self.block_extras);
+                self
+            }
         }
     };
 }
@@ -239,7 +523,40 @@ macro_rules! ratatui_set_style_fn {
                 return;
             }
             unsafe {
-                (&mut *ptr).$field = Some($crate::style_from_ffi(style));
+                (&mut *ptr).$field = Some($crate::style_from_ffi(style).into());
+            }
+        }
+    };
+}
+
+// Same as ratatui_set_style_fn, from FfiStyleEx
+#[macro_export]
+macro_rules! ratatui_set_style_ex_fn {
+    ($fn_name:ident, $ffi_ty:ty, $field:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, style: $crate::FfiStyleEx) {
+            if ptr.is_null() {
+                return;
+            }
+            unsafe {
+                (&mut *ptr).$field = Some($crate::style_from_ffi_ex(style).into());
+            }
+        }
+    };
+}
+
+// Bind a style field to a theme slot, resolved at draw time (0 unbinds)
+#[macro_export]
+macro_rules! ratatui_set_style_slot_fn {
+    ($fn_name:ident, $ffi_ty:ty, $field:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, slot: u32) {
+            if ptr.is_null() {
+                return;
+            }
+            unsafe {
+                (&mut *ptr).$field =
+                    (slot != 0).then_some($crate::ffi::theme::StyleRef::Slot(slot));
             }
         }
     };
@@ -265,3 +582,73 @@ macro_rules! ratatui_set_selected_i32_fn {
         }
     };
 }
+
+// Remove the element at `index` from a Vec field; returns false when out of range
+#[macro_export]
+macro_rules! ratatui_vec_remove_fn {
+    ($fn_name:ident, $ffi_ty:ty, $field:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, index: usize) -> bool {
+            if ptr.is_null() {
+                return false;
+            }
+            let v = unsafe { &mut (&mut *ptr).$field };
+            if index >= v.len() {
+                return false;
+            }
+            v.remove(index);
+            true
+        }
+    };
+}
+
+// Swap two elements of a Vec field; returns false when either index is out of range
+#[macro_export]
+macro_rules! ratatui_vec_swap_fn {
+    ($fn_name:ident, $ffi_ty:ty, $field:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, a: usize, b: usize) -> bool {
+            if ptr.is_null() {
+                return false;
+            }
+            let v = unsafe { &mut (&mut *ptr).$field };
+            if a >= v.len() || b >= v.len() {
+                return false;
+            }
+            v.swap(a, b);
+            true
+        }
+    };
+}
+
+// Truncate a Vec field to `len` elements
+#[macro_export]
+macro_rules! ratatui_vec_truncate_fn {
+    ($fn_name:ident, $ffi_ty:ty, $field:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty, len: usize) {
+            if ptr.is_null() {
+                return;
+            }
+            unsafe {
+                (&mut *ptr).$field.truncate(len);
+            }
+        }
+    };
+}
+
+// Clear a Vec field
+#[macro_export]
+macro_rules! ratatui_vec_clear_fn {
+    ($fn_name:ident, $ffi_ty:ty, $field:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(ptr: *mut $ffi_ty) {
+            if ptr.is_null() {
+                return;
+            }
+            unsafe {
+                (&mut *ptr).$field.clear();
+            }
+        }
+    };
+}
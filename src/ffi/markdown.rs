@@ -0,0 +1,452 @@
+// CommonMark -> styled lines for FfiParagraph. Layout is line-oriented: blocks are separated by
+// a blank line, quotes get a `│ ` gutter, list items a bullet/number with hanging indent.
+// Wrapping is left to the paragraph (`ratatui_paragraph_set_wrap`).
+
+use crate::ffi::widgets::paragraph::FfiParagraph;
+use crate::*;
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiMarkdownStyles {
+    pub h1: FfiStyle,
+    pub h2: FfiStyle,
+    pub h3: FfiStyle,
+    // h4..h6
+    pub h4: FfiStyle,
+    pub emphasis: FfiStyle,
+    pub strong: FfiStyle,
+    pub strikethrough: FfiStyle,
+    pub code: FfiStyle,
+    pub code_block: FfiStyle,
+    pub block_quote: FfiStyle,
+    pub link: FfiStyle,
+    pub list_marker: FfiStyle,
+    pub rule: FfiStyle,
+    pub rule_width: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiMarkdownStylesEx {
+    pub h1: FfiStyleEx,
+    pub h2: FfiStyleEx,
+    pub h3: FfiStyleEx,
+    // h4..h6
+    pub h4: FfiStyleEx,
+    pub emphasis: FfiStyleEx,
+    pub strong: FfiStyleEx,
+    pub strikethrough: FfiStyleEx,
+    pub code: FfiStyleEx,
+    pub code_block: FfiStyleEx,
+    pub block_quote: FfiStyleEx,
+    pub link: FfiStyleEx,
+    pub list_marker: FfiStyleEx,
+    pub rule: FfiStyleEx,
+    pub rule_width: u16,
+}
+
+// Resolved element styles; both FFI style maps convert into it.
+macro_rules! markdown_styles {
+    ($($field:ident),*) => {
+        #[derive(Copy, Clone)]
+        struct MarkdownStyles {
+            $($field: Style,)*
+            rule_width: u16,
+        }
+
+        impl From<FfiMarkdownStyles> for MarkdownStyles {
+            fn from(s: FfiMarkdownStyles) -> Self {
+                Self {
+                    $($field: style_from_ffi(s.$field),)*
+                    rule_width: s.rule_width,
+                }
+            }
+        }
+
+        impl From<FfiMarkdownStylesEx> for MarkdownStyles {
+            fn from(s: FfiMarkdownStylesEx) -> Self {
+                Self {
+                    $($field: style_from_ffi_ex(s.$field),)*
+                    rule_width: s.rule_width,
+                }
+            }
+        }
+
+        impl From<MarkdownStyles> for FfiMarkdownStylesEx {
+            fn from(s: MarkdownStyles) -> Self {
+                Self {
+                    $($field: style_ex_from_style(s.$field),)*
+                    rule_width: s.rule_width,
+                }
+            }
+        }
+    };
+}
+
+markdown_styles!(
+    h1,
+    h2,
+    h3,
+    h4,
+    emphasis,
+    strong,
+    strikethrough,
+    code,
+    code_block,
+    block_quote,
+    link,
+    list_marker,
+    rule
+);
+
+const fn ffi_style(fg: u32, mods: FfiStyleMods) -> FfiStyle {
+    FfiStyle {
+        fg,
+        bg: 0,
+        mods: mods.bits(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_markdown_default_styles() -> FfiMarkdownStyles {
+    // Colors use the named encoding: 4 yellow, 5 blue, 7 cyan, 8 gray, 9 dark gray.
+    FfiMarkdownStyles {
+        h1: ffi_style(7, FfiStyleMods::BOLD.union(FfiStyleMods::UNDERLINE)),
+        h2: ffi_style(7, FfiStyleMods::BOLD),
+        h3: ffi_style(0, FfiStyleMods::BOLD),
+        h4: ffi_style(0, FfiStyleMods::BOLD.union(FfiStyleMods::ITALIC)),
+        emphasis: ffi_style(0, FfiStyleMods::ITALIC),
+        strong: ffi_style(0, FfiStyleMods::BOLD),
+        strikethrough: ffi_style(0, FfiStyleMods::CROSSED),
+        code: ffi_style(4, FfiStyleMods::NONE),
+        code_block: ffi_style(4, FfiStyleMods::NONE),
+        block_quote: ffi_style(8, FfiStyleMods::ITALIC),
+        link: ffi_style(5, FfiStyleMods::UNDERLINE),
+        list_marker: ffi_style(9, FfiStyleMods::NONE),
+        rule: ffi_style(9, FfiStyleMods::NONE),
+        rule_width: 40,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_markdown_default_styles_ex() -> FfiMarkdownStylesEx {
+    MarkdownStyles::from(ratatui_markdown_default_styles()).into()
+}
+
+enum Container {
+    Quote,
+    // `marker` is emitted on the item's first line, then replaced by `width` spaces.
+    Item {
+        marker: Option<String>,
+        width: usize,
+    },
+}
+
+struct Renderer {
+    styles: MarkdownStyles,
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    line_open: bool,
+    blank_pending: bool,
+    inline: Vec<Style>,
+    containers: Vec<Container>,
+    lists: Vec<Option<u64>>,
+    in_code_block: bool,
+}
+
+impl Renderer {
+    fn style(&self) -> Style {
+        self.inline
+            .iter()
+            .fold(Style::default(), |st, s| st.patch(*s))
+    }
+
+    fn gutter(&mut self, quotes_only: bool) -> Vec<Span<'static>> {
+        let quote = self.styles.block_quote;
+        let marker = self.styles.list_marker;
+        let mut out = Vec::new();
+        for c in self.containers.iter_mut() {
+            match c {
+                Container::Quote => out.push(Span::styled("│ ", quote)),
+                Container::Item { .. } if quotes_only => {}
+                Container::Item { marker: m, width } => match m.take() {
+                    Some(text) => out.push(Span::styled(text, marker)),
+                    None => out.push(Span::raw(" ".repeat(*width))),
+                },
+            }
+        }
+        out
+    }
+
+    fn flush_blank(&mut self) {
+        if self.blank_pending && !self.lines.is_empty() {
+            let gutter = self.gutter(true);
+            self.lines.push(Line::from(gutter));
+        }
+        self.blank_pending = false;
+    }
+
+    fn open_line(&mut self) {
+        if self.line_open {
+            return;
+        }
+        self.flush_blank();
+        self.spans = self.gutter(false);
+        self.line_open = true;
+    }
+
+    fn close_line(&mut self) {
+        if self.line_open {
+            self.lines.push(Line::from(std::mem::take(&mut self.spans)));
+            self.line_open = false;
+        }
+    }
+
+    fn text(&mut self, text: &str, style: Style) {
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.open_line();
+                self.close_line();
+            }
+            if !part.is_empty() {
+                self.open_line();
+                self.spans.push(Span::styled(part.to_string(), style));
+            }
+        }
+    }
+
+    fn end_block(&mut self) {
+        self.close_line();
+        self.blank_pending = true;
+    }
+
+    fn push_inline(&mut self, st: Style) {
+        self.inline.push(st);
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.close_line();
+                let st = match level {
+                    HeadingLevel::H1 => self.styles.h1,
+                    HeadingLevel::H2 => self.styles.h2,
+                    HeadingLevel::H3 => self.styles.h3,
+                    _ => self.styles.h4,
+                };
+                self.push_inline(st);
+            }
+            Tag::Paragraph => self.close_line(),
+            Tag::BlockQuote(_) => {
+                self.close_line();
+                // the separating blank line belongs outside the quote gutter
+                self.flush_blank();
+                self.containers.push(Container::Quote);
+                self.push_inline(self.styles.block_quote);
+            }
+            Tag::CodeBlock(_) => {
+                self.close_line();
+                self.in_code_block = true;
+                self.push_inline(self.styles.code_block);
+            }
+            Tag::List(first) => {
+                // nested list inside a tight item: finish the item's text line first
+                self.close_line();
+                if self.lists.is_empty() {
+                    self.blank_pending |= !self.lines.is_empty();
+                }
+                self.lists.push(first);
+            }
+            Tag::Item => {
+                self.close_line();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        let m = format!("{n}. ");
+                        *n += 1;
+                        m
+                    }
+                    _ => "• ".to_string(),
+                };
+                let width = marker.chars().count();
+                self.containers.push(Container::Item {
+                    marker: Some(marker),
+                    width,
+                });
+            }
+            Tag::Emphasis => self.push_inline(self.styles.emphasis),
+            Tag::Strong => self.push_inline(self.styles.strong),
+            Tag::Strikethrough => self.push_inline(self.styles.strikethrough),
+            Tag::Link { .. } | Tag::Image { .. } => self.push_inline(self.styles.link),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Heading(_) => {
+                self.inline.pop();
+                self.end_block();
+            }
+            TagEnd::Paragraph => self.end_block(),
+            TagEnd::BlockQuote(_) => {
+                self.inline.pop();
+                self.close_line();
+                self.containers.pop();
+                self.blank_pending = true;
+            }
+            TagEnd::CodeBlock => {
+                self.inline.pop();
+                self.in_code_block = false;
+                self.end_block();
+            }
+            TagEnd::List(_) => {
+                self.close_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_pending = true;
+                }
+            }
+            TagEnd::Item => {
+                self.close_line();
+                self.containers.pop();
+            }
+            TagEnd::Emphasis
+            | TagEnd::Strong
+            | TagEnd::Strikethrough
+            | TagEnd::Link
+            | TagEnd::Image => {
+                self.inline.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn event(&mut self, ev: Event) {
+        match ev {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(t) => {
+                let st = self.style();
+                if self.in_code_block {
+                    self.text(&t, st);
+                } else {
+                    self.text(&t.replace('\n', " "), st);
+                }
+            }
+            Event::Code(t) => {
+                let st = self.style().patch(self.styles.code);
+                self.text(&t, st);
+            }
+            Event::InlineMath(t) | Event::DisplayMath(t) | Event::InlineHtml(t) => {
+                let st = self.style();
+                self.text(&t, st);
+            }
+            Event::Html(t) => {
+                let st = self.style();
+                self.text(&t, st);
+            }
+            Event::FootnoteReference(t) => {
+                let st = self.style();
+                self.text(&format!("[^{t}]"), st);
+            }
+            Event::SoftBreak => {
+                let st = self.style();
+                self.text(" ", st);
+            }
+            Event::HardBreak => {
+                self.open_line();
+                self.close_line();
+            }
+            Event::Rule => {
+                self.close_line();
+                let st = self.styles.rule;
+                self.text(&"─".repeat(self.styles.rule_width as usize), st);
+                self.end_block();
+            }
+            Event::TaskListMarker(done) => {
+                let st = self.styles.list_marker;
+                self.text(if done { "[x] " } else { "[ ] " }, st);
+            }
+        }
+    }
+}
+
+fn markdown_to_lines(src: &str, styles: MarkdownStyles) -> Vec<Line<'static>> {
+    let mut r = Renderer {
+        styles,
+        lines: Vec::new(),
+        spans: Vec::new(),
+        line_open: false,
+        blank_pending: false,
+        inline: Vec::new(),
+        containers: Vec::new(),
+        lists: Vec::new(),
+        in_code_block: false,
+    };
+    let opts = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    for ev in Parser::new_ext(src, opts) {
+        r.event(ev);
+    }
+    r.close_line();
+    r.lines
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_markdown(
+    para: *mut FfiParagraph,
+    markdown_utf8: *const c_char,
+) {
+    ratatui_paragraph_append_markdown_styled(para, markdown_utf8, std::ptr::null());
+}
+
+fn append_markdown(para: *mut FfiParagraph, markdown_utf8: *const c_char, styles: MarkdownStyles) {
+    if para.is_null() || markdown_utf8.is_null() {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    let c_str = unsafe { CStr::from_ptr(markdown_utf8) };
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_text_len(c_str.to_bytes().len()) {
+            return;
+        }
+    }
+    if let Ok(s) = c_str.to_str() {
+        p.lines.extend(markdown_to_lines(s, styles));
+    }
+}
+
+// `styles` may be null for `ratatui_markdown_default_styles()`.
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_markdown_styled(
+    para: *mut FfiParagraph,
+    markdown_utf8: *const c_char,
+    styles: *const FfiMarkdownStyles,
+) {
+    let styles = if styles.is_null() {
+        ratatui_markdown_default_styles()
+    } else {
+        unsafe { *styles }
+    };
+    append_markdown(para, markdown_utf8, styles.into());
+}
+
+// `FfiStyleEx` per element; null uses the defaults like `_styled`.
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_markdown_styled_ex(
+    para: *mut FfiParagraph,
+    markdown_utf8: *const c_char,
+    styles: *const FfiMarkdownStylesEx,
+) {
+    let styles = if styles.is_null() {
+        ratatui_markdown_default_styles().into()
+    } else {
+        unsafe { *styles }.into()
+    };
+    append_markdown(para, markdown_utf8, styles);
+}
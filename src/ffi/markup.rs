@@ -0,0 +1,297 @@
+// Inline markup for styled text, e.g. `[b]bold[/b] [fg=red]error[/] [bg=#202020 i]note[/]`.
+//
+// - `[attrs]` opens a scope; attrs are space-separated: b/bold, i/italic, u/underline, d/dim,
+//   s/strike, r/reverse, blink, rapidblink, hidden, fg=COLOR, bg=COLOR, @SLOT (a theme slot
+//   name, case-sensitive; other attrs of the tag apply on top of it).
+// - `[/]` closes the innermost scope, `[/b]` (or `[/fg]`, `[/@]`, ...) the innermost one setting
+//   it; scopes opened inside it stay active.
+// - Slots resolve against the active theme when parsed, except in `FfiText`, which keeps them
+//   bound and follows theme swaps.
+// - `[[` is a literal `[`; a bracket that does not form a valid tag is kept as text.
+// - Colors use the `FfiStyle` encoding (see `color_u32_from_str`); '\n' starts a new line.
+
+use crate::ffi::theme::{resolve_chain, slot_id, StyleChain, StyleRef};
+use crate::*;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+struct Scope {
+    keys: Vec<&'static str>,
+    patch: Style,
+    slot: u32,
+}
+
+pub(crate) fn mod_bit(tok: &str) -> Option<(&'static str, FfiStyleMods)> {
+    Some(match tok {
+        "b" | "bold" => ("bold", FfiStyleMods::BOLD),
+        "i" | "italic" => ("italic", FfiStyleMods::ITALIC),
+        "u" | "underline" | "underlined" => ("underline", FfiStyleMods::UNDERLINE),
+        "d" | "dim" => ("dim", FfiStyleMods::DIM),
+        "s" | "strike" | "crossed" => ("strike", FfiStyleMods::CROSSED),
+        "r" | "reverse" | "reversed" => ("reverse", FfiStyleMods::REVERSED),
+        "blink" | "slowblink" => ("blink", FfiStyleMods::SLOWBLINK),
+        "rapidblink" => ("rapidblink", FfiStyleMods::RAPIDBLINK),
+        "hidden" => ("hidden", FfiStyleMods::HIDDEN),
+        _ => return None,
+    })
+}
+
+// Parses the body of an opening tag into the scope it opens.
+fn open_tag(body: &str) -> Option<Scope> {
+    let mut st = FfiStyle {
+        fg: 0,
+        bg: 0,
+        mods: 0,
+    };
+    let mut keys = Vec::new();
+    let mut slot = 0;
+    for tok in body.split_whitespace() {
+        if let Some(name) = tok.strip_prefix('@') {
+            slot = slot_id(name);
+            if slot == 0 {
+                return None;
+            }
+            keys.push("@");
+            continue;
+        }
+        let tok = tok.to_ascii_lowercase();
+        if let Some(v) = tok.strip_prefix("fg=") {
+            st.fg = color_u32_from_str(v)?;
+            keys.push("fg");
+        } else if let Some(v) = tok.strip_prefix("bg=") {
+            st.bg = color_u32_from_str(v)?;
+            keys.push("bg");
+        } else {
+            let (key, bit) = mod_bit(&tok)?;
+            st.mods |= bit.bits();
+            keys.push(key);
+        }
+    }
+    if keys.is_empty() {
+        return None;
+    }
+    Some(Scope {
+        keys,
+        patch: style_from_ffi(st),
+        slot,
+    })
+}
+
+// Index of the scope a closing tag body refers to.
+fn close_tag(body: &str, stack: &[Scope]) -> Option<usize> {
+    let name = body.trim().to_ascii_lowercase();
+    if name.is_empty() {
+        return stack.len().checked_sub(1);
+    }
+    let key = match name.as_str() {
+        "fg" => "fg",
+        "bg" => "bg",
+        "@" => "@",
+        other => mod_bit(other)?.0,
+    };
+    stack.iter().rposition(|s| s.keys.contains(&key))
+}
+
+// Style chain of the open scopes; empty when none of them is bound to a slot.
+fn scope_chain(stack: &[Scope]) -> StyleChain {
+    if stack.iter().all(|s| s.slot == 0) {
+        return Vec::new();
+    }
+    stack
+        .iter()
+        .flat_map(|s| {
+            let slot = (s.slot != 0).then_some(StyleRef::Slot(s.slot));
+            slot.into_iter().chain([StyleRef::Fixed(s.patch)])
+        })
+        .collect()
+}
+
+pub(crate) fn parse_markup(src: &str) -> Vec<Line<'static>> {
+    parse_markup_bound(src).0
+}
+
+fn flush_span(
+    text: &mut String,
+    spans: &mut Vec<Span<'static>>,
+    chains: &mut Vec<StyleChain>,
+    stack: &[Scope],
+) {
+    if text.is_empty() {
+        return;
+    }
+    let chain = scope_chain(stack);
+    let style = if chain.is_empty() {
+        stack
+            .iter()
+            .fold(Style::default(), |st, s| st.patch(s.patch))
+    } else {
+        resolve_chain(&chain)
+    };
+    spans.push(Span::styled(std::mem::take(text), style));
+    chains.push(chain);
+}
+
+// Lines plus, per line, the slot chain of each span (see `FfiText`).
+pub(crate) fn parse_markup_bound(src: &str) -> (Vec<Line<'static>>, Vec<Vec<StyleChain>>) {
+    let mut lines = Vec::new();
+    let mut chains = Vec::new();
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut span_chains: Vec<StyleChain> = Vec::new();
+    let mut text = String::new();
+    let mut stack: Vec<Scope> = Vec::new();
+
+    let mut rest = src;
+    while let Some(ch) = rest.chars().next() {
+        rest = &rest[ch.len_utf8()..];
+        match ch {
+            '\n' => {
+                flush_span(&mut text, &mut spans, &mut span_chains, &stack);
+                lines.push(Line::from(std::mem::take(&mut spans)));
+                chains.push(std::mem::take(&mut span_chains));
+            }
+            '[' if rest.starts_with('[') => {
+                rest = &rest[1..];
+                text.push('[');
+            }
+            '[' => {
+                let tag = rest
+                    .find([']', '[', '\n'])
+                    .filter(|&end| rest[end..].starts_with(']'))
+                    .map(|end| &rest[..end]);
+                let action = tag.and_then(|body| match body.strip_prefix('/') {
+                    Some(name) => close_tag(name, &stack).map(|idx| (body, None, Some(idx))),
+                    None => open_tag(body).map(|scope| (body, Some(scope), None)),
+                });
+                let Some((body, open, close)) = action else {
+                    text.push('[');
+                    continue;
+                };
+                rest = &rest[body.len() + 1..];
+                flush_span(&mut text, &mut spans, &mut span_chains, &stack);
+                if let Some(scope) = open {
+                    stack.push(scope);
+                }
+                if let Some(idx) = close {
+                    stack.remove(idx);
+                }
+            }
+            c => text.push(c),
+        }
+    }
+    flush_span(&mut text, &mut spans, &mut span_chains, &stack);
+    lines.push(Line::from(spans));
+    chains.push(span_chains);
+    (lines, chains)
+}
+
+// Single-line form for titles and headers: lines are joined with a space.
+pub(crate) fn parse_markup_line(src: &str) -> Line<'static> {
+    let mut lines = parse_markup(src).into_iter();
+    let mut first = lines.next().unwrap_or_default();
+    for l in lines {
+        first.spans.push(Span::raw(" "));
+        first.spans.extend(l.spans);
+    }
+    first
+}
+
+pub(crate) fn markup_str_from_c<'a>(markup_utf8: *const c_char) -> Option<&'a str> {
+    if markup_utf8.is_null() {
+        return None;
+    }
+    let c_str = unsafe { CStr::from_ptr(markup_utf8) };
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_text_len(c_str.to_bytes().len()) {
+            return None;
+        }
+    }
+    c_str.to_str().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Modifier};
+
+    fn spans(line: &Line) -> Vec<(String, Style)> {
+        line.spans
+            .iter()
+            .map(|s| (s.content.to_string(), s.style))
+            .collect()
+    }
+
+    #[test]
+    fn nested_scopes_patch_styles() {
+        let lines = parse_markup("[b]bold [fg=red]both[/b] red[/] plain");
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        assert_eq!(
+            spans(&lines[0]),
+            vec![
+                ("bold ".to_string(), bold),
+                ("both".to_string(), bold.fg(Color::Red)),
+                (" red".to_string(), Style::default().fg(Color::Red)),
+                (" plain".to_string(), Style::default()),
+            ]
+        );
+    }
+
+    #[test]
+    fn named_close_drops_the_whole_scope_setting_it() {
+        let lines = parse_markup("[BG=#102030 i][u]x[/italic]y[/bg]z");
+        let u = Style::default().add_modifier(Modifier::UNDERLINED);
+        let bg = Style::default().bg(Color::Rgb(0x10, 0x20, 0x30));
+        assert_eq!(
+            spans(&lines[0]),
+            vec![
+                ("x".to_string(), bg.add_modifier(Modifier::ITALIC).patch(u)),
+                // no open scope sets bg any more, so the close tag is text
+                ("y[/bg]z".to_string(), u),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_tags_and_escapes_stay_text() {
+        let lines = parse_markup("[[b] [nope]x [fg=nocolor]y [/] [b z");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(
+            spans(&lines[0]),
+            vec![(
+                "[b] [nope]x [fg=nocolor]y [/] [b z".to_string(),
+                Style::default()
+            )]
+        );
+    }
+
+    #[test]
+    fn newlines_split_lines_and_keep_scopes_open() {
+        let lines = parse_markup("[d]a\nb[/d]\n");
+        assert_eq!(lines.len(), 3);
+        let dim = Style::default().add_modifier(Modifier::DIM);
+        assert_eq!(spans(&lines[1]), vec![("b".to_string(), dim)]);
+        assert!(lines[2].spans.is_empty());
+        let line = parse_markup_line("[b]a\nb");
+        assert_eq!(line.spans.len(), 3);
+        assert_eq!(line.spans[1].content, " ");
+    }
+
+    #[test]
+    fn slot_tags_are_bound_per_span() {
+        let (lines, chains) = parse_markup_bound("a [@markup.test b]x[/@]y\n[@]z");
+        assert_eq!(lines.len(), 2);
+        let slot = slot_id("markup.test");
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        assert!(chains[0][0].is_empty());
+        assert!(matches!(
+            chains[0][1].as_slice(),
+            [StyleRef::Slot(id), StyleRef::Fixed(st)] if *id == slot && *st == bold
+        ));
+        assert!(chains[0][2].is_empty());
+        assert_eq!(lines[0].spans[2].content, "y");
+        // an empty slot name is not a tag
+        assert_eq!(lines[1].spans[0].content, "[@]z");
+    }
+}
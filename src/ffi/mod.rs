@@ -1,10 +1,22 @@
+pub mod ansi;
+pub mod backend;
+pub mod color;
 pub mod headless_frame;
 pub mod macros;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod markup;
+pub mod rect;
 pub mod render;
 #[cfg(feature = "ffi_safety")]
 pub mod safety;
+pub mod source;
+pub mod symbols;
 pub mod terminal;
+pub mod text;
+pub mod theme;
 pub mod types;
+pub mod unicode;
 pub mod util;
 pub mod widgets;
 
@@ -0,0 +1,123 @@
+// Geometry on `FfiRect`, delegating to ratatui's `Rect` so bindings get exactly the math the
+// renderer uses (saturating at the u16 edges) instead of reimplementing it.
+
+use crate::{FfiMarginDto, FfiOffsetDto, FfiPositionDto, FfiRect};
+use ratatui::layout::{Constraint, Flex, Layout, Margin, Offset, Position, Rect};
+
+fn to_rect(r: FfiRect) -> Rect {
+    Rect {
+        x: r.x,
+        y: r.y,
+        width: r.width,
+        height: r.height,
+    }
+}
+
+fn from_rect(r: Rect) -> FfiRect {
+    FfiRect {
+        x: r.x,
+        y: r.y,
+        width: r.width,
+        height: r.height,
+    }
+}
+
+fn write_rects(rects: impl Iterator<Item = Rect>, out: *mut FfiRect, cap: usize) -> usize {
+    let mut n = 0;
+    for r in rects {
+        if !out.is_null() && n < cap {
+            unsafe {
+                *out.add(n) = from_rect(r);
+            }
+        }
+        n += 1;
+    }
+    n
+}
+
+fn centered(area: Rect, horizontal: Constraint, vertical: Constraint) -> Rect {
+    let [area] = Layout::vertical([vertical]).flex(Flex::Center).areas(area);
+    let [area] = Layout::horizontal([horizontal])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+// Empty (zero-sized) when the rects do not overlap.
+#[no_mangle]
+pub extern "C" fn ratatui_rect_intersection(a: FfiRect, b: FfiRect) -> FfiRect {
+    from_rect(to_rect(a).intersection(to_rect(b)))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_rect_union(a: FfiRect, b: FfiRect) -> FfiRect {
+    from_rect(to_rect(a).union(to_rect(b)))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_rect_intersects(a: FfiRect, b: FfiRect) -> bool {
+    to_rect(a).intersects(to_rect(b))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_rect_contains(r: FfiRect, pos: FfiPositionDto) -> bool {
+    to_rect(r).contains(Position::new(pos.x, pos.y))
+}
+
+// Shrinks by `horizontal` on the left and right and `vertical` on the top and bottom; a margin
+// larger than the rect yields an empty rect.
+#[no_mangle]
+pub extern "C" fn ratatui_rect_inner(r: FfiRect, margin: FfiMarginDto) -> FfiRect {
+    from_rect(to_rect(r).inner(Margin::new(margin.horizontal, margin.vertical)))
+}
+
+// Moves (and if needed shrinks) `r` so it fits inside `bounds`.
+#[no_mangle]
+pub extern "C" fn ratatui_rect_clamp(r: FfiRect, bounds: FfiRect) -> FfiRect {
+    from_rect(to_rect(r).clamp(to_rect(bounds)))
+}
+
+// Moves without resizing; the position saturates at 0 and at the u16 edge.
+#[no_mangle]
+pub extern "C" fn ratatui_rect_offset(r: FfiRect, offset: FfiOffsetDto) -> FfiRect {
+    from_rect(to_rect(r).offset(Offset {
+        x: offset.x,
+        y: offset.y,
+    }))
+}
+
+// One 1-high rect per row, top to bottom; returns the row count (pass `out_cap == 0` to size).
+#[no_mangle]
+pub extern "C" fn ratatui_rect_rows(r: FfiRect, out_rects: *mut FfiRect, out_cap: usize) -> usize {
+    write_rects(to_rect(r).rows(), out_rects, out_cap)
+}
+
+// One 1-wide rect per column, left to right; returns the column count.
+#[no_mangle]
+pub extern "C" fn ratatui_rect_columns(
+    r: FfiRect,
+    out_rects: *mut FfiRect,
+    out_cap: usize,
+) -> usize {
+    write_rects(to_rect(r).columns(), out_rects, out_cap)
+}
+
+// Popup area: `percent_x` x `percent_y` of `area`, centered within it.
+#[no_mangle]
+pub extern "C" fn ratatui_rect_centered(area: FfiRect, percent_x: u16, percent_y: u16) -> FfiRect {
+    from_rect(centered(
+        to_rect(area),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage(percent_y),
+    ))
+}
+
+// Popup area of a fixed size (clipped to `area`), centered within it.
+#[no_mangle]
+pub extern "C" fn ratatui_rect_centered_fixed(area: FfiRect, width: u16, height: u16) -> FfiRect {
+    from_rect(centered(
+        to_rect(area),
+        Constraint::Length(width),
+        Constraint::Length(height),
+    ))
+}
@@ -1,21 +1,19 @@
 use crate::ffi::widgets::barchart::FfiBarChart;
+use crate::ffi::widgets::block::FfiBlock;
 use crate::ffi::widgets::canvas::FfiCanvas;
 use crate::ffi::widgets::chart::FfiChart;
+use crate::ffi::widgets::list::list_items_window;
+use crate::ffi::widgets::paragraph::with_paragraph_widget;
+use crate::ffi::widgets::table::table_rows_window;
 use crate::*;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Rect};
 use ratatui::prelude::{Line, Span};
-use ratatui::widgets::canvas::{
-    Canvas as RtCanvas, Line as RtCanvasLine, Points as RtCanvasPoints, Rectangle as RtCanvasRect,
-};
-use ratatui::widgets::BarChart as RtBarChart;
 use ratatui::widgets::{
     Axis as RtAxis, Chart as RtChart, Dataset as RtDataset, GraphType as RtGraphType,
 };
 use ratatui::widgets::{Cell, Row, Table};
-use ratatui::widgets::{
-    Clear as RtClear, Gauge, LineGauge as RtLineGauge, List, ListItem, Paragraph, Tabs,
-};
+use ratatui::widgets::{Clear as RtClear, Gauge, LineGauge as RtLineGauge, List, Tabs};
 
 pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
     let res = term.terminal.draw(|frame| {
@@ -58,11 +56,7 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                     ) else {
                         continue;
                     };
-                    let mut w = Paragraph::new(p.lines.clone());
-                    if let Some(b) = &p.block {
-                        w = w.block(b.clone());
-                    }
-                    frame.render_widget(w, area);
+                    with_paragraph_widget(p, area, |w| frame.render_widget(w, area));
                 }
                 x if x == FfiWidgetKind::List as u32 => {
                     if cmd.handle.is_null() {
@@ -73,29 +67,25 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                     else {
                         continue;
                     };
-                    let items: Vec<ListItem> = l.items.iter().cloned().map(ListItem::new).collect();
+                    let (items, mut state) =
+                        list_items_window(l, area, l.selected, l.scroll_offset.unwrap_or(0));
                     let mut w = List::new(items);
                     if let Some(d) = l.direction {
                         w = w.direction(d);
                     }
-                    if let Some(b) = &l.block {
-                        w = w.block(b.clone());
+                    if let Some(b) = l.block_extras.apply(&l.block) {
+                        w = w.block(b);
+                    }
+                    if let Some(sty) = &l.highlight_style {
+                        w = w.highlight_style(sty.resolve());
+                    }
+                    if let Some(sym) = &l.highlight_symbol {
+                        w = w.highlight_symbol(sym.as_str());
                     }
                     if let Some(sp) = &l.highlight_spacing {
                         w = w.highlight_spacing(sp.clone());
                     }
-                    if l.selected.is_some() || l.scroll_offset.is_some() {
-                        let mut state = ratatui::widgets::ListState::default();
-                        if let Some(sel) = l.selected {
-                            state.select(Some(sel));
-                        }
-                        if let Some(off) = l.scroll_offset {
-                            state = state.with_offset(off);
-                        }
-                        frame.render_stateful_widget(w, area, &mut state);
-                    } else {
-                        frame.render_widget(w, area);
-                    }
+                    frame.render_stateful_widget(w, area, &mut state);
                 }
                 x if x == FfiWidgetKind::Table as u32 => {
                     if cmd.handle.is_null() {
@@ -110,7 +100,7 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                         let mut r =
                             Row::new(hs.iter().cloned().map(Cell::from).collect::<Vec<_>>());
                         if let Some(hsty) = &tb.header_style {
-                            r = r.style(hsty.clone());
+                            r = r.style(hsty.resolve());
                         }
                         Some(r)
                     } else if tb.headers.is_empty() {
@@ -124,38 +114,11 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                                 .collect::<Vec<_>>(),
                         ))
                     };
-                    let rows: Vec<Row> = if let Some(rows_cells) = &tb.rows_cells_lines {
-                        rows_cells
-                            .iter()
-                            .map(|cells| {
-                                let mut rc: Vec<Cell> = Vec::with_capacity(cells.len());
-                                for cell_lines in cells.iter() {
-                                    let text = ratatui::text::Text::from(cell_lines.clone());
-                                    rc.push(Cell::from(text));
-                                }
-                                Row::new(rc)
-                            })
-                            .collect()
-                    } else if let Some(rss) = &tb.rows_spans {
-                        rss.iter()
-                            .map(|r| {
-                                Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>())
-                            })
-                            .collect()
-                    } else {
-                        tb.rows
-                            .iter()
-                            .map(|r| {
-                                Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>())
-                            })
-                            .collect()
-                    };
+                    let (rows, columns, mut state) = table_rows_window(tb, area, tb.selected, 0);
                     let col_count = if let Some(w) = &tb.widths_pct {
                         w.len().max(1)
-                    } else if !tb.rows.is_empty() {
-                        tb.rows.iter().map(|r| r.len()).max().unwrap_or(1)
                     } else {
-                        tb.headers.len().max(1)
+                        columns.max(1)
                     };
                     let widths: Vec<Constraint> = if let Some(ws) = &tb.widths_pct {
                         ws.iter().map(|p| Constraint::Percentage(*p)).collect()
@@ -171,25 +134,25 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                     if let Some(hr) = header_row {
                         widget = widget.header(hr);
                     }
-                    if let Some(b) = &tb.block {
-                        widget = widget.block(b.clone());
+                    if let Some(b) = tb.block_extras.apply(&tb.block) {
+                        widget = widget.block(b);
                     }
                     if let Some(sty) = &tb.row_highlight_style {
-                        widget = widget.row_highlight_style(sty.clone());
+                        widget = widget.row_highlight_style(sty.resolve());
                     }
                     if let Some(sym) = &tb.highlight_symbol {
                         widget = widget.highlight_symbol(sym.clone());
                     }
                     if let Some(sty) = &tb.column_highlight_style {
-                        widget = widget.column_highlight_style(sty.clone());
+                        widget = widget.column_highlight_style(sty.resolve());
                     }
                     if let Some(sty) = &tb.cell_highlight_style {
-                        widget = widget.cell_highlight_style(sty.clone());
+                        widget = widget.cell_highlight_style(sty.resolve());
                     }
                     if let Some(sp) = &tb.highlight_spacing {
                         widget = widget.highlight_spacing(sp.clone());
                     }
-                    frame.render_widget(widget, area);
+                    frame.render_stateful_widget(widget, area, &mut state);
                 }
                 x if x == FfiWidgetKind::Gauge as u32 => {
                     if cmd.handle.is_null() {
@@ -204,8 +167,8 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                     if let Some(label) = &g.label {
                         w = w.label(label.clone());
                     }
-                    if let Some(b) = &g.block {
-                        w = w.block(b.clone());
+                    if let Some(b) = g.block_extras.apply(&g.block) {
+                        w = w.block(b);
                     }
                     frame.render_widget(w, area);
                 }
@@ -225,8 +188,8 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                         .map(|s| Line::from(Span::raw(s)))
                         .collect();
                     let mut w = Tabs::new(titles).select(tbs.selected as usize);
-                    if let Some(b) = &tbs.block {
-                        w = w.block(b.clone());
+                    if let Some(b) = tbs.block_extras.apply(&tbs.block) {
+                        w = w.block(b);
                     }
                     frame.render_widget(w, area);
                 }
@@ -239,32 +202,7 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                     else {
                         continue;
                     };
-                    let data: Vec<(&str, u64)> = bc
-                        .labels
-                        .iter()
-                        .map(|s| s.as_str())
-                        .zip(bc.values.iter().cloned())
-                        .collect();
-                    let mut w = RtBarChart::default().data(&data);
-                    if let Some(wd) = bc.bar_width {
-                        w = w.bar_width(wd);
-                    }
-                    if let Some(gp) = bc.bar_gap {
-                        w = w.bar_gap(gp);
-                    }
-                    if let Some(st) = &bc.bar_style {
-                        w = w.bar_style(st.clone());
-                    }
-                    if let Some(st) = &bc.value_style {
-                        w = w.value_style(st.clone());
-                    }
-                    if let Some(st) = &bc.label_style {
-                        w = w.label_style(st.clone());
-                    }
-                    if let Some(b) = &bc.block {
-                        w = w.block(b.clone());
-                    }
-                    frame.render_widget(w, area);
+                    frame.render_widget(bc.widget(), area);
                 }
                 x if x == FfiWidgetKind::Canvas as u32 => {
                     if cmd.handle.is_null() {
@@ -275,47 +213,7 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                     else {
                         continue;
                     };
-                    let mut w = RtCanvas::default()
-                        .x_bounds([cv.x_min, cv.x_max])
-                        .y_bounds([cv.y_min, cv.y_max]);
-                    if let Some(bg) = cv.background {
-                        w = w.background_color(bg);
-                    }
-                    if let Some(b) = &cv.block {
-                        w = w.block(b.clone());
-                    }
-                    if let Some(mk) = cv.marker {
-                        w = w.marker(mk);
-                    }
-                    w = w.paint(|p| {
-                        for l in &cv.lines {
-                            let col = crate::color_from_u32(l.style.fg).unwrap_or(Color::White);
-                            p.draw(&RtCanvasLine {
-                                x1: l.x1,
-                                y1: l.y1,
-                                x2: l.x2,
-                                y2: l.y2,
-                                color: col,
-                            });
-                        }
-                        for r in &cv.rects {
-                            let col = crate::color_from_u32(r.style.fg).unwrap_or(Color::White);
-                            p.draw(&RtCanvasRect {
-                                x: r.x,
-                                y: r.y,
-                                width: r.w,
-                                height: r.h,
-                                color: col,
-                            });
-                        }
-                        for (pts, col) in &cv.pts {
-                            p.draw(&RtCanvasPoints {
-                                coords: &pts[..],
-                                color: *col,
-                            });
-                        }
-                    });
-                    frame.render_widget(w, area);
+                    cv.render_into(area, frame.buffer_mut());
                 }
                 x if x == FfiWidgetKind::Chart as u32 => {
                     if cmd.handle.is_null() {
@@ -326,11 +224,12 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                     else {
                         continue;
                     };
+                    let points: Vec<_> = ch.datasets.iter().map(|ds| ds.points_slice()).collect();
                     let mut datasets: Vec<RtDataset> = Vec::new();
-                    for ds in &ch.datasets {
-                        let mut d = RtDataset::default().name(ds.name.clone()).data(&ds.points);
+                    for (ds, pts) in ch.datasets.iter().zip(&points) {
+                        let mut d = RtDataset::default().name(ds.name.clone()).data(pts);
                         if let Some(sty) = &ds.style {
-                            d = d.style(sty.clone());
+                            d = d.style(sty.resolve());
                         }
                         d = d.graph_type(match ds.kind {
                             1 => RtGraphType::Bar,
@@ -349,7 +248,7 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                             ax = ax.bounds([min, max]);
                         }
                         if let Some(st) = &ch.x_axis_style {
-                            ax = ax.style(st.clone());
+                            ax = ax.style(st.resolve());
                         }
                         if let Some(lbls) = &ch.x_labels {
                             ax = ax.labels(lbls.clone());
@@ -357,6 +256,9 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                         if let Some(al) = ch.x_labels_align {
                             ax = ax.labels_alignment(al);
                         }
+                        if let Some((b, l)) = ch.auto_ticks(true, area) {
+                            ax = ax.bounds(b).labels(l);
+                        }
                         ax
                     };
                     let y_axis = {
@@ -368,7 +270,7 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                             ay = ay.bounds([min, max]);
                         }
                         if let Some(st) = &ch.y_axis_style {
-                            ay = ay.style(st.clone());
+                            ay = ay.style(st.resolve());
                         }
                         if let Some(lbls) = &ch.y_labels {
                             ay = ay.labels(lbls.clone());
@@ -376,14 +278,17 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                         if let Some(al) = ch.y_labels_align {
                             ay = ay.labels_alignment(al);
                         }
+                        if let Some((b, l)) = ch.auto_ticks(false, area) {
+                            ay = ay.bounds(b).labels(l);
+                        }
                         ay
                     };
                     chart = chart.x_axis(x_axis).y_axis(y_axis);
                     if let Some(st) = &ch.chart_style {
-                        chart = chart.style(st.clone());
+                        chart = chart.style(st.resolve());
                     }
-                    if let Some(b) = &ch.block {
-                        chart = chart.block(b.clone());
+                    if let Some(b) = ch.block_extras.apply(&ch.block) {
+                        chart = chart.block(b);
                     }
                     frame.render_widget(chart, area);
                 }
@@ -398,11 +303,14 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                         continue;
                     };
                     let mut w = RtLineGauge::default().ratio(lg.ratio as f64);
+                    if let Some(set) = &lg.line_set {
+                        w = w.line_set(*set);
+                    }
                     if let Some(label) = &lg.label {
                         w = w.label(label.clone());
                     }
-                    if let Some(b) = &lg.block {
-                        w = w.block(b.clone());
+                    if let Some(b) = lg.block_extras.apply(&lg.block) {
+                        w = w.block(b);
                     }
                     frame.render_widget(w, area);
                 }
@@ -412,6 +320,17 @@ pub fn draw_frame(term: &mut FfiTerminal, slice: &[FfiDrawCmd]) -> bool {
                 x if x == FfiWidgetKind::RatatuiLogo as u32 => {
                     frame.render_widget(ratatui::widgets::RatatuiLogo::default(), area);
                 }
+                x if x == FfiWidgetKind::Block as u32 => {
+                    if cmd.handle.is_null() {
+                        continue;
+                    }
+                    let Some(b) =
+                        crate::ptr_checked(cmd.handle as *const FfiBlock, "draw_frame:Block")
+                    else {
+                        continue;
+                    };
+                    frame.render_widget(b.widget(), area);
+                }
                 _ => {}
             }
         }
@@ -432,23 +351,26 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
                 return;
             }
             let p = unsafe { &*(cmd.handle as *const FfiParagraph) };
-            let mut w = Paragraph::new(p.lines.clone());
-            if let Some(b) = &p.block {
-                w = w.block(b.clone());
-            }
-            ratatui::widgets::Widget::render(w, area, buf);
+            with_paragraph_widget(p, area, |w| ratatui::widgets::Widget::render(w, area, buf));
         }
         x if x == FfiWidgetKind::List as u32 => {
             if cmd.handle.is_null() {
                 return;
             }
             let l = unsafe { &*(cmd.handle as *const FfiList) };
-            let items: Vec<ListItem> = l.items.iter().cloned().map(ListItem::new).collect();
+            let (items, mut state) =
+                list_items_window(l, area, l.selected, l.scroll_offset.unwrap_or(0));
             let mut w = List::new(items);
-            if let Some(b) = &l.block {
-                w = w.block(b.clone());
+            if let Some(b) = l.block_extras.apply(&l.block) {
+                w = w.block(b);
             }
-            ratatui::widgets::Widget::render(w, area, buf);
+            if let Some(sty) = &l.highlight_style {
+                w = w.highlight_style(sty.resolve());
+            }
+            if let Some(sym) = &l.highlight_symbol {
+                w = w.highlight_symbol(sym.as_str());
+            }
+            ratatui::widgets::StatefulWidget::render(w, area, buf, &mut state);
         }
         x if x == FfiWidgetKind::Table as u32 => {
             if cmd.handle.is_null() {
@@ -466,16 +388,8 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
                         .collect::<Vec<_>>(),
                 ))
             };
-            let rows: Vec<Row> = tb
-                .rows
-                .iter()
-                .map(|r| Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>()))
-                .collect();
-            let col_count = if !tb.rows.is_empty() {
-                tb.rows.iter().map(|r| r.len()).max().unwrap_or(1)
-            } else {
-                tb.headers.len().max(1)
-            };
+            let (rows, columns, mut state) = table_rows_window(tb, area, tb.selected, 0);
+            let col_count = columns.max(1);
             let widths = std::iter::repeat(ratatui::layout::Constraint::Percentage(
                 (100 / col_count.max(1)) as u16,
             ))
@@ -484,10 +398,10 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
             if let Some(hr) = header_row {
                 w = w.header(hr);
             }
-            if let Some(b) = &tb.block {
-                w = w.block(b.clone());
+            if let Some(b) = tb.block_extras.apply(&tb.block) {
+                w = w.block(b);
             }
-            ratatui::widgets::Widget::render(w, area, buf);
+            ratatui::widgets::StatefulWidget::render(w, area, buf, &mut state);
         }
         x if x == FfiWidgetKind::Gauge as u32 => {
             if cmd.handle.is_null() {
@@ -498,8 +412,8 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
             if let Some(label) = &g.label {
                 w = w.label(label.clone());
             }
-            if let Some(b) = &g.block {
-                w = w.block(b.clone());
+            if let Some(b) = g.block_extras.apply(&g.block) {
+                w = w.block(b);
             }
             ratatui::widgets::Widget::render(w, area, buf);
         }
@@ -515,8 +429,8 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
                 .map(|s| Line::from(Span::raw(s)))
                 .collect();
             let mut w = Tabs::new(titles).select(t.selected as usize);
-            if let Some(b) = &t.block {
-                w = w.block(b.clone());
+            if let Some(b) = t.block_extras.apply(&t.block) {
+                w = w.block(b);
             }
             ratatui::widgets::Widget::render(w, area, buf);
         }
@@ -525,72 +439,26 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
                 return;
             }
             let bc = unsafe { &*(cmd.handle as *const FfiBarChart) };
-            let data: Vec<(&str, u64)> = bc
-                .labels
-                .iter()
-                .map(|s| s.as_str())
-                .zip(bc.values.iter().cloned())
-                .collect();
-            let mut w = RtBarChart::default().data(&data);
-            if let Some(b) = &bc.block {
-                w = w.block(b.clone());
-            }
-            ratatui::widgets::Widget::render(w, area, buf);
+            ratatui::widgets::Widget::render(bc.widget(), area, buf);
         }
         x if x == FfiWidgetKind::Canvas as u32 => {
             if cmd.handle.is_null() {
                 return;
             }
             let cv = unsafe { &*(cmd.handle as *const FfiCanvas) };
-            let mut w = RtCanvas::default()
-                .x_bounds([cv.x_min, cv.x_max])
-                .y_bounds([cv.y_min, cv.y_max]);
-            if let Some(bg) = cv.background {
-                w = w.background_color(bg);
-            }
-            if let Some(b) = &cv.block {
-                w = w.block(b.clone());
-            }
-            w = w.paint(|p| {
-                for l in &cv.lines {
-                    let col = crate::color_from_u32(l.style.fg).unwrap_or(Color::White);
-                    p.draw(&RtCanvasLine {
-                        x1: l.x1,
-                        y1: l.y1,
-                        x2: l.x2,
-                        y2: l.y2,
-                        color: col,
-                    });
-                }
-                for r in &cv.rects {
-                    let col = crate::color_from_u32(r.style.fg).unwrap_or(Color::White);
-                    p.draw(&RtCanvasRect {
-                        x: r.x,
-                        y: r.y,
-                        width: r.w,
-                        height: r.h,
-                        color: col,
-                    });
-                }
-                for (pts, col) in &cv.pts {
-                    p.draw(&RtCanvasPoints {
-                        coords: &pts[..],
-                        color: *col,
-                    });
-                }
-            });
-            ratatui::widgets::Widget::render(w, area, buf);
+            cv.render_into(area, buf);
         }
         x if x == FfiWidgetKind::Chart as u32 => {
             if cmd.handle.is_null() {
                 return;
             }
             let ch = unsafe { &*(cmd.handle as *const FfiChart) };
+            let points: Vec<_> = ch.datasets.iter().map(|ds| ds.points_slice()).collect();
             let mut datasets: Vec<RtDataset> = Vec::new();
-            for ds in &ch.datasets {
-                let mut d = RtDataset::default().name(ds.name.clone()).data(&ds.points);
+            for (ds, pts) in ch.datasets.iter().zip(&points) {
+                let mut d = RtDataset::default().name(ds.name.clone()).data(pts);
                 if let Some(sty) = &ds.style {
-                    d = d.style(sty.clone());
+                    d = d.style(sty.resolve());
                 }
                 d = d.graph_type(match ds.kind {
                     1 => RtGraphType::Bar,
@@ -605,6 +473,9 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
                 if let Some(ti) = &ch.x_title {
                     ax = ax.title(ti.clone());
                 }
+                if let Some((b, l)) = ch.auto_ticks(true, area) {
+                    ax = ax.bounds(b).labels(l);
+                }
                 ax
             };
             let y_axis = {
@@ -612,11 +483,14 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
                 if let Some(ti) = &ch.y_title {
                     ay = ay.title(ti.clone());
                 }
+                if let Some((b, l)) = ch.auto_ticks(false, area) {
+                    ay = ay.bounds(b).labels(l);
+                }
                 ay
             };
             chart = chart.x_axis(x_axis).y_axis(y_axis);
-            if let Some(b) = &ch.block {
-                chart = chart.block(b.clone());
+            if let Some(b) = ch.block_extras.apply(&ch.block) {
+                chart = chart.block(b);
             }
             ratatui::widgets::Widget::render(chart, area, buf);
         }
@@ -626,11 +500,14 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
             }
             let lg = unsafe { &*(cmd.handle as *const FfiLineGauge) };
             let mut w = ratatui::widgets::LineGauge::default().ratio(lg.ratio as f64);
+            if let Some(set) = &lg.line_set {
+                w = w.line_set(*set);
+            }
             if let Some(label) = &lg.label {
                 w = w.label(label.clone());
             }
-            if let Some(b) = &lg.block {
-                w = w.block(b.clone());
+            if let Some(b) = lg.block_extras.apply(&lg.block) {
+                w = w.block(b);
             }
             ratatui::widgets::Widget::render(w, area, buf);
         }
@@ -640,6 +517,13 @@ pub fn render_cmd_to_buffer(cmd: &FfiDrawCmd, buf: &mut Buffer) {
         x if x == FfiWidgetKind::RatatuiLogo as u32 => {
             ratatui::widgets::Widget::render(ratatui::widgets::RatatuiLogo::default(), area, buf);
         }
+        x if x == FfiWidgetKind::Block as u32 => {
+            if cmd.handle.is_null() {
+                return;
+            }
+            let b = unsafe { &*(cmd.handle as *const FfiBlock) };
+            ratatui::widgets::Widget::render(b.widget(), area, buf);
+        }
         _ => {}
     }
 }
@@ -0,0 +1,142 @@
+// Virtual row sources for List and Table: the host registers a row count and a fetch
+// callback; at render time only the visible window is requested. The callback pushes
+// rows into the sink, which copies them before the callback returns.
+
+use crate::*;
+use ratatui::text::Line;
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+
+pub type FfiFetchRowsFn =
+    Option<extern "C" fn(user_data: *mut c_void, start: usize, count: usize, out: *mut FfiRowSink)>;
+
+// row -> cells -> lines
+#[repr(C)]
+pub struct FfiRowSink {
+    pub rows: Vec<Vec<Vec<Line<'static>>>>,
+}
+
+#[derive(Clone, Copy)]
+pub struct VirtualSource {
+    pub count: usize,
+    pub fetch: extern "C" fn(*mut c_void, usize, usize, *mut FfiRowSink),
+    pub user_data: *mut c_void,
+}
+
+impl VirtualSource {
+    pub fn new(count: usize, fetch: FfiFetchRowsFn, user_data: *mut c_void) -> Option<Self> {
+        fetch.map(|fetch| VirtualSource {
+            count,
+            fetch,
+            user_data,
+        })
+    }
+
+    pub fn fetch_rows(&self, start: usize, count: usize) -> Vec<Vec<Vec<Line<'static>>>> {
+        let start = start.min(self.count);
+        let count = count.min(self.count - start);
+        if count == 0 {
+            return Vec::new();
+        }
+        let mut sink = FfiRowSink {
+            rows: Vec::with_capacity(count),
+        };
+        (self.fetch)(self.user_data, start, count, &mut sink);
+        sink.rows.truncate(count);
+        sink.rows
+    }
+}
+
+// First row of the visible window (keeping `selected` in view) and number of rows to fetch.
+pub fn visible_window(
+    count: usize,
+    visible: usize,
+    selected: Option<usize>,
+    offset: usize,
+) -> (usize, usize) {
+    let visible = visible.max(1);
+    let selected = selected.map(|s| s.min(count.saturating_sub(1)));
+    let mut start = offset.min(count.saturating_sub(1));
+    if let Some(sel) = selected {
+        if sel < start {
+            start = sel;
+        } else if sel >= start + visible {
+            start = sel + 1 - visible;
+        }
+    }
+    (start, visible.min(count - start.min(count)))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_row_sink_push_line_spans(
+    sink: *mut FfiRowSink,
+    spans: *const FfiSpan,
+    len: usize,
+) {
+    if sink.is_null() {
+        return;
+    }
+    let s = unsafe { &mut *sink };
+    let line = spans_from_ffi(spans, len)
+        .map(Line::from)
+        .unwrap_or_default();
+    s.rows.push(vec![vec![line]]);
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_row_sink_push_tsv(sink: *mut FfiRowSink, tsv_utf8: *const c_char) {
+    if sink.is_null() || tsv_utf8.is_null() {
+        return;
+    }
+    let s = unsafe { &mut *sink };
+    let c_str = unsafe { CStr::from_ptr(tsv_utf8) };
+    if let Ok(txt) = c_str.to_str() {
+        s.rows.push(
+            txt.split('\t')
+                .map(|cell| vec![Line::from(cell.to_string())])
+                .collect(),
+        );
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_row_sink_push_cells_spans(
+    sink: *mut FfiRowSink,
+    cells: *const FfiLineSpans,
+    len: usize,
+) {
+    if sink.is_null() || cells.is_null() {
+        return;
+    }
+    let s = unsafe { &mut *sink };
+    let slice = unsafe { std::slice::from_raw_parts(cells, len) };
+    s.rows
+        .push(slice.iter().map(|c| vec![line_from_ffi(c)]).collect());
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_row_sink_push_cells_lines(
+    sink: *mut FfiRowSink,
+    cells: *const FfiCellLines,
+    len: usize,
+) {
+    if sink.is_null() || cells.is_null() {
+        return;
+    }
+    let s = unsafe { &mut *sink };
+    let slice = unsafe { std::slice::from_raw_parts(cells, len) };
+    let row = slice
+        .iter()
+        .map(|cell| {
+            if cell.lines.is_null() || cell.len == 0 {
+                Vec::new()
+            } else {
+                unsafe { std::slice::from_raw_parts(cell.lines, cell.len) }
+                    .iter()
+                    .map(line_from_ffi)
+                    .collect()
+            }
+        })
+        .collect();
+    s.rows.push(row);
+}
@@ -0,0 +1,98 @@
+// Custom symbol sets from the generated `FfiSymbols*Set` structs. ratatui's sets borrow
+// `&'static str`, so each distinct symbol is copied and leaked once, then shared by every set
+// that uses it. The leak is capped at `MAX_INTERNED` distinct symbols; past that, sets that
+// need a new symbol are rejected.
+
+use crate::{
+    FfiStr, FfiSymbolsBarSet, FfiSymbolsBorderSet, FfiSymbolsLineSet, FfiSymbolsScrollbarSet,
+};
+use ratatui::symbols::{bar, border, line, scrollbar};
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+const MAX_INTERNED: usize = 4096;
+
+// `None` for a null pointer with non-zero length, invalid UTF-8 or a new symbol once the
+// interner is full.
+pub(crate) fn intern_ffi_str(s: FfiStr) -> Option<&'static str> {
+    let text = if s.len == 0 {
+        ""
+    } else {
+        if s.ptr.is_null() {
+            return None;
+        }
+        #[cfg(feature = "ffi_safety")]
+        {
+            if !crate::ffi::safety::check_text_len(s.len) {
+                return None;
+            }
+        }
+        std::str::from_utf8(unsafe { std::slice::from_raw_parts(s.ptr, s.len) }).ok()?
+    };
+    let mut set = INTERNED
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .ok()?;
+    if let Some(&interned) = set.get(text) {
+        return Some(interned);
+    }
+    if set.len() >= MAX_INTERNED {
+        return None;
+    }
+    let leaked: &'static str = Box::leak(text.to_owned().into_boxed_str());
+    set.insert(leaked);
+    Some(leaked)
+}
+
+pub(crate) fn border_set_from_ffi(s: FfiSymbolsBorderSet) -> Option<border::Set> {
+    Some(border::Set {
+        top_left: intern_ffi_str(s.top_left)?,
+        top_right: intern_ffi_str(s.top_right)?,
+        bottom_left: intern_ffi_str(s.bottom_left)?,
+        bottom_right: intern_ffi_str(s.bottom_right)?,
+        vertical_left: intern_ffi_str(s.vertical_left)?,
+        vertical_right: intern_ffi_str(s.vertical_right)?,
+        horizontal_top: intern_ffi_str(s.horizontal_top)?,
+        horizontal_bottom: intern_ffi_str(s.horizontal_bottom)?,
+    })
+}
+
+pub(crate) fn line_set_from_ffi(s: FfiSymbolsLineSet) -> Option<line::Set> {
+    Some(line::Set {
+        vertical: intern_ffi_str(s.vertical)?,
+        horizontal: intern_ffi_str(s.horizontal)?,
+        top_right: intern_ffi_str(s.top_right)?,
+        top_left: intern_ffi_str(s.top_left)?,
+        bottom_right: intern_ffi_str(s.bottom_right)?,
+        bottom_left: intern_ffi_str(s.bottom_left)?,
+        vertical_left: intern_ffi_str(s.vertical_left)?,
+        vertical_right: intern_ffi_str(s.vertical_right)?,
+        horizontal_down: intern_ffi_str(s.horizontal_down)?,
+        horizontal_up: intern_ffi_str(s.horizontal_up)?,
+        cross: intern_ffi_str(s.cross)?,
+    })
+}
+
+pub(crate) fn bar_set_from_ffi(s: FfiSymbolsBarSet) -> Option<bar::Set> {
+    Some(bar::Set {
+        full: intern_ffi_str(s.full)?,
+        seven_eighths: intern_ffi_str(s.seven_eighths)?,
+        three_quarters: intern_ffi_str(s.three_quarters)?,
+        five_eighths: intern_ffi_str(s.five_eighths)?,
+        half: intern_ffi_str(s.half)?,
+        three_eighths: intern_ffi_str(s.three_eighths)?,
+        one_quarter: intern_ffi_str(s.one_quarter)?,
+        one_eighth: intern_ffi_str(s.one_eighth)?,
+        empty: intern_ffi_str(s.empty)?,
+    })
+}
+
+pub(crate) fn scrollbar_set_from_ffi(s: FfiSymbolsScrollbarSet) -> Option<scrollbar::Set> {
+    Some(scrollbar::Set {
+        track: intern_ffi_str(s.track)?,
+        thumb: intern_ffi_str(s.thumb)?,
+        begin: intern_ffi_str(s.begin)?,
+        end: intern_ffi_str(s.end)?,
+    })
+}
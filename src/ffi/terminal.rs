@@ -1,3 +1,4 @@
+use crate::ffi::backend::{ColorModeBackend, FfiColorMode};
 use crate::*;
 use crossterm::{
     execute,
@@ -5,43 +6,61 @@ use crossterm::{
 };
 use std::io::stdout;
 
+// Colors are passed through unchanged; use `ratatui_init_terminal_with_color_mode` (or
+// `ratatui_terminal_set_color_mode`) to downsample for limited terminals.
 #[no_mangle]
 pub extern "C" fn ratatui_init_terminal() -> *mut FfiTerminal {
     crate::guard_ptr("ratatui_init_terminal", || {
-        let mut out = stdout();
-        let want_raw = std::env::var("RATATUI_FFI_NO_RAW").is_err();
-        let use_alt = std::env::var("RATATUI_FFI_ALTSCR").is_ok();
-        let mut entered_alt = false;
-        let mut raw_mode = false;
-        if want_raw {
-            if enable_raw_mode().is_ok() {
-                raw_mode = true;
-            }
+        init_terminal(FfiColorMode::TrueColor)
+    })
+}
+
+// `mode` is an `FfiColorMode`; `Auto` detects it from the environment. Null for an unknown mode.
+#[no_mangle]
+pub extern "C" fn ratatui_init_terminal_with_color_mode(mode: u32) -> *mut FfiTerminal {
+    crate::guard_ptr(
+        "ratatui_init_terminal_with_color_mode",
+        || match FfiColorMode::from_u32(mode) {
+            Some(mode) => init_terminal(mode),
+            None => std::ptr::null_mut(),
+        },
+    )
+}
+
+fn init_terminal(mode: FfiColorMode) -> *mut FfiTerminal {
+    let mut out = stdout();
+    let want_raw = std::env::var("RATATUI_FFI_NO_RAW").is_err();
+    let use_alt = std::env::var("RATATUI_FFI_ALTSCR").is_ok();
+    let mut entered_alt = false;
+    let mut raw_mode = false;
+    if want_raw {
+        if enable_raw_mode().is_ok() {
+            raw_mode = true;
         }
-        if use_alt {
-            if execute!(out, EnterAlternateScreen).is_ok() {
-                entered_alt = true;
-            }
+    }
+    if use_alt {
+        if execute!(out, EnterAlternateScreen).is_ok() {
+            entered_alt = true;
         }
-        let backend = CrosstermBackend::new(out);
-        match Terminal::new(backend) {
-            Ok(mut terminal) => {
-                let _ = terminal.hide_cursor();
-                let _ = terminal.clear();
-                Box::into_raw(Box::new(FfiTerminal {
-                    terminal,
-                    entered_alt,
-                    raw_mode,
-                }))
-            }
-            Err(_) => {
-                if raw_mode {
-                    let _ = disable_raw_mode();
-                }
-                std::ptr::null_mut()
+    }
+    let backend = ColorModeBackend::new(CrosstermBackend::new(out), mode);
+    match Terminal::new(backend) {
+        Ok(mut terminal) => {
+            let _ = terminal.hide_cursor();
+            let _ = terminal.clear();
+            Box::into_raw(Box::new(FfiTerminal {
+                terminal,
+                entered_alt,
+                raw_mode,
+            }))
+        }
+        Err(_) => {
+            if raw_mode {
+                let _ = disable_raw_mode();
             }
+            std::ptr::null_mut()
         }
-    })
+    }
 }
 
 #[no_mangle]
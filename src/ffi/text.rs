@@ -0,0 +1,516 @@
+// Shared text handles: an FfiText is built once and attached by reference to paragraphs, list
+// items, table cells and block titles. Widgets keep a clone of the Arc, so edits through the
+// handle show up on the next render of every widget using it, and freeing the handle does not
+// invalidate widgets that still hold it.
+//
+// Styles can also be bound to theme slots (`*_slot` setters, `[@slot]` markup tags): the text
+// keeps the bindings and re-resolves them on the first read after the theme registry changes, so
+// a theme swap restyles the text on the next draw like any other slot-bound style.
+
+use crate::ffi::markup::{markup_str_from_c, parse_markup_bound};
+use crate::ffi::theme::{resolve_chain, resolve_slot, theme_generation, StyleChain};
+use crate::*;
+use ratatui::text::{Line, Span, Text};
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_char;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+pub type SharedText = Arc<RwLock<ThemedText>>;
+
+pub struct FfiText {
+    pub text: SharedText,
+}
+
+// Slot bindings of one line: its own style (0 = unbound) and one chain per span (empty =
+// unbound).
+#[derive(Clone, Default)]
+pub struct LineSlots {
+    pub line: u32,
+    pub spans: Vec<StyleChain>,
+}
+
+// Text plus the theme slots bound to its styles. `line_slots` is index-aligned with `text.lines`
+// but may be shorter (missing entries are unbound); `generation` is the theme generation the
+// bound styles were last resolved against.
+#[derive(Default)]
+pub struct ThemedText {
+    pub text: Text<'static>,
+    style_slot: u32,
+    line_slots: Vec<LineSlots>,
+    generation: u64,
+}
+
+impl Deref for ThemedText {
+    type Target = Text<'static>;
+
+    fn deref(&self) -> &Text<'static> {
+        &self.text
+    }
+}
+
+impl DerefMut for ThemedText {
+    fn deref_mut(&mut self) -> &mut Text<'static> {
+        &mut self.text
+    }
+}
+
+impl ThemedText {
+    fn is_resolved(&self) -> bool {
+        (self.style_slot == 0 && self.line_slots.is_empty())
+            || self.generation == theme_generation()
+    }
+
+    fn resolve(&mut self) {
+        self.generation = theme_generation();
+        if self.style_slot != 0 {
+            self.text.style = resolve_slot(self.style_slot);
+        }
+        for (line, slots) in self.text.lines.iter_mut().zip(&self.line_slots) {
+            if slots.line != 0 {
+                line.style = resolve_slot(slots.line);
+            }
+            for (span, chain) in line.spans.iter_mut().zip(&slots.spans) {
+                if !chain.is_empty() {
+                    span.style = resolve_chain(chain);
+                }
+            }
+        }
+    }
+
+    fn line_slots_mut(&mut self, index: usize) -> &mut LineSlots {
+        if self.line_slots.len() <= index {
+            self.line_slots.resize_with(index + 1, LineSlots::default);
+        }
+        // force a resolve on the next read
+        self.generation = 0;
+        &mut self.line_slots[index]
+    }
+
+    fn push_line(&mut self, line: Line<'static>, spans: Vec<StyleChain>) {
+        let index = self.text.lines.len();
+        self.text.lines.push(line);
+        if spans.iter().any(|c| !c.is_empty()) {
+            self.line_slots_mut(index).spans = spans;
+        }
+    }
+
+    fn unbind_spans(&mut self, index: usize) {
+        if let Some(slots) = self.line_slots.get_mut(index) {
+            slots.spans.clear();
+        }
+    }
+
+    fn unbind_line(&mut self, index: usize) {
+        if let Some(slots) = self.line_slots.get_mut(index) {
+            slots.line = 0;
+        }
+    }
+}
+
+// Read access with slot-bound styles resolved against the active theme.
+pub(crate) fn read_text(t: &SharedText) -> RwLockReadGuard<'_, ThemedText> {
+    let guard = t.read().unwrap_or_else(PoisonError::into_inner);
+    if guard.is_resolved() {
+        return guard;
+    }
+    drop(guard);
+    write_text(t).resolve();
+    t.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write_text(t: &SharedText) -> RwLockWriteGuard<'_, ThemedText> {
+    t.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+// Shared handle behind an FfiText pointer (None for null, which detaches).
+pub(crate) fn shared_from_ffi(txt: *const FfiText) -> Option<SharedText> {
+    if txt.is_null() {
+        return None;
+    }
+    Some(unsafe { &*txt }.text.clone())
+}
+
+// Owned copy for widgets whose content must be 'static (ListItem, Cell).
+pub(crate) fn snapshot_text(t: &SharedText) -> Text<'static> {
+    read_text(t).text.clone()
+}
+
+// Zero-copy view of stored lines (span contents are borrowed).
+pub(crate) fn borrow_lines<'a>(lines: &'a [Line<'static>]) -> Vec<Line<'a>> {
+    lines
+        .iter()
+        .map(|l| Line {
+            spans: l
+                .spans
+                .iter()
+                .map(|s| Span {
+                    content: Cow::Borrowed(s.content.as_ref()),
+                    style: s.style,
+                })
+                .collect(),
+            style: l.style,
+            alignment: l.alignment,
+        })
+        .collect()
+}
+
+pub(crate) fn borrow_text<'a>(t: &'a Text<'static>) -> Text<'a> {
+    Text {
+        lines: borrow_lines(&t.lines),
+        style: t.style,
+        alignment: t.alignment,
+    }
+}
+
+fn with_text<R: Default>(txt: *mut FfiText, f: impl FnOnce(&mut ThemedText) -> R) -> R {
+    if txt.is_null() {
+        return R::default();
+    }
+    let t = unsafe { &*txt };
+    let mut guard = write_text(&t.text);
+    f(&mut guard)
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_new() -> *mut FfiText {
+    Box::into_raw(Box::new(FfiText {
+        text: Arc::new(RwLock::new(ThemedText::default())),
+    }))
+}
+
+// Releases this handle; widgets the text is attached to keep their reference.
+#[no_mangle]
+pub extern "C" fn ratatui_text_free(txt: *mut FfiText) {
+    if txt.is_null() {
+        return;
+    }
+    unsafe { drop(Box::from_raw(txt)) };
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_append_line(
+    txt: *mut FfiText,
+    text_utf8: *const c_char,
+    style: FfiStyle,
+) {
+    if let Some(line) = line_from_utf8(text_utf8, style) {
+        with_text(txt, |t| t.lines.push(line));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_append_line_spans(
+    txt: *mut FfiText,
+    spans: *const FfiSpan,
+    len: usize,
+) {
+    if spans.is_null() {
+        return;
+    }
+    if let Some(sp) = spans_from_ffi(spans, len) {
+        with_text(txt, |t| t.lines.push(Line::from(sp)));
+    }
+}
+
+// Spans bound to theme slots (see `FfiSpanSlot`); they restyle when the theme changes.
+#[no_mangle]
+pub extern "C" fn ratatui_text_append_line_spans_slot(
+    txt: *mut FfiText,
+    spans: *const FfiSpanSlot,
+    len: usize,
+) {
+    if spans.is_null() {
+        return;
+    }
+    if let Some((sp, chains)) = spans_from_ffi_slot(spans, len) {
+        with_text(txt, |t| t.push_line(Line::from(sp), chains));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_append_markup(txt: *mut FfiText, markup_utf8: *const c_char) {
+    if let Some(m) = markup_str_from_c(markup_utf8) {
+        let (lines, chains) = parse_markup_bound(m);
+        with_text(txt, |t| {
+            for (line, spans) in lines.into_iter().zip(chains) {
+                t.push_line(line, spans);
+            }
+        });
+    }
+}
+
+// Replaces the whole content with `text_utf8` split on '\n'.
+#[no_mangle]
+pub extern "C" fn ratatui_text_set(txt: *mut FfiText, text_utf8: *const c_char) {
+    if text_utf8.is_null() {
+        return;
+    }
+    let c_str = unsafe { CStr::from_ptr(text_utf8) };
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_text_len(c_str.to_bytes().len()) {
+            return;
+        }
+    }
+    if let Ok(s) = c_str.to_str() {
+        let lines: Vec<Line<'static>> = s.split('\n').map(|l| Line::from(l.to_string())).collect();
+        with_text(txt, |t| {
+            t.lines = lines;
+            t.line_slots.clear();
+        });
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_line_spans(
+    txt: *mut FfiText,
+    index: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    if spans.is_null() {
+        return false;
+    }
+    let Some(sp) = spans_from_ffi(spans, len) else {
+        return false;
+    };
+    with_text(txt, |t| match t.lines.get_mut(index) {
+        Some(line) => {
+            line.spans = sp;
+            t.unbind_spans(index);
+            true
+        }
+        None => false,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_line_spans_slot(
+    txt: *mut FfiText,
+    index: usize,
+    spans: *const FfiSpanSlot,
+    len: usize,
+) -> bool {
+    if spans.is_null() {
+        return false;
+    }
+    let Some((sp, chains)) = spans_from_ffi_slot(spans, len) else {
+        return false;
+    };
+    with_text(txt, |t| match t.lines.get_mut(index) {
+        Some(line) => {
+            line.spans = sp;
+            t.line_slots_mut(index).spans = chains;
+            true
+        }
+        None => false,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_line_style(
+    txt: *mut FfiText,
+    index: usize,
+    style: FfiStyle,
+) -> bool {
+    with_text(txt, |t| match t.lines.get_mut(index) {
+        Some(line) => {
+            line.style = style_from_ffi(style);
+            t.unbind_line(index);
+            true
+        }
+        None => false,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_line_style_ex(
+    txt: *mut FfiText,
+    index: usize,
+    style: FfiStyleEx,
+) -> bool {
+    with_text(txt, |t| match t.lines.get_mut(index) {
+        Some(line) => {
+            line.style = style_from_ffi_ex(style);
+            t.unbind_line(index);
+            true
+        }
+        None => false,
+    })
+}
+
+// slot 0 unbinds the line style (which keeps its last resolved value).
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_line_style_slot(
+    txt: *mut FfiText,
+    index: usize,
+    slot: u32,
+) -> bool {
+    with_text(txt, |t| {
+        if index >= t.lines.len() {
+            return false;
+        }
+        t.line_slots_mut(index).line = slot;
+        true
+    })
+}
+
+// align: 0 = Left, 1 = Center, 2 = Right
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_line_alignment(
+    txt: *mut FfiText,
+    index: usize,
+    align: u32,
+) -> bool {
+    with_text(txt, |t| match t.lines.get_mut(index) {
+        Some(line) => {
+            line.alignment = Some(alignment_from_u32(align));
+            true
+        }
+        None => false,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_style(txt: *mut FfiText, style: FfiStyle) {
+    with_text(txt, |t| {
+        t.style = style_from_ffi(style);
+        t.style_slot = 0;
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_style_ex(txt: *mut FfiText, style: FfiStyleEx) {
+    with_text(txt, |t| {
+        t.style = style_from_ffi_ex(style);
+        t.style_slot = 0;
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_style_slot(txt: *mut FfiText, slot: u32) {
+    with_text(txt, |t| {
+        t.style_slot = slot;
+        t.generation = 0;
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_set_alignment(txt: *mut FfiText, align: u32) {
+    with_text(txt, |t| t.alignment = Some(alignment_from_u32(align)));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_remove_line(txt: *mut FfiText, index: usize) -> bool {
+    with_text(txt, |t| {
+        if index < t.lines.len() {
+            t.lines.remove(index);
+            if index < t.line_slots.len() {
+                t.line_slots.remove(index);
+            }
+            true
+        } else {
+            false
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_clear(txt: *mut FfiText) {
+    with_text(txt, |t| {
+        t.lines.clear();
+        t.line_slots.clear();
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_line_count(txt: *const FfiText) -> usize {
+    if txt.is_null() {
+        return 0;
+    }
+    read_text(&unsafe { &*txt }.text).lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::theme::{
+        ratatui_theme_activate, ratatui_theme_set_slot_style, ratatui_theme_slot,
+    };
+    use crate::ffi::widgets::paragraph::{
+        ratatui_headless_render_paragraph, ratatui_paragraph_free, ratatui_paragraph_new,
+        ratatui_paragraph_set_text,
+    };
+    use std::ffi::CString;
+
+    fn fg(n: u32) -> FfiStyle {
+        FfiStyle {
+            fg: n,
+            bg: 0,
+            mods: 0,
+        }
+    }
+
+    fn render(p: *const FfiParagraph) -> String {
+        let mut out = std::ptr::null_mut();
+        assert!(ratatui_headless_render_paragraph(6, 2, p, &mut out));
+        unsafe { CString::from_raw(out) }.into_string().unwrap()
+    }
+
+    #[test]
+    fn edits_reach_attached_widgets_and_outlive_the_handle() {
+        let empty = CString::new("").unwrap();
+        let (one, two) = (CString::new("one").unwrap(), CString::new("two").unwrap());
+        let txt = ratatui_text_new();
+        ratatui_text_append_line(txt, one.as_ptr(), fg(0));
+        let p = ratatui_paragraph_new(empty.as_ptr());
+        ratatui_paragraph_set_text(p, txt);
+        assert_eq!(render(p), "one   \n      ");
+        ratatui_text_append_line(txt, two.as_ptr(), fg(0));
+        assert_eq!(render(p), "one   \ntwo   ");
+        assert!(ratatui_text_remove_line(txt, 0));
+        assert_eq!(render(p), "two   \n      ");
+        ratatui_text_free(txt);
+        assert_eq!(render(p), "two   \n      ");
+        ratatui_paragraph_free(p);
+    }
+
+    // The only test touching the global theme registry, under names no other test uses.
+    #[test]
+    fn slot_styles_re_resolve_after_theme_changes() {
+        let theme = CString::new("text-tests").unwrap();
+        let name = CString::new("text.tests.line").unwrap();
+        let slot = ratatui_theme_slot(name.as_ptr());
+        let set = |c: u32| {
+            let style = crate::ratatui_style_ex_from_style(fg(c));
+            assert!(ratatui_theme_set_slot_style(theme.as_ptr(), slot, style));
+        };
+        set(2);
+        assert!(ratatui_theme_activate(theme.as_ptr()));
+
+        let line = CString::new("x").unwrap();
+        let txt = ratatui_text_new();
+        ratatui_text_append_line(txt, line.as_ptr(), fg(0));
+        assert!(ratatui_text_set_line_style_slot(txt, 0, slot));
+        let shared = unsafe { &*txt }.text.clone();
+        assert_eq!(
+            read_text(&shared).lines[0].style.fg,
+            crate::color_from_u32(2)
+        );
+        set(3);
+        assert_eq!(
+            read_text(&shared).lines[0].style.fg,
+            crate::color_from_u32(3)
+        );
+        // a fixed style unbinds the slot
+        assert!(ratatui_text_set_line_style(txt, 0, fg(4)));
+        set(2);
+        assert_eq!(
+            read_text(&shared).lines[0].style.fg,
+            crate::color_from_u32(4)
+        );
+        ratatui_text_free(txt);
+    }
+}
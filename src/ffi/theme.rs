@@ -0,0 +1,1180 @@
+// Named theme registry. A theme maps style slots (`list.highlight`, `table.header`,
+// `border.focused`, ...) to styles; widget style fields bound to a slot resolve it against the
+// active theme on every draw, so activating another theme restyles them on the next frame.
+//
+// Theme files are flat maps from slot to a style string; nested JSON objects and TOML (inline)
+// tables join their keys with '.', an array of strings is one style string joined by spaces, and
+// numbers, booleans and null are accepted but bind nothing. Style strings use the markup
+// attribute syntax (`b fg=yellow bg=#202020`) plus `-b` to remove a modifier, `ul=COLOR` for the
+// underline color, `reset` for `Style::reset()` and `reset` as a color; a bare color sets the
+// foreground.
+//
+//   JSON: {"list": {"highlight": "b fg=black bg=yellow"}, "border.focused": "fg=cyan"}
+//   TOML: [list]
+//         highlight = "b fg=black bg=yellow"
+//         "border.focused" = "fg=cyan"
+
+use crate::ffi::markup::mod_bit;
+use crate::*;
+use ratatui::style::palette::{material, tailwind};
+use ratatui::style::{Color, Modifier, Style};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+// Style stored in a widget field: a literal, or a slot looked up in the active theme at draw.
+#[derive(Copy, Clone, Debug)]
+pub enum StyleRef {
+    Fixed(Style),
+    Slot(u32),
+}
+
+impl StyleRef {
+    pub fn resolve(&self) -> Style {
+        match self {
+            StyleRef::Fixed(st) => *st,
+            StyleRef::Slot(id) => resolve_slot(*id),
+        }
+    }
+}
+
+impl From<Style> for StyleRef {
+    fn from(st: Style) -> Self {
+        StyleRef::Fixed(st)
+    }
+}
+
+// Style patches of one span, folded in order when resolved; empty when the span is not bound to a
+// slot.
+pub(crate) type StyleChain = Vec<StyleRef>;
+
+pub(crate) fn resolve_chain(chain: &[StyleRef]) -> Style {
+    chain
+        .iter()
+        .fold(Style::default(), |st, r| st.patch(r.resolve()))
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FfiThemePreset {
+    TailwindDark = 0,
+    TailwindLight = 1,
+    MaterialDark = 2,
+    MaterialLight = 3,
+}
+
+#[derive(Default)]
+struct Registry {
+    // slot id N is slot_names[N - 1]; 0 is never a valid slot
+    slot_names: Vec<String>,
+    slot_ids: HashMap<String, u32>,
+    themes: HashMap<String, HashMap<u32, Style>>,
+    active: Option<String>,
+}
+
+impl Registry {
+    fn intern(&mut self, name: &str) -> u32 {
+        if let Some(&id) = self.slot_ids.get(name) {
+            return id;
+        }
+        self.slot_names.push(name.to_string());
+        let id = self.slot_names.len() as u32;
+        self.slot_ids.insert(name.to_string(), id);
+        id
+    }
+
+    // Missing slots fall back to their dotted parent (`border.focused` -> `border`).
+    fn lookup(&self, id: u32) -> Option<Style> {
+        let theme = self.themes.get(self.active.as_ref()?)?;
+        if let Some(st) = theme.get(&id) {
+            return Some(*st);
+        }
+        let mut name = self.slot_names.get(id.checked_sub(1)? as usize)?.as_str();
+        while let Some((parent, _)) = name.rsplit_once('.') {
+            if let Some(st) = self.slot_ids.get(parent).and_then(|p| theme.get(p)) {
+                return Some(*st);
+            }
+            name = parent;
+        }
+        None
+    }
+
+    fn merge(&mut self, theme: &str, styles: Vec<(String, Style)>) -> bool {
+        let changed = !styles.is_empty();
+        let ids: Vec<(u32, Style)> = styles
+            .into_iter()
+            .map(|(slot, st)| (self.intern(&slot), st))
+            .collect();
+        self.themes
+            .entry(theme.to_string())
+            .or_default()
+            .extend(ids);
+        changed
+    }
+}
+
+// Bumped whenever a registry change can alter a resolved style, so content caching resolved slot styles (FfiText) knows when to
+// resolve again.
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+pub(crate) fn theme_generation() -> u64 {
+    GENERATION.load(Ordering::Acquire)
+}
+
+fn registry() -> &'static RwLock<Registry> {
+    static REGISTRY: OnceLock<RwLock<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(Registry::default()))
+}
+
+pub(crate) fn resolve_slot(id: u32) -> Style {
+    let reg = registry().read().unwrap_or_else(PoisonError::into_inner);
+    reg.lookup(id).unwrap_or_default()
+}
+
+// `f` returns whether it changed anything; lookups and failed edits leave the generation alone.
+fn with_registry(f: impl FnOnce(&mut Registry) -> bool) -> bool {
+    let mut reg = registry().write().unwrap_or_else(PoisonError::into_inner);
+    let changed = f(&mut reg);
+    if changed {
+        GENERATION.fetch_add(1, Ordering::AcqRel);
+    }
+    changed
+}
+
+pub(crate) fn slot_id(name: &str) -> u32 {
+    if name.is_empty() {
+        return 0;
+    }
+    let reg = registry().read().unwrap_or_else(PoisonError::into_inner);
+    if let Some(&id) = reg.slot_ids.get(name) {
+        return id;
+    }
+    drop(reg);
+    let mut id = 0;
+    with_registry(|r| {
+        let known = r.slot_names.len();
+        id = r.intern(name);
+        r.slot_names.len() > known
+    });
+    id
+}
+
+// ----- Style strings -----
+
+fn theme_color(v: &str) -> Option<Color> {
+    if v.eq_ignore_ascii_case("reset") {
+        return Some(Color::Reset);
+    }
+    color_from_u32(color_u32_from_str(v)?)
+}
+
+pub(crate) fn parse_style(src: &str) -> Option<Style> {
+    let mut st = Style::default();
+    for tok in src.split_whitespace() {
+        let tok = tok.to_ascii_lowercase();
+        if tok == "reset" {
+            st = Style::reset();
+        } else if let Some(v) = tok.strip_prefix("fg=") {
+            st.fg = Some(theme_color(v)?);
+        } else if let Some(v) = tok.strip_prefix("bg=") {
+            st.bg = Some(theme_color(v)?);
+        } else if let Some(v) = tok
+            .strip_prefix("ul=")
+            .or_else(|| tok.strip_prefix("underline_color="))
+        {
+            st.underline_color = Some(theme_color(v)?);
+        } else if let Some(m) = tok.strip_prefix('-') {
+            st = st.remove_modifier(modifier_from_ffi(mod_bit(m)?.1.bits() as u32));
+        } else if let Some((_, bit)) = mod_bit(&tok) {
+            st = st.add_modifier(modifier_from_ffi(bit.bits() as u32));
+        } else {
+            st.fg = Some(theme_color(&tok)?);
+        }
+    }
+    Some(st)
+}
+
+// ----- Theme files -----
+
+// Byte offset of the closing `delim` in `s`, skipping backslash escapes (basic strings).
+fn basic_string_end(s: &str, delim: &str) -> Option<usize> {
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if s[i..].starts_with(delim) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn hex_escape(chars: &mut std::str::Chars, digits: usize) -> Option<u32> {
+    let hex: String = chars.take(digits).collect();
+    if hex.len() != digits || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+// Resolves JSON escapes in a string body; `toml` adds `\UXXXXXXXX` and line-ending backslashes.
+fn unescape(body: &str, toml: bool) -> Option<String> {
+    let mut out = String::with_capacity(body.len());
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        let c = match chars.next()? {
+            '"' => '"',
+            '\\' => '\\',
+            '/' if !toml => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let hi = hex_escape(&mut chars, 4)?;
+                if !(0xD800..0xDC00).contains(&hi) {
+                    char::from_u32(hi)?
+                } else {
+                    // UTF-16 surrogate pair: `😀`
+                    if toml || chars.next()? != '\\' || chars.next()? != 'u' {
+                        return None;
+                    }
+                    let lo = hex_escape(&mut chars, 4)?;
+                    if !(0xDC00..0xE000).contains(&lo) {
+                        return None;
+                    }
+                    char::from_u32(0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00))?
+                }
+            }
+            'U' if toml => char::from_u32(hex_escape(&mut chars, 8)?)?,
+            ws if toml && ws.is_whitespace() => {
+                let rest = chars.as_str();
+                let trimmed = rest.trim_start();
+                if !(ws == '\n' || rest[..rest.len() - trimmed.len()].contains('\n')) {
+                    return None;
+                }
+                chars = trimmed.chars();
+                continue;
+            }
+            _ => return None,
+        };
+        out.push(c);
+    }
+    Some(out)
+}
+
+struct JsonParser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl JsonParser<'_> {
+    fn skip_ws(&mut self) {
+        let rest = &self.src[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        self.skip_ws();
+        if self.src[self.pos..].starts_with(ch) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_ws();
+        self.src[self.pos..].chars().next()
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if !self.eat('"') {
+            return None;
+        }
+        let end = basic_string_end(&self.src[self.pos..], "\"")?;
+        let out = unescape(&self.src[self.pos..self.pos + end], false)?;
+        self.pos += end + 1;
+        Some(out)
+    }
+
+    // Numbers, booleans and null are valid JSON but name no style; they parse and bind nothing.
+    fn scalar(&mut self) -> Option<()> {
+        let rest = &self.src[self.pos..];
+        if let Some(lit) = ["true", "false", "null"]
+            .into_iter()
+            .find(|l| rest.starts_with(l))
+        {
+            self.pos += lit.len();
+            return Some(());
+        }
+        let b = rest.as_bytes();
+        let digits = |i: usize| {
+            b[i.min(b.len())..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count()
+        };
+        let mut i = usize::from(b.first() == Some(&b'-'));
+        let n = digits(i);
+        if n == 0 || (n > 1 && b[i] == b'0') {
+            return None;
+        }
+        i += n;
+        if b.get(i) == Some(&b'.') {
+            let n = digits(i + 1);
+            if n == 0 {
+                return None;
+            }
+            i += 1 + n;
+        }
+        if matches!(b.get(i), Some(b'e' | b'E')) {
+            i += 1;
+            if matches!(b.get(i), Some(b'+' | b'-')) {
+                i += 1;
+            }
+            let n = digits(i);
+            if n == 0 {
+                return None;
+            }
+            i += n;
+        }
+        self.pos += i;
+        Some(())
+    }
+
+    // An array of strings is one style string with its items joined by spaces; any other array
+    // binds nothing.
+    fn array(&mut self) -> Option<Option<String>> {
+        if !self.eat('[') {
+            return None;
+        }
+        let mut words = Some(Vec::new());
+        if self.eat(']') {
+            return Some(words.map(|w: Vec<String>| w.join(" ")));
+        }
+        loop {
+            if self.peek()? == '"' {
+                let s = self.string()?;
+                if let Some(w) = &mut words {
+                    w.push(s);
+                }
+            } else {
+                self.value("", &mut Vec::new())?;
+                words = None;
+            }
+            if self.eat(']') {
+                return Some(words.map(|w| w.join(" ")));
+            }
+            if !self.eat(',') {
+                return None;
+            }
+        }
+    }
+
+    fn value(&mut self, key: &str, out: &mut Vec<(String, String)>) -> Option<()> {
+        match self.peek()? {
+            '{' => self.object(key, out),
+            '"' => {
+                out.push((key.to_string(), self.string()?));
+                Some(())
+            }
+            '[' => {
+                if let Some(style) = self.array()? {
+                    out.push((key.to_string(), style));
+                }
+                Some(())
+            }
+            _ => self.scalar(),
+        }
+    }
+
+    fn object(&mut self, prefix: &str, out: &mut Vec<(String, String)>) -> Option<()> {
+        if !self.eat('{') {
+            return None;
+        }
+        if self.eat('}') {
+            return Some(());
+        }
+        loop {
+            self.skip_ws();
+            let key = self.string()?;
+            let key = if prefix.is_empty() {
+                key
+            } else {
+                format!("{prefix}.{key}")
+            };
+            if !self.eat(':') {
+                return None;
+            }
+            self.value(&key, out)?;
+            if self.eat('}') {
+                return Some(());
+            }
+            if !self.eat(',') {
+                return None;
+            }
+        }
+    }
+}
+
+pub(crate) fn parse_theme_json(src: &str) -> Option<Vec<(String, String)>> {
+    let mut p = JsonParser { src, pos: 0 };
+    let mut out = Vec::new();
+    p.object("", &mut out)?;
+    p.skip_ws();
+    (p.pos == src.len()).then_some(out)
+}
+
+// TOML subset for theme files: tables, dotted and quoted keys, all four string forms, inline
+// tables and arrays. As in JSON, arrays of strings join into one style string and numbers,
+// booleans and dates bind nothing. Array-of-tables headers (`[[x]]`) are rejected.
+struct TomlParser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> TomlParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_ws(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    }
+
+    // Whitespace, newlines and comments (between lines and inside arrays).
+    fn skip_blank(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if !trimmed.starts_with('#') {
+                return;
+            }
+            self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+        }
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        self.skip_ws();
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    // Rest of the line must be blank or a comment.
+    fn line_end(&mut self) -> Option<()> {
+        self.skip_ws();
+        let rest = self.rest();
+        let end = rest.find('\n').unwrap_or(rest.len());
+        let tail = rest[..end].trim_end_matches('\r');
+        if !(tail.is_empty() || tail.starts_with('#')) {
+            return None;
+        }
+        self.pos += end;
+        Some(())
+    }
+
+    // One key or table-header segment list: bare, "quoted" or 'literal', joined by dots.
+    fn key(&mut self) -> Option<String> {
+        let mut parts = Vec::new();
+        loop {
+            self.skip_ws();
+            let rest = self.rest();
+            let part = match rest.chars().next()? {
+                '"' => {
+                    let end = basic_string_end(&rest[1..], "\"")?;
+                    let part = unescape(&rest[1..end + 1], true)?;
+                    self.pos += end + 2;
+                    part
+                }
+                '\'' => {
+                    let end = rest[1..].find('\'')?;
+                    self.pos += end + 2;
+                    rest[1..end + 1].to_string()
+                }
+                _ => {
+                    let end = rest
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                        .unwrap_or(rest.len());
+                    if end == 0 {
+                        return None;
+                    }
+                    self.pos += end;
+                    rest[..end].to_string()
+                }
+            };
+            parts.push(part);
+            if !self.eat(".") {
+                return Some(parts.join("."));
+            }
+        }
+    }
+
+    // `"""` / `'''` strings: a newline right after the opening quotes is dropped, and up to two
+    // quotes directly before the closing ones belong to the content.
+    fn multiline(&mut self, quote: &str) -> Option<String> {
+        self.pos += 3;
+        let rest = self.rest();
+        let mut end = if quote == "\"\"\"" {
+            basic_string_end(rest, quote)?
+        } else {
+            rest.find(quote)?
+        };
+        let quote_char = quote.as_bytes()[0];
+        for _ in 0..2 {
+            if rest.as_bytes().get(end + 3) == Some(&quote_char) {
+                end += 1;
+            }
+        }
+        let body = &rest[..end];
+        let body = body
+            .strip_prefix("\r\n")
+            .or_else(|| body.strip_prefix('\n'))
+            .unwrap_or(body);
+        let out = if quote_char == b'"' {
+            unescape(body, true)?
+        } else {
+            body.to_string()
+        };
+        self.pos += end + 3;
+        Some(out)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let rest = self.rest();
+        if rest.starts_with("\"\"\"") {
+            return self.multiline("\"\"\"");
+        }
+        if rest.starts_with("'''") {
+            return self.multiline("'''");
+        }
+        let q = rest.chars().next()?;
+        let end = if q == '"' {
+            basic_string_end(&rest[1..], "\"")?
+        } else {
+            rest[1..].find('\'')?
+        };
+        let body = &rest[1..end + 1];
+        if body.contains('\n') {
+            return None;
+        }
+        let out = if q == '"' {
+            unescape(body, true)?
+        } else {
+            body.to_string()
+        };
+        self.pos += end + 2;
+        Some(out)
+    }
+
+    fn array(&mut self) -> Option<Option<String>> {
+        self.pos += 1;
+        let mut words = Some(Vec::new());
+        loop {
+            self.skip_blank();
+            if self.rest().starts_with(']') {
+                self.pos += 1;
+                return Some(words.map(|w: Vec<String>| w.join(" ")));
+            }
+            if self.rest().starts_with(['"', '\'']) {
+                let s = self.string()?;
+                if let Some(w) = &mut words {
+                    w.push(s);
+                }
+            } else {
+                self.value("", &mut Vec::new())?;
+                words = None;
+            }
+            self.skip_blank();
+            if self.rest().starts_with(',') {
+                self.pos += 1;
+            } else if !self.rest().starts_with(']') {
+                return None;
+            }
+        }
+    }
+
+    fn inline_table(&mut self, prefix: &str, out: &mut Vec<(String, String)>) -> Option<()> {
+        self.pos += 1;
+        if self.eat("}") {
+            return Some(());
+        }
+        loop {
+            let key = format!("{prefix}.{}", self.key()?);
+            if !self.eat("=") {
+                return None;
+            }
+            self.value(key.trim_start_matches('.'), out)?;
+            if self.eat("}") {
+                return Some(());
+            }
+            if !self.eat(",") {
+                return None;
+            }
+        }
+    }
+
+    fn value(&mut self, key: &str, out: &mut Vec<(String, String)>) -> Option<()> {
+        self.skip_ws();
+        match self.rest().chars().next()? {
+            '"' | '\'' => out.push((key.to_string(), self.string()?)),
+            '{' => self.inline_table(key, out)?,
+            '[' => {
+                if let Some(style) = self.array()? {
+                    out.push((key.to_string(), style));
+                }
+            }
+            _ => {
+                // number, boolean or date: one bare token
+                let rest = self.rest();
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || matches!(c, ',' | ']' | '}' | '#'))
+                    .unwrap_or(rest.len());
+                let tok = &rest[..end];
+                let numeric = tok.starts_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-')
+                    && tok
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "+-.:_".contains(c));
+                if !(numeric || matches!(tok, "true" | "false" | "inf" | "nan")) {
+                    return None;
+                }
+                self.pos += end;
+            }
+        }
+        Some(())
+    }
+}
+
+pub(crate) fn parse_theme_toml(src: &str) -> Option<Vec<(String, String)>> {
+    let mut p = TomlParser { src, pos: 0 };
+    let mut out = Vec::new();
+    let mut table = String::new();
+    loop {
+        p.skip_blank();
+        if p.rest().is_empty() {
+            return Some(out);
+        }
+        if p.rest().starts_with('[') {
+            if p.rest().starts_with("[[") {
+                return None;
+            }
+            p.pos += 1;
+            table = p.key()?;
+            if !p.eat("]") {
+                return None;
+            }
+        } else {
+            let key = p.key()?;
+            let key = if table.is_empty() {
+                key
+            } else {
+                format!("{table}.{key}")
+            };
+            if !p.eat("=") {
+                return None;
+            }
+            p.value(&key, &mut out)?;
+        }
+        p.line_end()?;
+    }
+}
+
+fn load_pairs(theme: &str, pairs: Option<Vec<(String, String)>>) -> bool {
+    let Some(pairs) = pairs else {
+        return false;
+    };
+    let mut styles = Vec::with_capacity(pairs.len());
+    for (slot, spec) in pairs {
+        let Some(st) = parse_style(&spec) else {
+            return false;
+        };
+        styles.push((slot, st));
+    }
+    with_registry(|r| r.merge(theme, styles));
+    true
+}
+
+// ----- Presets -----
+
+struct PresetColors {
+    fg: Color,
+    bg: Color,
+    muted: Color,
+    border: Color,
+    surface: Color,
+    accent: Color,
+    accent_soft: Color,
+    error: Color,
+    warning: Color,
+    success: Color,
+}
+
+fn preset_styles(c: PresetColors) -> Vec<(String, Style)> {
+    let bold = Modifier::BOLD;
+    let slots = [
+        ("base", Style::new().fg(c.fg).bg(c.bg)),
+        ("border", Style::new().fg(c.border)),
+        ("border.focused", Style::new().fg(c.accent)),
+        ("title", Style::new().fg(c.fg).add_modifier(bold)),
+        ("text.muted", Style::new().fg(c.muted)),
+        ("text.accent", Style::new().fg(c.accent)),
+        (
+            "list.highlight",
+            Style::new().bg(c.surface).add_modifier(bold),
+        ),
+        ("table.header", Style::new().fg(c.accent).add_modifier(bold)),
+        ("table.row_highlight", Style::new().bg(c.surface)),
+        ("table.column_highlight", Style::new().fg(c.accent_soft)),
+        ("table.cell_highlight", Style::new().fg(c.bg).bg(c.accent)),
+        (
+            "tabs.selected",
+            Style::new().fg(c.accent).add_modifier(bold),
+        ),
+        ("tabs.unselected", Style::new().fg(c.muted)),
+        ("gauge", Style::new().fg(c.accent).bg(c.surface)),
+        ("chart", Style::new().fg(c.fg)),
+        ("sparkline", Style::new().fg(c.accent_soft)),
+        ("barchart.bar", Style::new().fg(c.accent_soft)),
+        ("barchart.value", Style::new().fg(c.bg).bg(c.accent_soft)),
+        ("barchart.label", Style::new().fg(c.muted)),
+        ("error", Style::new().fg(c.error)),
+        ("warning", Style::new().fg(c.warning)),
+        ("success", Style::new().fg(c.success)),
+    ];
+    slots
+        .into_iter()
+        .map(|(slot, st)| (slot.to_string(), st))
+        .collect()
+}
+
+fn preset_colors(preset: FfiThemePreset) -> PresetColors {
+    use material as md;
+    use tailwind as tw;
+    match preset {
+        FfiThemePreset::TailwindDark => PresetColors {
+            fg: tw::SLATE.c200,
+            bg: tw::SLATE.c950,
+            muted: tw::SLATE.c500,
+            border: tw::SLATE.c600,
+            surface: tw::SLATE.c800,
+            accent: tw::BLUE.c400,
+            accent_soft: tw::SKY.c300,
+            error: tw::RED.c400,
+            warning: tw::AMBER.c400,
+            success: tw::EMERALD.c400,
+        },
+        FfiThemePreset::TailwindLight => PresetColors {
+            fg: tw::SLATE.c800,
+            bg: tw::SLATE.c50,
+            muted: tw::SLATE.c500,
+            border: tw::SLATE.c300,
+            surface: tw::SLATE.c200,
+            accent: tw::BLUE.c600,
+            accent_soft: tw::SKY.c700,
+            error: tw::RED.c600,
+            warning: tw::AMBER.c600,
+            success: tw::EMERALD.c600,
+        },
+        FfiThemePreset::MaterialDark => PresetColors {
+            fg: md::GRAY.c200,
+            bg: md::GRAY.c900,
+            muted: md::BLUE_GRAY.c300,
+            border: md::BLUE_GRAY.c600,
+            surface: md::GRAY.c800,
+            accent: md::LIGHT_BLUE.c300,
+            accent_soft: md::TEAL.c200,
+            error: md::RED.c300,
+            warning: md::AMBER.c300,
+            success: md::GREEN.c300,
+        },
+        FfiThemePreset::MaterialLight => PresetColors {
+            fg: md::GRAY.c900,
+            bg: md::GRAY.c50,
+            muted: md::BLUE_GRAY.c500,
+            border: md::BLUE_GRAY.c200,
+            surface: md::BLUE_GRAY.c100,
+            accent: md::BLUE.c700,
+            accent_soft: md::TEAL.c700,
+            error: md::RED.c700,
+            warning: md::ORANGE.c800,
+            success: md::GREEN.c700,
+        },
+    }
+}
+
+// ----- FFI -----
+
+fn name_from_c<'a>(name_utf8: *const c_char) -> Option<&'a str> {
+    if name_utf8.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(name_utf8) }.to_str().ok()
+}
+
+// Id of a named slot (interned on first use; ids stay valid for the process lifetime).
+#[no_mangle]
+pub extern "C" fn ratatui_theme_slot(name_utf8: *const c_char) -> u32 {
+    name_from_c(name_utf8).map_or(0, slot_id)
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_theme_set_slot_style(
+    theme_utf8: *const c_char,
+    slot: u32,
+    style: FfiStyleEx,
+) -> bool {
+    let Some(theme) = name_from_c(theme_utf8) else {
+        return false;
+    };
+    with_registry(|r| {
+        if slot == 0 || slot as usize > r.slot_names.len() {
+            return false;
+        }
+        r.themes
+            .entry(theme.to_string())
+            .or_default()
+            .insert(slot, style_from_ffi_ex(style));
+        true
+    })
+}
+
+// Merges the slots into `theme` (created if needed); nothing is applied on a parse error.
+#[no_mangle]
+pub extern "C" fn ratatui_theme_load_json(
+    theme_utf8: *const c_char,
+    json_utf8: *const c_char,
+) -> bool {
+    match (name_from_c(theme_utf8), name_from_c(json_utf8)) {
+        (Some(theme), Some(src)) => load_pairs(theme, parse_theme_json(src)),
+        _ => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_theme_load_toml(
+    theme_utf8: *const c_char,
+    toml_utf8: *const c_char,
+) -> bool {
+    match (name_from_c(theme_utf8), name_from_c(toml_utf8)) {
+        (Some(theme), Some(src)) => load_pairs(theme, parse_theme_toml(src)),
+        _ => false,
+    }
+}
+
+// Format follows the extension: `.toml` is TOML, anything else JSON.
+#[no_mangle]
+pub extern "C" fn ratatui_theme_load_file(
+    theme_utf8: *const c_char,
+    path_utf8: *const c_char,
+) -> bool {
+    let (Some(theme), Some(path)) = (name_from_c(theme_utf8), name_from_c(path_utf8)) else {
+        return false;
+    };
+    let Ok(src) = std::fs::read_to_string(path) else {
+        return false;
+    };
+    if path.to_ascii_lowercase().ends_with(".toml") {
+        load_pairs(theme, parse_theme_toml(&src))
+    } else {
+        load_pairs(theme, parse_theme_json(&src))
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_theme_load_preset(theme_utf8: *const c_char, preset: u32) -> bool {
+    let Some(theme) = name_from_c(theme_utf8) else {
+        return false;
+    };
+    let preset = match preset {
+        0 => FfiThemePreset::TailwindDark,
+        1 => FfiThemePreset::TailwindLight,
+        2 => FfiThemePreset::MaterialDark,
+        3 => FfiThemePreset::MaterialLight,
+        _ => return false,
+    };
+    let styles = preset_styles(preset_colors(preset));
+    with_registry(|r| r.merge(theme, styles));
+    true
+}
+
+// Makes `theme` the active theme (null deactivates: every slot resolves to the default style).
+#[no_mangle]
+pub extern "C" fn ratatui_theme_activate(theme_utf8: *const c_char) -> bool {
+    if theme_utf8.is_null() {
+        with_registry(|r| r.active.take().is_some());
+        return true;
+    }
+    let Some(theme) = name_from_c(theme_utf8) else {
+        return false;
+    };
+    let mut found = false;
+    with_registry(|r| {
+        found = r.themes.contains_key(theme);
+        if !found || r.active.as_deref() == Some(theme) {
+            return false;
+        }
+        r.active = Some(theme.to_string());
+        true
+    });
+    found
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_theme_remove(theme_utf8: *const c_char) -> bool {
+    let Some(theme) = name_from_c(theme_utf8) else {
+        return false;
+    };
+    with_registry(|r| {
+        if r.active.as_deref() == Some(theme) {
+            r.active = None;
+        }
+        r.themes.remove(theme).is_some()
+    })
+}
+
+// Style a slot currently resolves to, for host-drawn content; spans that should follow theme
+// swaps are bound through FfiText (`ratatui_text_*_slot`).
+#[no_mangle]
+pub extern "C" fn ratatui_theme_style(slot: u32) -> FfiStyleEx {
+    style_ex_from_style(resolve_slot(slot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(v: &[(&str, &str)]) -> Vec<(String, String)> {
+        v.iter()
+            .map(|(k, s)| (k.to_string(), s.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn json_nested_objects_become_dotted_slots() {
+        let src = r#"{ "base": "fg=white", "border": { "default": "gray", "focused": "fg=blue bold" },
+            "list": { "highlight": { "active": "reversed" } } }"#;
+        assert_eq!(
+            parse_theme_json(src).unwrap(),
+            pairs(&[
+                ("base", "fg=white"),
+                ("border.default", "gray"),
+                ("border.focused", "fg=blue bold"),
+                ("list.highlight.active", "reversed"),
+            ])
+        );
+        assert_eq!(parse_theme_json(" {} ").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn json_string_escapes() {
+        let src = r#"{"a\"b": "x\ty", "c": "A\\\n"}"#;
+        assert_eq!(
+            parse_theme_json(src).unwrap(),
+            pairs(&[("a\"b", "x\ty"), ("c", "A\\\n")])
+        );
+    }
+
+    #[test]
+    fn json_control_escapes_and_surrogate_pairs() {
+        let src = r#"{"a": "\b\f\r\/", "b": "\uD83D\uDE00 \u00e9"}"#;
+        assert_eq!(
+            parse_theme_json(src).unwrap(),
+            pairs(&[("a", "\u{8}\u{c}\r/"), ("b", "\u{1F600} \u{e9}")])
+        );
+    }
+
+    #[test]
+    fn json_non_string_values() {
+        let src = r#"{"version": 2, "dark": true, "x": null, "n": -1.5e3,
+            "base": ["fg=white", "bold"], "empty": [], "mixed": ["b", 1, {"c": "d"}],
+            "border": {"width": 0, "default": "gray"}}"#;
+        assert_eq!(
+            parse_theme_json(src).unwrap(),
+            pairs(&[
+                ("base", "fg=white bold"),
+                ("empty", ""),
+                ("border.default", "gray"),
+            ])
+        );
+    }
+
+    #[test]
+    fn json_malformed_is_rejected() {
+        for src in [
+            "",
+            "{",
+            r#"{"a": "b""#,
+            r#"{"a" "b"}"#,
+            r#"{"a": "b",}"#,
+            r#"{"a": "b"} trailing"#,
+            r#"{"a": "\u00"}"#,
+            r#"{"a": "\q"}"#,
+            r#"{"a": "\uD83D"}"#,
+            r#"{"a": "\uD83Dx"}"#,
+            r#"{"a": 01}"#,
+            r#"{"a": 1.}"#,
+            r#"{"a": tru}"#,
+            r#"{"a": ["b",]}"#,
+        ] {
+            assert!(parse_theme_json(src).is_none(), "{src:?}");
+        }
+    }
+
+    #[test]
+    fn toml_tables_and_quoted_keys() {
+        let src = r#"
+            # comment
+            base = "fg=white"
+            [border]
+            default = 'gray'   # literal string
+            "focused.ring" = "fg=blue\tbold"
+            [list . highlight]
+            active = "reversed"
+        "#;
+        assert_eq!(
+            parse_theme_toml(src).unwrap(),
+            pairs(&[
+                ("base", "fg=white"),
+                ("border.default", "gray"),
+                ("border.focused.ring", "fg=blue\tbold"),
+                ("list.highlight.active", "reversed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn toml_inline_tables_and_arrays() {
+        let src = r#"
+            border = { default = "gray", focused = { ring = 'fg=blue' } }
+            [list]
+            highlight = { active = "reversed", "idle.dim" = "dim" } # comment
+            base = ["fg=white",
+                    "bold", # trailing comma
+            ]
+            size = 3
+            flags = [1, 2]
+            dark = true
+        "#;
+        assert_eq!(
+            parse_theme_toml(src).unwrap(),
+            pairs(&[
+                ("border.default", "gray"),
+                ("border.focused.ring", "fg=blue"),
+                ("list.highlight.active", "reversed"),
+                ("list.highlight.idle.dim", "dim"),
+                ("list.base", "fg=white bold"),
+            ])
+        );
+    }
+
+    #[test]
+    fn toml_multiline_strings() {
+        let src = r#"
+            a = """
+            fg=red \
+                bold"""
+            b = '''
+            x\y
+            '''
+            c = """q"""""
+            d = "\b\f\r\U0001F600"
+        "#;
+        assert_eq!(
+            parse_theme_toml(src).unwrap(),
+            pairs(&[
+                ("a", "            fg=red bold"),
+                ("b", "            x\\y\n            "),
+                ("c", "q\"\""),
+                ("d", "\u{8}\u{c}\r\u{1F600}"),
+            ])
+        );
+    }
+
+    #[test]
+    fn toml_malformed_is_rejected() {
+        for src in [
+            "base",
+            "base = white",
+            "base = \"white",
+            "base = \"white\" extra",
+            "[border",
+            "[border] extra",
+            "= \"x\"",
+            "[[border]]",
+            "a = { b = \"x\"",
+            "a = { b = \"x\" c = \"y\" }",
+            "a = [\"x\" \"y\"]",
+            "a = \"\"\"x",
+            "a = \"x\ny\"",
+            "a = \"\\q\"",
+        ] {
+            assert!(parse_theme_toml(src).is_none(), "{src:?}");
+        }
+    }
+
+    #[test]
+    fn style_strings() {
+        let st = parse_style("fg=red bg=#102030 ul=blue bold -italic").unwrap();
+        assert_eq!(st.fg, Some(Color::Red));
+        assert_eq!(st.bg, Some(Color::Rgb(0x10, 0x20, 0x30)));
+        assert_eq!(st.underline_color, Some(Color::Blue));
+        assert_eq!(st.add_modifier, Modifier::BOLD);
+        assert_eq!(st.sub_modifier, Modifier::ITALIC);
+        assert_eq!(parse_style("green").unwrap().fg, Some(Color::Green));
+        assert_eq!(parse_style("fg=reset").unwrap().fg, Some(Color::Reset));
+        assert!(parse_style("fg=nocolor").is_none());
+        assert!(parse_style("-nomod").is_none());
+    }
+
+    #[test]
+    fn dotted_slots_fall_back_to_parents() {
+        let mut reg = Registry::default();
+        let border = Style::new().fg(Color::Gray);
+        let list = Style::new().fg(Color::Blue);
+        reg.merge(
+            "t",
+            vec![("border".into(), border), ("list.highlight".into(), list)],
+        );
+        reg.active = Some("t".into());
+        let focused = reg.intern("border.focused.hover");
+        let active = reg.intern("list.highlight.active");
+        let missing = reg.intern("table.header");
+        assert_eq!(reg.lookup(focused), Some(border));
+        assert_eq!(reg.lookup(active), Some(list));
+        assert_eq!(reg.lookup(missing), None);
+        assert_eq!(reg.lookup(0), None);
+        reg.active = None;
+        assert_eq!(reg.lookup(active), None);
+    }
+
+    #[test]
+    fn malformed_theme_leaves_no_partial_theme() {
+        let name = "tests.partial";
+        let bad_style = pairs(&[("base", "fg=white"), ("border", "fg=nocolor")]);
+        assert!(!load_pairs(name, Some(bad_style)));
+        assert!(!load_pairs(
+            name,
+            parse_theme_json(r#"{"base": "fg=white""#)
+        ));
+        assert!(!load_pairs(
+            name,
+            parse_theme_toml("base = \"fg=white\"\nborder")
+        ));
+        let reg = registry().read().unwrap_or_else(PoisonError::into_inner);
+        assert!(!reg.themes.contains_key(name));
+    }
+}
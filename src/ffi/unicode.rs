@@ -0,0 +1,356 @@
+// Width measurement matching ratatui's layout: widths come from `unicode-width`, truncation
+// and wrapping step over extended grapheme clusters, so hosts can fit columns and ellipsize
+// text exactly the way it will be rendered.
+
+use std::collections::VecDeque;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+const DEFAULT_ELLIPSIS: &str = "…";
+const NBSP: &str = "\u{00a0}";
+const ZWSP: &str = "\u{200b}";
+
+fn str_from_c<'a>(text_utf8: *const c_char) -> Option<&'a str> {
+    if text_utf8.is_null() {
+        return None;
+    }
+    let c_str = unsafe { CStr::from_ptr(text_utf8) };
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_text_len(c_str.to_bytes().len()) {
+            return None;
+        }
+    }
+    c_str.to_str().ok()
+}
+
+// Same rule as ratatui's word wrapper: zero-width space breaks, non-breaking space does not.
+fn is_break_space(g: &str) -> bool {
+    g == ZWSP || g.chars().all(char::is_whitespace) && g != NBSP
+}
+
+// Width of the widest '\n'-separated line (as `Text::width`).
+pub(crate) fn text_width(s: &str) -> usize {
+    s.split('\n').map(UnicodeWidthStr::width).max().unwrap_or(0)
+}
+
+// Cuts `s` to at most `width` columns; when it does not fit, the kept prefix is followed by
+// `ellipsis` (dropped if the ellipsis alone is wider than `width`).
+pub(crate) fn truncate_to_width(s: &str, width: usize, ellipsis: &str) -> String {
+    if s.width() <= width {
+        return s.to_string();
+    }
+    let ellipsis_w = ellipsis.width();
+    let (budget, suffix) = if ellipsis_w <= width {
+        (width - ellipsis_w, ellipsis)
+    } else {
+        (width, "")
+    };
+    let mut used = 0;
+    let mut end = 0;
+    for (idx, g) in s.grapheme_indices(true) {
+        let w = g.width();
+        if used + w > budget {
+            break;
+        }
+        used += w;
+        end = idx + g.len();
+    }
+    let mut out = String::with_capacity(end + suffix.len());
+    out.push_str(&s[..end]);
+    out.push_str(suffix);
+    out
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct FfiTextRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+fn extend(line: &mut Option<FfiTextRange>, start: usize, end: usize) {
+    match line {
+        Some(r) => r.end = end,
+        None => *line = Some(FfiTextRange { start, end }),
+    }
+}
+
+// Port of ratatui's `WordWrapper::process_input` over byte ranges instead of graphemes, so the
+// ranges are exactly the text each rendered line shows.
+fn wrap_line(out: &mut Vec<FfiTextRange>, base: usize, line: &str, max: usize, trim: bool) {
+    let first = out.len();
+    let mut pending_line: Option<FfiTextRange> = None;
+    let mut pending_word: Option<FfiTextRange> = None;
+    // (start, end, width)
+    let mut pending_ws: VecDeque<(usize, usize, usize)> = VecDeque::new();
+    let (mut line_w, mut word_w, mut ws_w) = (0, 0, 0);
+    let mut non_ws_previous = false;
+
+    for (idx, g) in line.grapheme_indices(true) {
+        let (start, end) = (base + idx, base + idx + g.len());
+        let is_ws = is_break_space(g);
+        let w = g.width();
+        // ignore symbols wider than line limit
+        if w > max {
+            continue;
+        }
+        let word_found = non_ws_previous && is_ws;
+        let line_empty = pending_line.is_none();
+        let trimmed_overflow = line_empty && trim && word_w + w > max;
+        let ws_overflow = line_empty && trim && ws_w + w > max;
+        let untrimmed_overflow = line_empty && !trim && word_w + ws_w + w > max;
+
+        if word_found || trimmed_overflow || ws_overflow || untrimmed_overflow {
+            if pending_line.is_some() || !trim {
+                if let (Some(f), Some(l)) = (pending_ws.front(), pending_ws.back()) {
+                    extend(&mut pending_line, f.0, l.1);
+                }
+                line_w += ws_w;
+            }
+            if let Some(word) = pending_word.take() {
+                extend(&mut pending_line, word.start, word.end);
+            }
+            line_w += word_w;
+            pending_ws.clear();
+            ws_w = 0;
+            word_w = 0;
+        }
+
+        let line_full = line_w >= max;
+        let pending_word_overflow = w > 0 && line_w + ws_w + word_w >= max;
+        if line_full || pending_word_overflow {
+            let mut remaining = max.saturating_sub(line_w);
+            out.push(
+                pending_line
+                    .take()
+                    .unwrap_or(FfiTextRange { start, end: start }),
+            );
+            line_w = 0;
+            // whitespace up to the end of the line is dropped
+            while let Some(&(_, _, sw)) = pending_ws.front() {
+                if sw > remaining {
+                    break;
+                }
+                ws_w -= sw;
+                remaining -= sw;
+                pending_ws.pop_front();
+            }
+            if is_ws && pending_ws.is_empty() {
+                continue;
+            }
+        }
+
+        if is_ws {
+            ws_w += w;
+            pending_ws.push_back((start, end, w));
+        } else {
+            word_w += w;
+            extend(&mut pending_word, start, end);
+        }
+        non_ws_previous = !is_ws;
+    }
+
+    let line_end = base + line.len();
+    if pending_line.is_none() && pending_word.is_none() && !pending_ws.is_empty() {
+        out.push(FfiTextRange {
+            start: line_end,
+            end: line_end,
+        });
+    }
+    if pending_line.is_some() || !trim {
+        if let (Some(f), Some(l)) = (pending_ws.front(), pending_ws.back()) {
+            extend(&mut pending_line, f.0, l.1);
+        }
+    }
+    if let Some(word) = pending_word {
+        extend(&mut pending_line, word.start, word.end);
+    }
+    if let Some(r) = pending_line {
+        out.push(r);
+    }
+    if out.len() == first {
+        out.push(FfiTextRange {
+            start: base,
+            end: base,
+        });
+    }
+}
+
+// Byte ranges (into `s`) of each rendered line when `s` is word-wrapped to `width` columns the
+// way `Paragraph` with `Wrap { trim }` does. Lines are split like `Text::from` (on '\n',
+// dropping a trailing '\r').
+pub(crate) fn wrap_ranges(s: &str, width: usize, trim: bool) -> Vec<FfiTextRange> {
+    let mut out = Vec::new();
+    if s.is_empty() {
+        // an empty string still renders one (empty) line
+        wrap_line(&mut out, 0, s, width, trim);
+        return out;
+    }
+    let mut base = 0;
+    for raw in s.split_inclusive('\n') {
+        let line = raw.strip_suffix('\n').unwrap_or(raw);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        wrap_line(&mut out, base, line, width, trim);
+        base += raw.len();
+    }
+    out
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_text_width(text_utf8: *const c_char) -> usize {
+    str_from_c(text_utf8).map_or(0, text_width)
+}
+
+// `ellipsis_utf8` may be null for "…" (pass "" for a hard cut). Free the result with
+// `ratatui_string_free`.
+#[no_mangle]
+pub extern "C" fn ratatui_text_truncate_to_width(
+    text_utf8: *const c_char,
+    width: u16,
+    ellipsis_utf8: *const c_char,
+    out_text_utf8: *mut *mut c_char,
+) -> bool {
+    if out_text_utf8.is_null() {
+        return false;
+    }
+    let Some(s) = str_from_c(text_utf8) else {
+        return false;
+    };
+    let ellipsis = if ellipsis_utf8.is_null() {
+        DEFAULT_ELLIPSIS
+    } else {
+        match str_from_c(ellipsis_utf8) {
+            Some(e) => e,
+            None => return false,
+        }
+    };
+    match CString::new(truncate_to_width(s, width as usize, ellipsis)) {
+        Ok(cstr) => {
+            unsafe {
+                *out_text_utf8 = cstr.into_raw();
+            }
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// Writes up to `cap` line ranges (byte offsets into `text_utf8`, end exclusive) and returns the
+// total number of rendered lines; call with `cap == 0` to size the buffer.
+#[no_mangle]
+pub extern "C" fn ratatui_text_wrap_to_width(
+    text_utf8: *const c_char,
+    width: u16,
+    trim: bool,
+    out_ranges: *mut FfiTextRange,
+    cap: usize,
+) -> usize {
+    let Some(s) = str_from_c(text_utf8) else {
+        return 0;
+    };
+    if width == 0 {
+        return 0;
+    }
+    let ranges = wrap_ranges(s, width as usize, trim);
+    if !out_ranges.is_null() {
+        let n = ranges.len().min(cap);
+        unsafe { std::ptr::copy_nonoverlapping(ranges.as_ptr(), out_ranges, n) };
+    }
+    ranges.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::{Paragraph, Widget, Wrap};
+
+    fn lines(s: &str, width: usize, trim: bool) -> Vec<&str> {
+        wrap_ranges(s, width, trim)
+            .iter()
+            .map(|r| &s[r.start..r.end])
+            .collect()
+    }
+
+    // Rows of a wrapped `Paragraph` rendered `width` columns wide, skipping the cells hidden
+    // behind wide graphemes and the padding after the text.
+    fn rendered(s: &str, width: u16, trim: bool) -> Vec<String> {
+        let p = Paragraph::new(s).wrap(Wrap { trim });
+        let h = p.line_count(width) as u16;
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, h));
+        p.render(buf.area, &mut buf);
+        (0..h)
+            .map(|y| {
+                let mut row = String::new();
+                let mut x = 0;
+                while x < width {
+                    let sym = buf[(x, y)].symbol();
+                    row.push_str(sym);
+                    x += sym.width().max(1) as u16;
+                }
+                row.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    fn assert_matches_paragraph(s: &str, widths: &[u16]) {
+        for &w in widths {
+            for trim in [true, false] {
+                let ranges = lines(s, w as usize, trim);
+                let p = Paragraph::new(s).wrap(Wrap { trim });
+                assert_eq!(ranges.len(), p.line_count(w), "{s:?} w={w} trim={trim}");
+                let ranges: Vec<&str> = ranges.iter().map(|l| l.trim_end()).collect();
+                assert_eq!(ranges, rendered(s, w, trim), "{s:?} w={w} trim={trim}");
+            }
+        }
+    }
+
+    #[test]
+    fn long_words_split_at_width() {
+        assert_eq!(lines("abcdefgh ij", 3, true), ["abc", "def", "gh", "ij"]);
+        assert_matches_paragraph("supercalifragilistic word", &[1, 3, 4, 7, 10, 30]);
+        assert_matches_paragraph("a verylongword b", &[2, 5, 8]);
+    }
+
+    #[test]
+    fn trailing_and_leading_spaces() {
+        // trim only drops whitespace at wrap points; the last line keeps its tail
+        assert_eq!(lines("  ab cd  ", 4, true), ["ab", "cd  "]);
+        assert_eq!(lines("  ab cd  ", 4, false), ["  ab", "cd  "]);
+        assert_eq!(lines("    ", 2, true), [""]);
+        for s in ["trailing   ", "   leading words here", "a   b    ", "    "] {
+            assert_matches_paragraph(s, &[1, 2, 3, 5, 8]);
+        }
+    }
+
+    #[test]
+    fn wide_cjk() {
+        assert_eq!(lines("世界你好 abc", 4, true), ["世界", "你好", "abc"]);
+        // a symbol wider than the line is dropped, as ratatui does
+        assert_eq!(lines("a世b", 1, true), ["a", "b"]);
+        assert_matches_paragraph("世界你好世界你好 abc", &[1, 3, 4, 5, 7, 10]);
+        assert_matches_paragraph("ab 世界 cd", &[3, 4, 5, 10]);
+    }
+
+    #[test]
+    fn combining_marks_stay_with_their_base() {
+        let s = "e\u{301}e\u{301}e\u{301} cafe\u{301}";
+        assert_eq!(
+            lines(s, 2, true),
+            ["e\u{301}e\u{301}", "e\u{301}", "ca", "fe\u{301}"]
+        );
+        assert_matches_paragraph(s, &[1, 2, 3, 4, 5, 10]);
+        assert_matches_paragraph("x\u{308}yz w\u{308}", &[1, 2, 3]);
+    }
+
+    #[test]
+    fn newlines_and_empty_text() {
+        assert_eq!(lines("", 4, true), [""]);
+        assert_eq!(lines("one\r\n\ntwo", 4, true), ["one", "", "two"]);
+        assert_matches_paragraph("one\ntwo three\n\nfour", &[2, 3, 5, 20]);
+    }
+}
@@ -1,8 +1,11 @@
+use crate::ffi::theme::{resolve_chain, StyleChain, StyleRef};
 use crate::*;
 use ratatui::prelude::{Alignment, Line, Span};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::Padding as RtPadding;
 use ratatui::widgets::{Block, BorderType as RtBorderType, Borders};
+use std::collections::VecDeque;
+use std::os::raw::c_char;
 
 pub fn spans_from_ffi<'a>(spans: *const FfiSpan, len: usize) -> Option<Vec<Span<'static>>> {
     if spans.is_null() {
@@ -39,6 +42,144 @@ pub fn spans_from_ffi<'a>(spans: *const FfiSpan, len: usize) -> Option<Vec<Span<
     Some(out)
 }
 
+pub(crate) fn spans_from_ffi_ex(spans: *const FfiSpanEx, len: usize) -> Option<Vec<Span<'static>>> {
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return None;
+        }
+    }
+    let slice = crate::slice_checked(spans, len, "spans_from_ffi_ex")?;
+    let mut out: Vec<Span<'static>> = Vec::with_capacity(len);
+    for s in slice.iter() {
+        if s.text_utf8.is_null() {
+            continue;
+        }
+        let c = unsafe { std::ffi::CStr::from_ptr(s.text_utf8) };
+        if let Ok(txt) = c.to_str() {
+            out.push(Span::styled(txt.to_string(), style_from_ffi_ex(s.style)));
+        }
+    }
+    Some(out)
+}
+
+// Spans plus the slot chain of each (empty when unbound); styles are resolved against the active
+// theme.
+pub(crate) fn spans_from_ffi_slot(
+    spans: *const FfiSpanSlot,
+    len: usize,
+) -> Option<(Vec<Span<'static>>, Vec<StyleChain>)> {
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return None;
+        }
+    }
+    let slice = crate::slice_checked(spans, len, "spans_from_ffi_slot")?;
+    let mut out: Vec<Span<'static>> = Vec::with_capacity(len);
+    let mut chains: Vec<StyleChain> = Vec::with_capacity(len);
+    for s in slice.iter() {
+        if s.text_utf8.is_null() {
+            continue;
+        }
+        let c = unsafe { std::ffi::CStr::from_ptr(s.text_utf8) };
+        if let Ok(txt) = c.to_str() {
+            let fixed = style_from_ffi_ex(s.style);
+            let chain = match s.slot {
+                0 => Vec::new(),
+                slot => vec![StyleRef::Slot(slot), StyleRef::Fixed(fixed)],
+            };
+            let style = if chain.is_empty() {
+                fixed
+            } else {
+                resolve_chain(&chain)
+            };
+            out.push(Span::styled(txt.to_string(), style));
+            chains.push(chain);
+        }
+    }
+    Some((out, chains))
+}
+
+pub fn line_from_ffi(ls: &FfiLineSpans) -> Line<'static> {
+    if ls.spans.is_null() || ls.len == 0 {
+        return Line::default();
+    }
+    spans_from_ffi(ls.spans, ls.len)
+        .map(Line::from)
+        .unwrap_or_default()
+}
+
+pub(crate) fn line_from_ffi_ex(ls: &FfiLineSpansEx) -> Line<'static> {
+    let mut line = if ls.spans.is_null() || ls.len == 0 {
+        Line::default()
+    } else {
+        spans_from_ffi_ex(ls.spans, ls.len)
+            .map(Line::from)
+            .unwrap_or_default()
+    };
+    line.style = style_from_ffi_ex(ls.style);
+    if ls.alignment >= 0 {
+        line.alignment = Some(alignment_from_u32(ls.alignment as u32));
+    }
+    line
+}
+
+pub(crate) fn lines_from_ffi_ex(lines: *const FfiLineSpansEx, len: usize) -> Vec<Line<'static>> {
+    if lines.is_null() || len == 0 {
+        return Vec::new();
+    }
+    unsafe { std::slice::from_raw_parts(lines, len) }
+        .iter()
+        .map(line_from_ffi_ex)
+        .collect()
+}
+
+pub(crate) fn line_from_utf8(text_utf8: *const c_char, style: FfiStyle) -> Option<Line<'static>> {
+    styled_line_from_utf8(text_utf8, style_from_ffi(style))
+}
+
+pub(crate) fn line_from_utf8_ex(
+    text_utf8: *const c_char,
+    style: FfiStyleEx,
+) -> Option<Line<'static>> {
+    styled_line_from_utf8(text_utf8, style_from_ffi_ex(style))
+}
+
+fn styled_line_from_utf8(text_utf8: *const c_char, style: Style) -> Option<Line<'static>> {
+    if text_utf8.is_null() {
+        return None;
+    }
+    let c_str = unsafe { std::ffi::CStr::from_ptr(text_utf8) };
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_text_len(c_str.to_bytes().len()) {
+            return None;
+        }
+    }
+    let s = c_str.to_str().ok()?;
+    Some(Line::from(Span::styled(s.to_string(), style)))
+}
+
+// Insert at `index` (== len appends); false when out of range.
+pub fn vec_insert_at<T>(v: &mut Vec<T>, index: usize, item: T) -> bool {
+    if index > v.len() {
+        return false;
+    }
+    v.insert(index, item);
+    true
+}
+
+pub fn vec_replace_at<T>(v: &mut [T], index: usize, item: T) -> bool {
+    match v.get_mut(index) {
+        Some(slot) => {
+            *slot = item;
+            true
+        }
+        None => false,
+    }
+}
+
 pub fn build_block_from_adv(
     borders_bits: u8,
     border_type: u32,
@@ -56,27 +197,50 @@ pub fn build_block_from_adv(
         top: pad_t,
         bottom: pad_b,
     });
-    block = match border_type {
-        1 => block.border_type(RtBorderType::Rounded),
-        2 => block.border_type(RtBorderType::Double),
-        3 => block.border_type(RtBorderType::Thick),
-        4 => block.border_type(RtBorderType::QuadrantInside),
-        5 => block.border_type(RtBorderType::QuadrantOutside),
-        _ => block.border_type(RtBorderType::Plain),
-    };
+    block = block.border_type(border_type_from_u32(border_type));
     if let Some(sp) = spans_from_ffi(title_spans, title_len) {
         block = block.title(Line::from(sp));
     }
     block
 }
 
-pub fn apply_block_title_alignment(b: Block<'static>, align_code: u32) -> Block<'static> {
-    let align = match align_code {
+// 0 = Plain, 1 = Rounded, 2 = Double, 3 = Thick, 4 = QuadrantInside, 5 = QuadrantOutside
+pub(crate) fn border_type_from_u32(border_type: u32) -> RtBorderType {
+    match border_type {
+        1 => RtBorderType::Rounded,
+        2 => RtBorderType::Double,
+        3 => RtBorderType::Thick,
+        4 => RtBorderType::QuadrantInside,
+        5 => RtBorderType::QuadrantOutside,
+        _ => RtBorderType::Plain,
+    }
+}
+
+// 0 = Left, 1 = Center, 2 = Right (anything else falls back to Left)
+pub(crate) fn alignment_from_u32(align_code: u32) -> Alignment {
+    match align_code {
         1 => Alignment::Center,
         2 => Alignment::Right,
         _ => Alignment::Left,
-    };
-    b.title_alignment(align)
+    }
+}
+
+pub fn apply_block_title_alignment(b: Block<'static>, align_code: u32) -> Block<'static> {
+    b.title_alignment(alignment_from_u32(align_code))
+}
+
+// 0 = Top, 1 = Bottom (anything else falls back to Top)
+pub(crate) fn block_add_title(
+    b: Block<'static>,
+    title: Line<'static>,
+    position: u32,
+    align_code: u32,
+) -> Block<'static> {
+    let title = title.alignment(alignment_from_u32(align_code));
+    match position {
+        1 => b.title_bottom(title),
+        _ => b.title_top(title),
+    }
 }
 
 bitflags::bitflags! {
@@ -167,6 +331,56 @@ pub fn color_to_u32(c: Color) -> u32 {
     }
 }
 
+// Parses an ANSI color name (`red`, `dark_gray`, `light-blue`, ...), `#rrggbb`/`#rgb`,
+// `rgb(r,g,b)`, `hsl(h,s%,l%)`, `indexed(n)` or a bare palette index `0..=255`, or a CSS/X11
+// color name (`tomato`, `rebecca-purple`) into the packed `FfiStyle` color encoding. ANSI names
+// win over CSS names of the same spelling.
+pub(crate) fn color_u32_from_str(s: &str) -> Option<u32> {
+    let s = s.trim();
+    if let Some(hex) = s.strip_prefix('#') {
+        let rgb = match hex.len() {
+            6 => u32::from_str_radix(hex, 16).ok()?,
+            3 => {
+                let v = u32::from_str_radix(hex, 16).ok()?;
+                let (r, g, b) = ((v >> 8) & 0xF, (v >> 4) & 0xF, v & 0xF);
+                (r * 0x11) << 16 | (g * 0x11) << 8 | (b * 0x11)
+            }
+            _ => return None,
+        };
+        return Some(0x8000_0000 | rgb);
+    }
+    if let Ok(idx) = s.parse::<u8>() {
+        return Some(0x4000_0000 | idx as u32);
+    }
+    if s.ends_with(')') {
+        return crate::ffi::color::parse_function(s);
+    }
+    let name: String = s
+        .chars()
+        .filter(|c| *c != '_' && *c != '-' && *c != ' ')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    Some(match name.as_str() {
+        "black" => 1,
+        "red" => 2,
+        "green" => 3,
+        "yellow" => 4,
+        "blue" => 5,
+        "magenta" => 6,
+        "cyan" => 7,
+        "gray" | "grey" => 8,
+        "darkgray" | "darkgrey" => 9,
+        "lightred" => 10,
+        "lightgreen" => 11,
+        "lightyellow" => 12,
+        "lightblue" => 13,
+        "lightmagenta" => 14,
+        "lightcyan" => 15,
+        "white" => 16,
+        _ => return crate::ffi::color::css_rgb(&name).map(|rgb| 0x8000_0000 | rgb),
+    })
+}
+
 pub fn style_from_ffi(s: FfiStyle) -> Style {
     let mut st = Style::default();
     if let Some(fg) = color_from_u32(s.fg) {
@@ -175,33 +389,90 @@ pub fn style_from_ffi(s: FfiStyle) -> Style {
     if let Some(bg) = color_from_u32(s.bg) {
         st = st.bg(bg);
     }
-    let mods = FfiStyleMods::from_bits_truncate(s.mods);
-    if mods.contains(FfiStyleMods::BOLD) {
-        st = st.add_modifier(Modifier::BOLD);
-    }
-    if mods.contains(FfiStyleMods::ITALIC) {
-        st = st.add_modifier(Modifier::ITALIC);
-    }
-    if mods.contains(FfiStyleMods::UNDERLINE) {
-        st = st.add_modifier(Modifier::UNDERLINED);
-    }
-    if mods.contains(FfiStyleMods::DIM) {
-        st = st.add_modifier(Modifier::DIM);
-    }
-    if mods.contains(FfiStyleMods::CROSSED) {
-        st = st.add_modifier(Modifier::CROSSED_OUT);
-    }
-    if mods.contains(FfiStyleMods::REVERSED) {
-        st = st.add_modifier(Modifier::REVERSED);
+    st.add_modifier(modifier_from_ffi(s.mods as u32))
+}
+
+const FFI_MODS: [(u16, Modifier); 9] = [
+    (FfiStyleMods::BOLD.bits(), Modifier::BOLD),
+    (FfiStyleMods::ITALIC.bits(), Modifier::ITALIC),
+    (FfiStyleMods::UNDERLINE.bits(), Modifier::UNDERLINED),
+    (FfiStyleMods::DIM.bits(), Modifier::DIM),
+    (FfiStyleMods::CROSSED.bits(), Modifier::CROSSED_OUT),
+    (FfiStyleMods::REVERSED.bits(), Modifier::REVERSED),
+    (FfiStyleMods::RAPIDBLINK.bits(), Modifier::RAPID_BLINK),
+    (FfiStyleMods::SLOWBLINK.bits(), Modifier::SLOW_BLINK),
+    (FfiStyleMods::HIDDEN.bits(), Modifier::HIDDEN),
+];
+
+pub(crate) fn modifier_from_ffi(mods: u32) -> Modifier {
+    FFI_MODS
+        .iter()
+        .filter(|(f, _)| mods & *f as u32 != 0)
+        .fold(Modifier::empty(), |m, (_, r)| m | *r)
+}
+
+pub(crate) fn modifier_to_ffi(m: Modifier) -> u32 {
+    FFI_MODS
+        .iter()
+        .filter(|(_, r)| m.contains(*r))
+        .fold(0, |bits, (f, _)| bits | *f as u32)
+}
+
+pub fn style_from_ffi_ex(s: FfiStyleEx) -> Style {
+    let reset = FfiStyleReset::from_bits_truncate(s.reset);
+    let color = |c: u32, bit: FfiStyleReset| {
+        if reset.contains(bit) {
+            Some(Color::Reset)
+        } else {
+            color_from_u32(c)
+        }
+    };
+    let mut sub = modifier_from_ffi(s.sub_mods);
+    if reset.contains(FfiStyleReset::MODS) {
+        sub = Modifier::all();
     }
-    if mods.contains(FfiStyleMods::RAPIDBLINK) {
-        st = st.add_modifier(Modifier::RAPID_BLINK);
+    Style {
+        fg: color(s.fg, FfiStyleReset::FG),
+        bg: color(s.bg, FfiStyleReset::BG),
+        underline_color: color(s.underline_color, FfiStyleReset::UNDERLINE_COLOR),
+        add_modifier: modifier_from_ffi(s.add_mods),
+        sub_modifier: sub,
     }
-    if mods.contains(FfiStyleMods::SLOWBLINK) {
-        st = st.add_modifier(Modifier::SLOW_BLINK);
+}
+
+pub(crate) fn style_ex_from_style(st: Style) -> FfiStyleEx {
+    let mut reset = FfiStyleReset::empty();
+    let mut color = |c: Option<Color>, bit: FfiStyleReset| match c {
+        Some(Color::Reset) => {
+            reset |= bit;
+            0
+        }
+        Some(c) => color_to_u32(c),
+        None => 0,
+    };
+    let fg = color(st.fg, FfiStyleReset::FG);
+    let bg = color(st.bg, FfiStyleReset::BG);
+    let underline_color = color(st.underline_color, FfiStyleReset::UNDERLINE_COLOR);
+    FfiStyleEx {
+        fg,
+        bg,
+        underline_color,
+        add_mods: modifier_to_ffi(st.add_modifier),
+        sub_mods: modifier_to_ffi(st.sub_modifier),
+        reset: reset.bits(),
     }
-    if mods.contains(FfiStyleMods::HIDDEN) {
-        st = st.add_modifier(Modifier::HIDDEN);
+}
+
+// Appends `new`, dropping the oldest items so at most `cap` remain (0 = unbounded). Used for the
+// streaming chart/sparkline buffers, where hosts only send the samples added since the last frame;
+// trimming pops from the front of the ring, so a push costs O(new) whatever the capacity.
+pub(crate) fn push_bounded<T: Copy>(buf: &mut VecDeque<T>, cap: usize, new: &[T]) {
+    if cap == 0 {
+        buf.extend(new);
+        return;
     }
-    st
+    let new = &new[new.len().saturating_sub(cap)..];
+    let excess = (buf.len() + new.len()).saturating_sub(cap);
+    buf.drain(..excess);
+    buf.extend(new);
 }
@@ -12,15 +12,18 @@
 
 // use crate::*; // enable when moving implementations
 
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::ffi::widgets::chart::FfiChart;
 use crate::{
     ratatui_block_adv_fn, ratatui_block_title_fn, ratatui_block_title_spans_fn, FfiLineSpans,
-    FfiRect, FfiStyle, FfiTerminal,
+    FfiLineSpansEx, FfiRect, FfiSpanEx, FfiStyle, FfiStyleEx, FfiSymbolsBarSet, FfiTerminal,
 };
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Alignment, Rect};
-use ratatui::prelude::Style;
-use ratatui::widgets::{BarChart as RtBarChart, Block};
+use ratatui::layout::{Alignment, Direction, Rect};
+use ratatui::symbols::bar;
+use ratatui::text::Line;
+use ratatui::widgets::{Bar, BarChart as RtBarChart, BarGroup, Block};
 use std::ffi::{c_char, CStr, CString};
 
 #[repr(C)]
@@ -28,11 +31,86 @@ pub struct FfiBarChart {
     pub values: Vec<u64>,
     pub labels: Vec<String>,
     pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
     pub bar_width: Option<u16>,
     pub bar_gap: Option<u16>,
-    pub bar_style: Option<Style>,
-    pub value_style: Option<Style>,
-    pub label_style: Option<Style>,
+    pub bar_style: Option<StyleRef>,
+    pub value_style: Option<StyleRef>,
+    pub label_style: Option<StyleRef>,
+    pub bar_set: Option<bar::Set>,
+    pub groups: Vec<BarGroup<'static>>,
+    pub direction: Option<Direction>,
+    pub group_gap: Option<u16>,
+    pub max: Option<u64>,
+}
+
+#[repr(C)]
+pub struct FfiBar {
+    pub value: u64,
+    pub label: FfiLineSpans,            // len 0 = no label
+    pub text_value_utf8: *const c_char, // null = the value itself
+    pub style: FfiStyle,                // patched over the chart's bar style
+    pub value_style: FfiStyle,          // patched over the chart's value style
+}
+
+#[repr(C)]
+pub struct FfiBarEx {
+    pub value: u64,
+    pub label: FfiLineSpansEx, // len 0 = no label
+    pub text_value_utf8: *const c_char,
+    pub style: FfiStyleEx,
+    pub value_style: FfiStyleEx,
+}
+
+impl FfiBarChart {
+    // Groups added with `ratatui_barchart_add_group` replace the flat values/labels.
+    pub(crate) fn widget(&self) -> RtBarChart<'_> {
+        let mut w = RtBarChart::default();
+        if self.groups.is_empty() {
+            let data: Vec<(&str, u64)> = self
+                .labels
+                .iter()
+                .map(|s| s.as_str())
+                .zip(self.values.iter().cloned())
+                .collect();
+            w = w.data(&data[..]);
+        } else {
+            for g in &self.groups {
+                w = w.data(g.clone());
+            }
+        }
+        if let Some(set) = &self.bar_set {
+            w = w.bar_set(set.clone());
+        }
+        if let Some(wd) = self.bar_width {
+            w = w.bar_width(wd);
+        }
+        if let Some(gp) = self.bar_gap {
+            w = w.bar_gap(gp);
+        }
+        if let Some(gp) = self.group_gap {
+            w = w.group_gap(gp);
+        }
+        if let Some(d) = self.direction {
+            w = w.direction(d);
+        }
+        if let Some(m) = self.max {
+            w = w.max(m);
+        }
+        if let Some(st) = &self.bar_style {
+            w = w.bar_style(st.resolve());
+        }
+        if let Some(st) = &self.value_style {
+            w = w.value_style(st.resolve());
+        }
+        if let Some(st) = &self.label_style {
+            w = w.label_style(st.resolve());
+        }
+        if let Some(bl) = self.block_extras.apply(&self.block) {
+            w = w.block(bl);
+        }
+        w
+    }
 }
 
 #[no_mangle]
@@ -41,11 +119,17 @@ pub extern "C" fn ratatui_barchart_new() -> *mut FfiBarChart {
         values: Vec::new(),
         labels: Vec::new(),
         block: None,
+        block_extras: BlockExtras::default(),
         bar_width: None,
         bar_gap: None,
         bar_style: None,
         value_style: None,
         label_style: None,
+        bar_set: None,
+        groups: Vec::new(),
+        direction: None,
+        group_gap: None,
+        max: None,
     }))
 }
 
@@ -128,6 +212,19 @@ pub extern "C" fn ratatui_barchart_set_bar_width(b: *mut FfiBarChart, width: u16
     }
 }
 
+// Bar glyphs from empty to full in eighths; false on invalid UTF-8.
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_set_bar_set(b: *mut FfiBarChart, set: FfiSymbolsBarSet) -> bool {
+    if b.is_null() {
+        return false;
+    }
+    let Some(set) = crate::ffi::symbols::bar_set_from_ffi(set) else {
+        return false;
+    };
+    unsafe { &mut *b }.bar_set = Some(set);
+    true
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_barchart_set_bar_gap(b: *mut FfiBarChart, gap: u16) {
     if b.is_null() {
@@ -138,6 +235,158 @@ pub extern "C" fn ratatui_barchart_set_bar_gap(b: *mut FfiBarChart, gap: u16) {
     }
 }
 
+// Appends a group (null/empty label = none); bars carry their own label, text and styles. False if
+// a bar label or text value is not valid UTF-8.
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_add_group(
+    b: *mut FfiBarChart,
+    label_spans: *const crate::FfiSpan,
+    label_len: usize,
+    bars: *const FfiBar,
+    len: usize,
+) -> bool {
+    if b.is_null() || (bars.is_null() && len > 0) {
+        return false;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return false;
+        }
+    }
+    let bc = unsafe { &mut *b };
+    let slice = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(bars, len) }
+    };
+    let mut out: Vec<Bar<'static>> = Vec::with_capacity(len);
+    for fb in slice {
+        let mut bar = Bar::default()
+            .value(fb.value)
+            .style(crate::style_from_ffi(fb.style))
+            .value_style(crate::style_from_ffi(fb.value_style));
+        if !fb.label.spans.is_null() && fb.label.len > 0 {
+            let Some(sp) = crate::spans_from_ffi(fb.label.spans, fb.label.len) else {
+                return false;
+            };
+            bar = bar.label(Line::from(sp));
+        }
+        let Some(bar) = bar_text_value(bar, fb.text_value_utf8) else {
+            return false;
+        };
+        out.push(bar);
+    }
+    let mut group = BarGroup::default().bars(&out);
+    if !label_spans.is_null() && label_len > 0 {
+        let Some(sp) = crate::spans_from_ffi(label_spans, label_len) else {
+            return false;
+        };
+        group = group.label(Line::from(sp));
+    }
+    bc.groups.push(group);
+    true
+}
+
+fn bar_text_value(bar: Bar<'static>, text_value_utf8: *const c_char) -> Option<Bar<'static>> {
+    if text_value_utf8.is_null() {
+        return Some(bar);
+    }
+    let txt = unsafe { CStr::from_ptr(text_value_utf8) }.to_str().ok()?;
+    Some(bar.text_value(txt.to_string()))
+}
+
+// `ratatui_barchart_add_group` with `FfiStyleEx` bar styles and `FfiLineSpansEx` labels.
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_add_group_ex(
+    b: *mut FfiBarChart,
+    label_spans: *const FfiSpanEx,
+    label_len: usize,
+    bars: *const FfiBarEx,
+    len: usize,
+) -> bool {
+    if b.is_null() || (bars.is_null() && len > 0) {
+        return false;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return false;
+        }
+    }
+    let bc = unsafe { &mut *b };
+    let slice = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(bars, len) }
+    };
+    let mut out: Vec<Bar<'static>> = Vec::with_capacity(len);
+    for fb in slice {
+        let mut bar = Bar::default()
+            .value(fb.value)
+            .style(crate::style_from_ffi_ex(fb.style))
+            .value_style(crate::style_from_ffi_ex(fb.value_style));
+        if !fb.label.spans.is_null() && fb.label.len > 0 {
+            bar = bar.label(crate::line_from_ffi_ex(&fb.label));
+        }
+        let Some(bar) = bar_text_value(bar, fb.text_value_utf8) else {
+            return false;
+        };
+        out.push(bar);
+    }
+    let mut group = BarGroup::default().bars(&out);
+    if !label_spans.is_null() && label_len > 0 {
+        let Some(sp) = crate::spans_from_ffi_ex(label_spans, label_len) else {
+            return false;
+        };
+        group = group.label(Line::from(sp));
+    }
+    bc.groups.push(group);
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_clear_groups(b: *mut FfiBarChart) {
+    if b.is_null() {
+        return;
+    }
+    unsafe { &mut *b }.groups.clear();
+}
+
+// 0 = vertical bars (default), 1 = horizontal bars with labels on the left.
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_set_direction(b: *mut FfiBarChart, direction: u32) {
+    if b.is_null() {
+        return;
+    }
+    unsafe { &mut *b }.direction = Some(if direction == 1 {
+        Direction::Horizontal
+    } else {
+        Direction::Vertical
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_set_group_gap(b: *mut FfiBarChart, gap: u16) {
+    if b.is_null() {
+        return;
+    }
+    unsafe {
+        (&mut *b).group_gap = Some(gap);
+    }
+}
+
+// Value drawn as a full bar (defaults to the largest value).
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_set_max(b: *mut FfiBarChart, max: u64) {
+    if b.is_null() {
+        return;
+    }
+    unsafe {
+        (&mut *b).max = Some(max);
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_barchart_set_styles(
     b: *mut FfiBarChart,
@@ -149,9 +398,37 @@ pub extern "C" fn ratatui_barchart_set_styles(
         return;
     }
     let bc = unsafe { &mut *b };
-    bc.bar_style = Some(crate::style_from_ffi(bar));
-    bc.value_style = Some(crate::style_from_ffi(value));
-    bc.label_style = Some(crate::style_from_ffi(label));
+    bc.bar_style = Some(crate::style_from_ffi(bar).into());
+    bc.value_style = Some(crate::style_from_ffi(value).into());
+    bc.label_style = Some(crate::style_from_ffi(label).into());
+}
+
+crate::ratatui_set_style_slot_fn!(ratatui_barchart_set_bar_style_slot, FfiBarChart, bar_style);
+crate::ratatui_set_style_slot_fn!(
+    ratatui_barchart_set_value_style_slot,
+    FfiBarChart,
+    value_style
+);
+crate::ratatui_set_style_slot_fn!(
+    ratatui_barchart_set_label_style_slot,
+    FfiBarChart,
+    label_style
+);
+
+#[no_mangle]
+pub extern "C" fn ratatui_barchart_set_styles_ex(
+    b: *mut FfiBarChart,
+    bar: crate::FfiStyleEx,
+    value: crate::FfiStyleEx,
+    label: crate::FfiStyleEx,
+) {
+    if b.is_null() {
+        return;
+    }
+    let bc = unsafe { &mut *b };
+    bc.bar_style = Some(crate::style_from_ffi_ex(bar).into());
+    bc.value_style = Some(crate::style_from_ffi_ex(value).into());
+    bc.label_style = Some(crate::style_from_ffi_ex(label).into());
 }
 
 #[no_mangle]
@@ -178,16 +455,7 @@ pub extern "C" fn ratatui_terminal_draw_barchart_in(
                 return false;
             }
         }
-        let data: Vec<(&str, u64)> = bc
-            .labels
-            .iter()
-            .map(|s| s.as_str())
-            .zip(bc.values.iter().cloned())
-            .collect();
-        let mut w = RtBarChart::default().data(&data);
-        if let Some(bl) = &bc.block {
-            w = w.block(bl.clone());
-        }
+        let w = bc.widget();
         let res = t.terminal.draw(|frame| {
             frame.render_widget(w.clone(), area);
         });
@@ -213,16 +481,7 @@ pub extern "C" fn ratatui_headless_render_barchart(
         height,
     };
     let mut buf = Buffer::empty(area);
-    let data: Vec<(&str, u64)> = bc
-        .labels
-        .iter()
-        .map(|s| s.as_str())
-        .zip(bc.values.iter().cloned())
-        .collect();
-    let mut w = RtBarChart::default().data(&data);
-    if let Some(bl) = &bc.block {
-        w = w.block(bl.clone());
-    }
+    let w = bc.widget();
     ratatui::widgets::Widget::render(w, area, &mut buf);
     let mut s = String::new();
     for y in 0..height {
@@ -267,4 +526,142 @@ pub extern "C" fn ratatui_chart_set_labels_alignment(c: *mut FfiChart, x_align:
 
 ratatui_block_title_fn!(ratatui_barchart_set_block_title, FfiBarChart);
 ratatui_block_title_spans_fn!(ratatui_barchart_set_block_title_spans, FfiBarChart);
+crate::ratatui_block_title_markup_fn!(ratatui_barchart_set_block_title_markup, FfiBarChart);
+
+crate::ratatui_block_title_text_fn!(ratatui_barchart_set_block_title_text, FfiBarChart);
 ratatui_block_adv_fn!(ratatui_barchart_set_block_adv, FfiBarChart);
+crate::ratatui_block_add_title_fn!(
+    ratatui_barchart_add_block_title_spans,
+    ratatui_barchart_add_block_title_markup,
+    FfiBarChart
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_barchart_set_block_title_spans_ex,
+    ratatui_barchart_add_block_title_spans_ex,
+    FfiBarChart
+);
+crate::ratatui_block_style_fn!(ratatui_barchart_set_block_style, FfiBarChart, style);
+crate::ratatui_block_style_fn!(
+    ratatui_barchart_set_block_border_style,
+    FfiBarChart,
+    border_style
+);
+crate::ratatui_block_style_fn!(
+    ratatui_barchart_set_block_title_style,
+    FfiBarChart,
+    title_style
+);
+crate::ratatui_block_style_ex_fn!(ratatui_barchart_set_block_style_ex, FfiBarChart, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_barchart_set_block_border_style_ex,
+    FfiBarChart,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_barchart_set_block_title_style_ex,
+    FfiBarChart,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_barchart_set_block_style_slot, FfiBarChart, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_barchart_set_block_border_style_slot,
+    FfiBarChart,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_barchart_set_block_title_style_slot,
+    FfiBarChart,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_barchart_set_block_border_set, FfiBarChart);
+crate::ratatui_block_set_fn!(ratatui_barchart_set_block, FfiBarChart);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAIN: FfiStyle = FfiStyle {
+        fg: 0,
+        bg: 0,
+        mods: 0,
+    };
+
+    fn render(b: *const FfiBarChart, width: u16, height: u16) -> String {
+        let mut out = std::ptr::null_mut();
+        assert!(ratatui_headless_render_barchart(width, height, b, &mut out));
+        unsafe { CString::from_raw(out) }.into_string().unwrap()
+    }
+
+    fn bar(value: u64) -> FfiBar {
+        FfiBar {
+            value,
+            label: FfiLineSpans {
+                spans: std::ptr::null(),
+                len: 0,
+            },
+            text_value_utf8: std::ptr::null(),
+            style: PLAIN,
+            value_style: PLAIN,
+        }
+    }
+
+    fn add_group(b: *mut FfiBarChart, label: &str, bars: &[FfiBar]) -> bool {
+        let text = CString::new(label).unwrap();
+        let span = crate::FfiSpan {
+            text_utf8: text.as_ptr(),
+            style: PLAIN,
+        };
+        ratatui_barchart_add_group(b, &span, 1, bars.as_ptr(), bars.len())
+    }
+
+    #[test]
+    fn grouped_bars_with_gaps_and_group_labels() {
+        let b = ratatui_barchart_new();
+        assert!(add_group(b, "A", &[bar(2), bar(4)]));
+        assert!(add_group(b, "B", &[bar(1), bar(3)]));
+        ratatui_barchart_set_bar_width(b, 1);
+        ratatui_barchart_set_bar_gap(b, 1);
+        ratatui_barchart_set_group_gap(b, 2);
+        let rows = [
+            "  █       ",
+            "  █     █ ",
+            "█ █     █ ",
+            "2 4   1 3 ",
+            "A     B   ",
+        ];
+        assert_eq!(render(b, 10, 5), rows.join("\n"));
+        ratatui_barchart_clear_groups(b);
+        assert_eq!(render(b, 4, 1), "    ");
+        ratatui_barchart_free(b);
+    }
+
+    #[test]
+    fn horizontal_bars_with_labels_text_values_and_max() {
+        let b = ratatui_barchart_new();
+        let (cpu, mem) = (CString::new("cpu").unwrap(), CString::new("mem").unwrap());
+        let label = |c: &CString| crate::FfiSpan {
+            text_utf8: c.as_ptr(),
+            style: PLAIN,
+        };
+        let (cpu, mem) = (label(&cpu), label(&mem));
+        let hi = CString::new("hi").unwrap();
+        let mut bars = [bar(3), bar(6)];
+        bars[0].label = FfiLineSpans {
+            spans: &cpu,
+            len: 1,
+        };
+        bars[1].label = FfiLineSpans {
+            spans: &mem,
+            len: 1,
+        };
+        bars[1].text_value_utf8 = hi.as_ptr();
+        let (ptr, len) = (bars.as_ptr(), bars.len());
+        assert!(ratatui_barchart_add_group(b, std::ptr::null(), 0, ptr, len));
+        ratatui_barchart_set_direction(b, 1);
+        ratatui_barchart_set_bar_gap(b, 0);
+        assert_eq!(render(b, 10, 2), "cpu 3██   \nmem hi████");
+        ratatui_barchart_set_max(b, 12);
+        assert_eq!(render(b, 10, 2), "cpu 3     \nmem hi█   ");
+        ratatui_barchart_free(b);
+    }
+}
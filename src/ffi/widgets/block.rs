@@ -0,0 +1,293 @@
+// Standalone Block: configured once, drawn on its own (draw kind `Block`) around host-drawn
+// content, or attached by reference to any block-bearing widget with `ratatui_<widget>_set_block`.
+// Like FfiText, widgets keep a clone of the Arc, so edits through the handle show up on their
+// next draw and freeing the handle does not invalidate them.
+
+use crate::ffi::text::{read_text, SharedText};
+use crate::ffi::theme::resolve_slot;
+use crate::*;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, Padding};
+use std::ffi::CString;
+use std::ops::DerefMut;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+pub type SharedBlock = Arc<RwLock<BlockState>>;
+
+#[derive(Default)]
+pub struct BlockState {
+    pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
+}
+
+// Block state resolved at draw time, kept beside every widget's `block`: an attached FfiText
+// title (one title per line), theme slots bound to the block styles and an attached FfiBlock,
+// which replaces the widget's own block while set. Setters that start a fresh block reset it.
+#[derive(Clone, Default)]
+pub struct BlockExtras {
+    pub title_text: Option<SharedText>,
+    pub slots: BlockSlots,
+    pub shared: Option<SharedBlock>,
+}
+
+// Theme slots of the block styles (0 = unbound); the fixed style setters unbind them.
+#[derive(Copy, Clone, Default)]
+pub struct BlockSlots {
+    pub style: u32,
+    pub border_style: u32,
+    pub title_style: u32,
+}
+
+impl BlockExtras {
+    // `block` with the extras applied; a title or slot alone creates a borderless block.
+    pub(crate) fn apply(&self, block: &Option<Block<'static>>) -> Option<Block<'static>> {
+        if let Some(s) = &self.shared {
+            let st = read_block(s);
+            return st.block_extras.apply(&st.block);
+        }
+        let BlockSlots {
+            style,
+            border_style,
+            title_style,
+        } = self.slots;
+        if self.title_text.is_none() && style == 0 && border_style == 0 && title_style == 0 {
+            return block.clone();
+        }
+        let mut b = block.clone().unwrap_or_default();
+        if style != 0 {
+            b = b.style(resolve_slot(style));
+        }
+        if border_style != 0 {
+            b = b.border_style(resolve_slot(border_style));
+        }
+        if title_style != 0 {
+            b = b.title_style(resolve_slot(title_style));
+        }
+        let Some(t) = &self.title_text else {
+            return Some(b);
+        };
+        let text = read_text(t);
+        for line in text.lines.iter() {
+            let mut line = line.clone().patch_style(text.style);
+            if line.alignment.is_none() {
+                line.alignment = text.alignment;
+            }
+            b = b.title(line);
+        }
+        Some(b)
+    }
+}
+
+fn read_block(b: &SharedBlock) -> RwLockReadGuard<'_, BlockState> {
+    b.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+// What the shared `ratatui_block_*_fn!` setters edit: a widget edits its own `block` /
+// `block_extras` (an attached FfiBlock is copied in first, detaching it), an FfiBlock edits the
+// state behind its lock.
+pub trait BlockHost {
+    type Guard<'a>: DerefMut
+    where
+        Self: 'a;
+
+    fn block_host(&mut self) -> Self::Guard<'_>;
+}
+
+pub struct FfiBlock {
+    pub block: SharedBlock,
+}
+
+impl FfiBlock {
+    pub(crate) fn widget(&self) -> Block<'static> {
+        let st = read_block(&self.block);
+        st.block_extras.apply(&st.block).unwrap_or_default()
+    }
+}
+
+impl BlockHost for FfiBlock {
+    type Guard<'a> = RwLockWriteGuard<'a, BlockState>;
+
+    fn block_host(&mut self) -> Self::Guard<'_> {
+        self.block.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// Copies the state of an attached FfiBlock into the widget's own fields and detaches it.
+pub(crate) fn detach_shared(block: &mut Option<Block<'static>>, extras: &mut BlockExtras) {
+    let Some(s) = extras.shared.take() else {
+        return;
+    };
+    let st = read_block(&s);
+    *block = st.block.clone();
+    *extras = st.block_extras.clone();
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_block_new() -> *mut FfiBlock {
+    Box::into_raw(Box::new(FfiBlock {
+        block: Arc::new(RwLock::new(BlockState {
+            block: Some(Block::default()),
+            block_extras: BlockExtras::default(),
+        })),
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_block_free(b: *mut FfiBlock) {
+    if b.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(b));
+    }
+}
+
+// `FfiBorders` bits; unlike `ratatui_block_set_adv` this keeps titles, styles and padding.
+#[no_mangle]
+pub extern "C" fn ratatui_block_set_borders(b: *mut FfiBlock, borders_bits: u8) {
+    if b.is_null() {
+        return;
+    }
+    let mut bb = unsafe { &mut *b }.block_host();
+    let block = bb.block.take().unwrap_or_default();
+    bb.block = Some(block.borders(borders_from_bits(borders_bits)));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_block_set_border_type(b: *mut FfiBlock, border_type: u32) {
+    if b.is_null() {
+        return;
+    }
+    let mut bb = unsafe { &mut *b }.block_host();
+    let block = bb.block.take().unwrap_or_default();
+    bb.block = Some(block.border_type(border_type_from_u32(border_type)));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_block_set_padding(
+    b: *mut FfiBlock,
+    pad_l: u16,
+    pad_t: u16,
+    pad_r: u16,
+    pad_b: u16,
+) {
+    if b.is_null() {
+        return;
+    }
+    let mut bb = unsafe { &mut *b }.block_host();
+    let block = bb.block.take().unwrap_or_default();
+    bb.block = Some(block.padding(Padding::new(pad_l, pad_r, pad_t, pad_b)));
+}
+
+crate::ratatui_block_title_fn!(ratatui_block_set_title, FfiBlock);
+crate::ratatui_block_title_spans_fn!(ratatui_block_set_title_spans, FfiBlock);
+crate::ratatui_block_title_markup_fn!(ratatui_block_set_title_markup, FfiBlock);
+
+crate::ratatui_block_title_text_fn!(ratatui_block_set_title_text, FfiBlock);
+crate::ratatui_block_title_alignment_fn!(ratatui_block_set_title_alignment, FfiBlock);
+crate::ratatui_block_adv_fn!(ratatui_block_set_adv, FfiBlock);
+crate::ratatui_block_add_title_fn!(
+    ratatui_block_add_title_spans,
+    ratatui_block_add_title_markup,
+    FfiBlock
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_block_set_title_spans_ex,
+    ratatui_block_add_title_spans_ex,
+    FfiBlock
+);
+crate::ratatui_block_style_fn!(ratatui_block_set_style, FfiBlock, style);
+crate::ratatui_block_style_fn!(ratatui_block_set_border_style, FfiBlock, border_style);
+crate::ratatui_block_style_fn!(ratatui_block_set_title_style, FfiBlock, title_style);
+crate::ratatui_block_style_ex_fn!(ratatui_block_set_style_ex, FfiBlock, style);
+crate::ratatui_block_style_ex_fn!(ratatui_block_set_border_style_ex, FfiBlock, border_style);
+crate::ratatui_block_style_ex_fn!(ratatui_block_set_title_style_ex, FfiBlock, title_style);
+crate::ratatui_block_style_slot_fn!(ratatui_block_set_style_slot, FfiBlock, style);
+crate::ratatui_block_style_slot_fn!(ratatui_block_set_border_style_slot, FfiBlock, border_style);
+crate::ratatui_block_style_slot_fn!(ratatui_block_set_title_style_slot, FfiBlock, title_style);
+crate::ratatui_block_border_set_fn!(ratatui_block_set_border_set, FfiBlock);
+
+// Area left for content inside borders, titles and padding (`rect` itself for a null block).
+#[no_mangle]
+pub extern "C" fn ratatui_block_inner(b: *const FfiBlock, rect: FfiRect) -> FfiRect {
+    if b.is_null() {
+        return rect;
+    }
+    let area = Rect {
+        x: rect.x,
+        y: rect.y,
+        width: rect.width,
+        height: rect.height,
+    };
+    let inner = unsafe { &*b }.widget().inner(area);
+    FfiRect {
+        x: inner.x,
+        y: inner.y,
+        width: inner.width,
+        height: inner.height,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_terminal_draw_block_in(
+    term: *mut FfiTerminal,
+    b: *const FfiBlock,
+    rect: FfiRect,
+) -> bool {
+    crate::guard_bool("ratatui_terminal_draw_block_in", || {
+        if term.is_null() || b.is_null() {
+            return false;
+        }
+        let t = unsafe { &mut *term };
+        let w = unsafe { &*b }.widget();
+        let area = Rect {
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        };
+        let res = t.terminal.draw(|frame| {
+            frame.render_widget(w, area);
+        });
+        res.is_ok()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_headless_render_block(
+    width: u16,
+    height: u16,
+    b: *const FfiBlock,
+    out_text_utf8: *mut *mut std::os::raw::c_char,
+) -> bool {
+    if b.is_null() || out_text_utf8.is_null() {
+        return false;
+    }
+    let area = Rect {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    };
+    let mut buf = Buffer::empty(area);
+    ratatui::widgets::Widget::render(unsafe { &*b }.widget(), area, &mut buf);
+    let mut s = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            s.push_str(buf[(x, y)].symbol());
+        }
+        if y + 1 < height {
+            s.push('\n');
+        }
+    }
+    match CString::new(s) {
+        Ok(cstr) => {
+            unsafe {
+                *out_text_utf8 = cstr.into_raw();
+            }
+            true
+        }
+        Err(_) => false,
+    }
+}
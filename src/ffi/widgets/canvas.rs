@@ -12,20 +12,24 @@
 
 // use crate::*; // enable when moving implementations
 
+use crate::ffi::widgets::block::BlockExtras;
 use crate::{
     ratatui_block_title_alignment_fn, ratatui_block_title_fn, ratatui_block_title_spans_fn,
-    FfiRect, FfiSpan, FfiStyle, FfiTerminal,
+    FfiMapResolution, FfiRect, FfiSpan, FfiStyle, FfiStyleEx, FfiTerminal,
 };
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::Color;
 use ratatui::symbols::Marker as RtMarker;
+use ratatui::text::Line;
 use ratatui::widgets::canvas::{
-    Canvas as RtCanvas, Line as RtCanvasLine, Points as RtCanvasPoints, Rectangle as RtCanvasRect,
+    Canvas as RtCanvas, Circle as RtCircle, Context, Line as RtCanvasLine, Map as RtMap,
+    MapResolution, Painter, Points as RtCanvasPoints, Rectangle as RtCanvasRect, Shape,
 };
 use ratatui::widgets::Block;
 use std::ffi::{c_char, CString};
 
+#[allow(dead_code)]
 #[repr(C)]
 pub struct FfiCanvasLine {
     pub x1: f64,
@@ -35,7 +39,8 @@ pub struct FfiCanvasLine {
     pub style: FfiStyle,
 }
 
-#[allow(dead_code)]
+// One entry of `ratatui_canvas_add_points_batch`. `marker` is read as an `i32` override, so
+// `UINT32_MAX` (-1) keeps the canvas marker.
 #[repr(C)]
 pub struct FfiCanvasPoints {
     pub points_xy: *const f64,
@@ -44,6 +49,7 @@ pub struct FfiCanvasPoints {
     pub marker: u32,
 }
 
+#[allow(dead_code)]
 #[repr(C)]
 pub struct FfiCanvasRect {
     pub x: f64,
@@ -62,16 +68,437 @@ pub struct FfiCanvas {
     pub y_max: f64,
     pub background: Option<Color>,
     pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
     pub marker: Option<RtMarker>,
-    pub lines: Vec<FfiCanvasLine>,
-    pub rects: Vec<FfiCanvasRect>,
-    pub pts: Vec<(Vec<(f64, f64)>, Color)>,
+    pub shapes: Vec<CanvasShape>, // ascending id order
+    pub layer: i32,               // layer given to newly added shapes
+    pub next_id: u64,
+}
+
+pub enum CanvasShapeKind {
+    Line {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        color: Color,
+    },
+    Rect {
+        x: f64,
+        y: f64,
+        w: f64,
+        h: f64,
+        color: Color,
+        filled: bool,
+    },
+    Points {
+        coords: Vec<(f64, f64)>,
+        color: Color,
+    },
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        color: Color,
+    },
+    Map {
+        resolution: MapResolution,
+        color: Color,
+    },
+    Label {
+        x: f64,
+        y: f64,
+        line: Line<'static>,
+    },
+}
+
+pub struct CanvasShape {
+    pub id: u64,
+    pub layer: i32,
+    pub hidden: bool,
+    pub marker: Option<RtMarker>, // None = the canvas marker
+    pub kind: CanvasShapeKind,
+}
+
+// ratatui's Rectangle only strokes the outline; this paints every grid point inside.
+struct FilledRect {
+    left: f64,
+    bottom: f64,
+    right: f64,
+    top: f64,
+    color: Color,
+}
+
+impl Shape for FilledRect {
+    fn draw(&self, painter: &mut Painter) {
+        let (Some((x0, y0)), Some((x1, y1))) = (
+            painter.get_point(self.left, self.top),
+            painter.get_point(self.right, self.bottom),
+        ) else {
+            return;
+        };
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                painter.paint(x, y, self.color);
+            }
+        }
+    }
+}
+
+impl FfiCanvas {
+    fn push_shape(&mut self, kind: CanvasShapeKind) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.shapes.push(CanvasShape {
+            id,
+            layer: self.layer,
+            hidden: false,
+            marker: None,
+            kind,
+        });
+        id
+    }
+
+    fn shape_mut(&mut self, id: u64) -> Option<&mut CanvasShape> {
+        let i = self.shapes.binary_search_by_key(&id, |s| s.id).ok()?;
+        Some(&mut self.shapes[i])
+    }
+
+    fn replace_shape(&mut self, id: u64, kind: CanvasShapeKind) -> bool {
+        match self.shape_mut(id) {
+            Some(s) => {
+                s.kind = kind;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // `shapes` arrive sorted by layer; each layer goes on its own ratatui canvas layer so higher
+    // layers overdraw lower ones.
+    fn paint(&self, ctx: &mut Context, shapes: &[&CanvasShape]) {
+        let mut current = shapes.first().map(|s| s.layer);
+        for s in shapes {
+            if current != Some(s.layer) {
+                ctx.layer();
+                current = Some(s.layer);
+            }
+            match &s.kind {
+                &CanvasShapeKind::Line {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    color,
+                } => ctx.draw(&RtCanvasLine {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    color,
+                }),
+                &CanvasShapeKind::Rect {
+                    x,
+                    y,
+                    w,
+                    h,
+                    color,
+                    filled,
+                } => {
+                    if filled {
+                        let (left, bottom) = (x.max(self.x_min), y.max(self.y_min));
+                        let (right, top) = ((x + w).min(self.x_max), (y + h).min(self.y_max));
+                        if left <= right && bottom <= top {
+                            ctx.draw(&FilledRect {
+                                left,
+                                bottom,
+                                right,
+                                top,
+                                color,
+                            });
+                        }
+                    } else {
+                        ctx.draw(&RtCanvasRect {
+                            x,
+                            y,
+                            width: w,
+                            height: h,
+                            color,
+                        });
+                    }
+                }
+                CanvasShapeKind::Points { coords, color } => ctx.draw(&RtCanvasPoints {
+                    coords: &coords[..],
+                    color: *color,
+                }),
+                CanvasShapeKind::Circle {
+                    x,
+                    y,
+                    radius,
+                    color,
+                } => ctx.draw(&RtCircle {
+                    x: *x,
+                    y: *y,
+                    radius: *radius,
+                    color: *color,
+                }),
+                CanvasShapeKind::Map { resolution, color } => ctx.draw(&RtMap {
+                    resolution: *resolution,
+                    color: *color,
+                }),
+                CanvasShapeKind::Label { x, y, line } => ctx.print(*x, *y, line.clone()),
+            }
+        }
+    }
+
+    fn canvas<'a>(
+        &'a self,
+        marker: RtMarker,
+        shapes: &'a [&'a CanvasShape],
+    ) -> RtCanvas<'a, impl Fn(&mut Context) + 'a> {
+        RtCanvas::default()
+            .x_bounds([self.x_min, self.x_max])
+            .y_bounds([self.y_min, self.y_max])
+            .marker(marker)
+            .paint(move |ctx| self.paint(ctx, shapes))
+    }
+
+    // Draws shapes onto a blank scratch buffer and copies the painted cells over `buf`, the same
+    // way ratatui merges canvas layers, so a pass with another marker overdraws earlier passes.
+    fn overlay(&self, area: Rect, buf: &mut Buffer, marker: RtMarker, shapes: &[&CanvasShape]) {
+        let mut scratch = Buffer::empty(area);
+        ratatui::widgets::Widget::render(self.canvas(marker, shapes), area, &mut scratch);
+        for pos in area.positions() {
+            let src = &scratch[pos];
+            if src.symbol() == " " {
+                continue;
+            }
+            let dst = &mut buf[pos];
+            dst.set_symbol(src.symbol());
+            if src.fg != Color::Reset {
+                dst.fg = src.fg;
+            }
+            if src.bg != Color::Reset {
+                dst.bg = src.bg;
+            }
+        }
+    }
+
+    // Visible shapes draw in ascending layer order (add order within a layer); labels always end
+    // up on top. ratatui has one marker per canvas, so shapes with marker overrides are drawn as
+    // consecutive same-marker passes instead of a single canvas.
+    pub(crate) fn render_into(&self, area: Rect, buf: &mut Buffer) {
+        let mut order: Vec<&CanvasShape> = self.shapes.iter().filter(|s| !s.hidden).collect();
+        order.sort_by_key(|s| s.layer);
+        let canvas_marker = self.marker.unwrap_or(RtMarker::Braille);
+        let marker_of = |s: &CanvasShape| s.marker.unwrap_or(canvas_marker);
+        let is_label = |s: &CanvasShape| matches!(s.kind, CanvasShapeKind::Label { .. });
+        let mixed = order
+            .iter()
+            .any(|s| !is_label(s) && marker_of(s) != canvas_marker);
+        let mut w = self.canvas(canvas_marker, if mixed { &[] } else { &order });
+        if let Some(bg) = self.background {
+            w = w.background_color(bg);
+        }
+        if let Some(b) = self.block_extras.apply(&self.block) {
+            w = w.block(b);
+        }
+        ratatui::widgets::Widget::render(w, area, buf);
+        if !mixed {
+            return;
+        }
+        let inner = self
+            .block_extras
+            .apply(&self.block)
+            .map_or(area, |b| b.inner(area));
+        let (labels, shapes): (Vec<&CanvasShape>, Vec<&CanvasShape>) =
+            order.into_iter().partition(|s| is_label(s));
+        for run in shapes.chunk_by(|a, b| marker_of(a) == marker_of(b)) {
+            self.overlay(inner, buf, marker_of(run[0]), run);
+        }
+        if !labels.is_empty() {
+            self.overlay(inner, buf, canvas_marker, &labels);
+        }
+    }
+}
+
+// Same numbering as `ratatui_canvas_set_marker`.
+fn marker_from_u32(marker: u32) -> RtMarker {
+    match marker {
+        1 => RtMarker::Braille,
+        2 => RtMarker::Block,
+        3 => RtMarker::HalfBlock,
+        _ => RtMarker::Dot,
+    }
+}
+
+// Per-shape override: negative means "use the canvas marker".
+fn marker_from_i32(marker: i32) -> Option<RtMarker> {
+    (marker >= 0).then(|| marker_from_u32(marker as u32))
+}
+
+// `len_pairs` interleaved x,y pairs; None when empty, when the length overflows or when it
+// exceeds the batch cap.
+fn points_from_ffi(points_xy: *const f64, len_pairs: usize) -> Option<Vec<(f64, f64)>> {
+    if points_xy.is_null() || len_pairs == 0 {
+        return None;
+    }
+    let len = len_pairs.checked_mul(2)?;
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len_pairs) {
+            return None;
+        }
+    }
+    let slice = unsafe { std::slice::from_raw_parts(points_xy, len) };
+    Some(slice.chunks_exact(2).map(|p| (p[0], p[1])).collect())
+}
+
+// Shapes take only the style's foreground; unset draws white.
+fn shape_color(fg: u32) -> Color {
+    crate::color_from_u32(fg).unwrap_or(Color::White)
+}
+
+fn line_kind(x1: f64, y1: f64, x2: f64, y2: f64, fg: u32) -> CanvasShapeKind {
+    CanvasShapeKind::Line {
+        x1,
+        y1,
+        x2,
+        y2,
+        color: shape_color(fg),
+    }
+}
+
+fn rect_kind(x: f64, y: f64, w: f64, h: f64, fg: u32, filled: bool) -> CanvasShapeKind {
+    CanvasShapeKind::Rect {
+        x,
+        y,
+        w,
+        h,
+        color: shape_color(fg),
+        filled,
+    }
+}
+
+fn points_kind(points_xy: *const f64, len_pairs: usize, fg: u32) -> Option<CanvasShapeKind> {
+    Some(CanvasShapeKind::Points {
+        coords: points_from_ffi(points_xy, len_pairs)?,
+        color: shape_color(fg),
+    })
+}
+
+fn circle_kind(x: f64, y: f64, radius: f64, fg: u32) -> CanvasShapeKind {
+    CanvasShapeKind::Circle {
+        x,
+        y,
+        radius,
+        color: shape_color(fg),
+    }
+}
+
+fn map_kind(resolution: u32, fg: u32) -> CanvasShapeKind {
+    CanvasShapeKind::Map {
+        resolution: if resolution == FfiMapResolution::High as u32 {
+            MapResolution::High
+        } else {
+            MapResolution::Low
+        },
+        color: shape_color(fg),
+    }
+}
+
+fn add_shape(c: *mut FfiCanvas, kind: CanvasShapeKind) -> u64 {
+    if c.is_null() {
+        return 0;
+    }
+    unsafe { &mut *c }.push_shape(kind)
+}
+
+fn update_shape(c: *mut FfiCanvas, id: u64, kind: CanvasShapeKind) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    unsafe { &mut *c }.replace_shape(id, kind)
+}
+
+fn add_points(
+    c: *mut FfiCanvas,
+    points_xy: *const f64,
+    len_pairs: usize,
+    fg: u32,
+    marker: i32,
+) -> u64 {
+    if c.is_null() {
+        return 0;
+    }
+    let Some(kind) = points_kind(points_xy, len_pairs, fg) else {
+        return 0;
+    };
+    let cv = unsafe { &mut *c };
+    let id = cv.push_shape(kind);
+    if let Some(shape) = cv.shape_mut(id) {
+        shape.marker = marker_from_i32(marker);
+    }
+    id
+}
+
+fn label_kind(x: f64, y: f64, spans: *const FfiSpan, len: usize) -> Option<CanvasShapeKind> {
+    let sp = crate::spans_from_ffi(spans, len)?;
+    Some(CanvasShapeKind::Label {
+        x,
+        y,
+        line: Line::from(sp),
+    })
 }
 
 // Canvas block/title helpers generated via macros
 ratatui_block_title_fn!(ratatui_canvas_set_block_title, FfiCanvas);
 ratatui_block_title_spans_fn!(ratatui_canvas_set_block_title_spans, FfiCanvas);
+crate::ratatui_block_title_markup_fn!(ratatui_canvas_set_block_title_markup, FfiCanvas);
+
+crate::ratatui_block_title_text_fn!(ratatui_canvas_set_block_title_text, FfiCanvas);
 ratatui_block_title_alignment_fn!(ratatui_canvas_set_block_title_alignment, FfiCanvas);
+crate::ratatui_block_add_title_fn!(
+    ratatui_canvas_add_block_title_spans,
+    ratatui_canvas_add_block_title_markup,
+    FfiCanvas
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_canvas_set_block_title_spans_ex,
+    ratatui_canvas_add_block_title_spans_ex,
+    FfiCanvas
+);
+crate::ratatui_block_style_fn!(ratatui_canvas_set_block_style, FfiCanvas, style);
+crate::ratatui_block_style_fn!(
+    ratatui_canvas_set_block_border_style,
+    FfiCanvas,
+    border_style
+);
+crate::ratatui_block_style_fn!(ratatui_canvas_set_block_title_style, FfiCanvas, title_style);
+crate::ratatui_block_style_ex_fn!(ratatui_canvas_set_block_style_ex, FfiCanvas, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_canvas_set_block_border_style_ex,
+    FfiCanvas,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_canvas_set_block_title_style_ex,
+    FfiCanvas,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_canvas_set_block_style_slot, FfiCanvas, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_canvas_set_block_border_style_slot,
+    FfiCanvas,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_canvas_set_block_title_style_slot,
+    FfiCanvas,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_canvas_set_block_border_set, FfiCanvas);
+crate::ratatui_block_set_fn!(ratatui_canvas_set_block, FfiCanvas);
 
 #[no_mangle]
 pub extern "C" fn ratatui_canvas_new(
@@ -87,10 +514,11 @@ pub extern "C" fn ratatui_canvas_new(
         y_max,
         background: None,
         block: None,
+        block_extras: BlockExtras::default(),
         marker: None,
-        lines: Vec::new(),
-        rects: Vec::new(),
-        pts: Vec::new(),
+        shapes: Vec::new(),
+        layer: 0,
+        next_id: 1,
     }))
 }
 
@@ -150,6 +578,7 @@ pub extern "C" fn ratatui_canvas_set_block_adv(
         return;
     }
     let cv = unsafe { &mut *c };
+    cv.block_extras = Default::default();
     cv.block = Some(crate::build_block_from_adv(
         borders_bits,
         border_type,
@@ -168,14 +597,12 @@ pub extern "C" fn ratatui_canvas_set_marker(c: *mut FfiCanvas, marker: u32) {
         return;
     }
     let cv = unsafe { &mut *c };
-    cv.marker = Some(match marker {
-        1 => RtMarker::Braille,
-        2 => RtMarker::Block,
-        3 => RtMarker::HalfBlock,
-        _ => RtMarker::Dot,
-    });
+    cv.marker = Some(marker_from_u32(marker));
 }
 
+// Every add returns the new shape's id (never 0; 0 means nothing was added). Ids stay valid until
+// the shape is removed or the canvas cleared and are not reused. Shapes are drawn in one color:
+// the `_ex` variants take an `FfiStyleEx` for symmetry with other widgets but only read its `fg`.
 #[no_mangle]
 pub extern "C" fn ratatui_canvas_add_line(
     c: *mut FfiCanvas,
@@ -184,18 +611,20 @@ pub extern "C" fn ratatui_canvas_add_line(
     x2: f64,
     y2: f64,
     style: FfiStyle,
-) {
-    if c.is_null() {
-        return;
-    }
-    let cv = unsafe { &mut *c };
-    cv.lines.push(FfiCanvasLine {
-        x1,
-        y1,
-        x2,
-        y2,
-        style,
-    });
+) -> u64 {
+    add_shape(c, line_kind(x1, y1, x2, y2, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_line_ex(
+    c: *mut FfiCanvas,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    style: FfiStyleEx,
+) -> u64 {
+    add_shape(c, line_kind(x1, y1, x2, y2, style.fg))
 }
 
 #[no_mangle]
@@ -207,44 +636,367 @@ pub extern "C" fn ratatui_canvas_add_rect(
     h: f64,
     style: FfiStyle,
     filled: bool,
-) {
+) -> u64 {
+    add_shape(c, rect_kind(x, y, w, h, style.fg, filled))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_rect_ex(
+    c: *mut FfiCanvas,
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+    style: FfiStyleEx,
+    filled: bool,
+) -> u64 {
+    add_shape(c, rect_kind(x, y, w, h, style.fg, filled))
+}
+
+// `marker` is ignored: the points use the canvas marker. Use `ratatui_canvas_add_points_ex` to
+// pick one per shape.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_points(
+    c: *mut FfiCanvas,
+    points_xy: *const f64,
+    len_pairs: usize,
+    style: FfiStyle,
+    _marker: u32,
+) -> u64 {
+    add_points(c, points_xy, len_pairs, style.fg, -1)
+}
+
+// `marker` overrides the canvas marker for these points (`ratatui_canvas_set_marker` numbering);
+// negative inherits the canvas marker.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_points_ex(
+    c: *mut FfiCanvas,
+    points_xy: *const f64,
+    len_pairs: usize,
+    style: FfiStyleEx,
+    marker: i32,
+) -> u64 {
+    add_points(c, points_xy, len_pairs, style.fg, marker)
+}
+
+// Adds `len` point sets in one call and returns how many were added. `out_ids` (may be null)
+// receives one id per entry, `0` for an entry that was skipped (null or empty points).
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_points_batch(
+    c: *mut FfiCanvas,
+    sets: *const FfiCanvasPoints,
+    len: usize,
+    out_ids: *mut u64,
+) -> usize {
+    if c.is_null() || sets.is_null() || len == 0 {
+        return 0;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return 0;
+        }
+    }
+    let slice = unsafe { std::slice::from_raw_parts(sets, len) };
+    let mut added = 0;
+    for (i, s) in slice.iter().enumerate() {
+        let id = add_points(c, s.points_xy, s.len_pairs, s.style.fg, s.marker as i32);
+        if id != 0 {
+            added += 1;
+        }
+        if !out_ids.is_null() {
+            unsafe { *out_ids.add(i) = id };
+        }
+    }
+    added
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_circle(
+    c: *mut FfiCanvas,
+    x: f64,
+    y: f64,
+    radius: f64,
+    style: FfiStyle,
+) -> u64 {
+    add_shape(c, circle_kind(x, y, radius, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_circle_ex(
+    c: *mut FfiCanvas,
+    x: f64,
+    y: f64,
+    radius: f64,
+    style: FfiStyleEx,
+) -> u64 {
+    add_shape(c, circle_kind(x, y, radius, style.fg))
+}
+
+// World coastlines in lon/lat degrees; set bounds to [-180, 180] x [-90, 90] for the whole map.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_map(
+    c: *mut FfiCanvas,
+    resolution: u32,
+    style: FfiStyle,
+) -> u64 {
+    add_shape(c, map_kind(resolution, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_map_ex(
+    c: *mut FfiCanvas,
+    resolution: u32,
+    style: FfiStyleEx,
+) -> u64 {
+    add_shape(c, map_kind(resolution, style.fg))
+}
+
+// Text anchored at canvas coordinates (its first cell); drawn above every layer.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_add_label(
+    c: *mut FfiCanvas,
+    x: f64,
+    y: f64,
+    spans: *const FfiSpan,
+    len: usize,
+) -> u64 {
+    if c.is_null() {
+        return 0;
+    }
+    let Some(kind) = label_kind(x, y, spans, len) else {
+        return 0;
+    };
+    unsafe { &mut *c }.push_shape(kind)
+}
+
+// Layer for shapes added from now on (default 0). Layers draw in ascending order, so shapes on a
+// higher layer cover lower ones where they overlap.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_set_layer(c: *mut FfiCanvas, layer: i32) {
     if c.is_null() {
         return;
     }
-    let cv = unsafe { &mut *c };
-    cv.rects.push(FfiCanvasRect {
-        x,
-        y,
-        w,
-        h,
-        style,
-        filled,
-    });
+    unsafe { &mut *c }.layer = layer;
 }
 
+// Updates replace a shape's geometry and style in place (the kind may change); layer, visibility
+// and marker are kept. False for an unknown id.
 #[no_mangle]
-pub extern "C" fn ratatui_canvas_add_points(
+pub extern "C" fn ratatui_canvas_update_line(
+    c: *mut FfiCanvas,
+    id: u64,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    style: FfiStyle,
+) -> bool {
+    update_shape(c, id, line_kind(x1, y1, x2, y2, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_line_ex(
+    c: *mut FfiCanvas,
+    id: u64,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    style: FfiStyleEx,
+) -> bool {
+    update_shape(c, id, line_kind(x1, y1, x2, y2, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_rect(
     c: *mut FfiCanvas,
+    id: u64,
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+    style: FfiStyle,
+    filled: bool,
+) -> bool {
+    update_shape(c, id, rect_kind(x, y, w, h, style.fg, filled))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_rect_ex(
+    c: *mut FfiCanvas,
+    id: u64,
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+    style: FfiStyleEx,
+    filled: bool,
+) -> bool {
+    update_shape(c, id, rect_kind(x, y, w, h, style.fg, filled))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_points(
+    c: *mut FfiCanvas,
+    id: u64,
     points_xy: *const f64,
     len_pairs: usize,
     style: FfiStyle,
-    marker: u32,
-) {
+) -> bool {
+    match points_kind(points_xy, len_pairs, style.fg) {
+        Some(kind) => update_shape(c, id, kind),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_points_ex(
+    c: *mut FfiCanvas,
+    id: u64,
+    points_xy: *const f64,
+    len_pairs: usize,
+    style: FfiStyleEx,
+) -> bool {
+    match points_kind(points_xy, len_pairs, style.fg) {
+        Some(kind) => update_shape(c, id, kind),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_circle(
+    c: *mut FfiCanvas,
+    id: u64,
+    x: f64,
+    y: f64,
+    radius: f64,
+    style: FfiStyle,
+) -> bool {
+    update_shape(c, id, circle_kind(x, y, radius, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_circle_ex(
+    c: *mut FfiCanvas,
+    id: u64,
+    x: f64,
+    y: f64,
+    radius: f64,
+    style: FfiStyleEx,
+) -> bool {
+    update_shape(c, id, circle_kind(x, y, radius, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_map(
+    c: *mut FfiCanvas,
+    id: u64,
+    resolution: u32,
+    style: FfiStyle,
+) -> bool {
+    update_shape(c, id, map_kind(resolution, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_map_ex(
+    c: *mut FfiCanvas,
+    id: u64,
+    resolution: u32,
+    style: FfiStyleEx,
+) -> bool {
+    update_shape(c, id, map_kind(resolution, style.fg))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_update_label(
+    c: *mut FfiCanvas,
+    id: u64,
+    x: f64,
+    y: f64,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
     if c.is_null() {
-        return;
+        return false;
+    }
+    let Some(kind) = label_kind(x, y, spans, len) else {
+        return false;
+    };
+    unsafe { &mut *c }.replace_shape(id, kind)
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_remove_shape(c: *mut FfiCanvas, id: u64) -> bool {
+    if c.is_null() {
+        return false;
     }
     let cv = unsafe { &mut *c };
-    if points_xy.is_null() || len_pairs == 0 {
-        return;
+    match cv.shapes.binary_search_by_key(&id, |s| s.id) {
+        Ok(i) => {
+            cv.shapes.remove(i);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// Hidden shapes keep their id, layer and data but are skipped when drawing.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_set_shape_hidden(
+    c: *mut FfiCanvas,
+    id: u64,
+    hidden: bool,
+) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    match unsafe { &mut *c }.shape_mut(id) {
+        Some(s) => {
+            s.hidden = hidden;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_set_shape_layer(c: *mut FfiCanvas, id: u64, layer: i32) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    match unsafe { &mut *c }.shape_mut(id) {
+        Some(s) => {
+            s.layer = layer;
+            true
+        }
+        None => false,
+    }
+}
+
+// `ratatui_canvas_set_marker` numbering; negative falls back to the canvas marker. Ignored for
+// labels, which are text.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_set_shape_marker(c: *mut FfiCanvas, id: u64, marker: i32) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    match unsafe { &mut *c }.shape_mut(id) {
+        Some(s) => {
+            s.marker = marker_from_i32(marker);
+            true
+        }
+        None => false,
     }
-    let slice = unsafe { std::slice::from_raw_parts(points_xy, len_pairs * 2) };
-    let mut pts: Vec<(f64, f64)> = Vec::with_capacity(len_pairs);
-    for i in 0..len_pairs {
-        pts.push((slice[i * 2], slice[i * 2 + 1]));
+}
+
+// Removes every shape; ids handed out before are not reused.
+#[no_mangle]
+pub extern "C" fn ratatui_canvas_clear(c: *mut FfiCanvas) {
+    if c.is_null() {
+        return;
     }
-    let col = crate::color_from_u32(style.fg).unwrap_or(Color::White);
-    let _ = marker; // marker not supported in ratatui 0.29 Points shape; ignored
-    cv.pts.push((pts, col));
+    unsafe { &mut *c }.shapes.clear();
 }
 
 #[no_mangle]
@@ -271,48 +1023,8 @@ pub extern "C" fn ratatui_terminal_draw_canvas_in(
                 return false;
             }
         }
-        let mut w = RtCanvas::default()
-            .x_bounds([cv.x_min, cv.x_max])
-            .y_bounds([cv.y_min, cv.y_max]);
-        if let Some(bg) = cv.background {
-            w = w.background_color(bg);
-        }
-        if let Some(b) = &cv.block {
-            w = w.block(b.clone());
-        }
-        if let Some(mk) = cv.marker {
-            w = w.marker(mk);
-        }
-        w = w.paint(|p| {
-            for l in &cv.lines {
-                let col = crate::color_from_u32(l.style.fg).unwrap_or(Color::White);
-                p.draw(&RtCanvasLine {
-                    x1: l.x1,
-                    y1: l.y1,
-                    x2: l.x2,
-                    y2: l.y2,
-                    color: col,
-                });
-            }
-            for r in &cv.rects {
-                let col = crate::color_from_u32(r.style.fg).unwrap_or(Color::White);
-                p.draw(&RtCanvasRect {
-                    x: r.x,
-                    y: r.y,
-                    width: r.w,
-                    height: r.h,
-                    color: col,
-                });
-            }
-            for (pts, col) in &cv.pts {
-                p.draw(&RtCanvasPoints {
-                    coords: &pts[..],
-                    color: *col,
-                });
-            }
-        });
         let res = t.terminal.draw(|frame| {
-            frame.render_widget(w.clone(), area);
+            cv.render_into(area, frame.buffer_mut());
         });
         res.is_ok()
     })
@@ -338,47 +1050,7 @@ pub extern "C" fn ratatui_headless_render_canvas(
         height,
     };
     let mut buf = Buffer::empty(area);
-    let mut w = RtCanvas::default()
-        .x_bounds([cv.x_min, cv.x_max])
-        .y_bounds([cv.y_min, cv.y_max]);
-    if let Some(bg) = cv.background {
-        w = w.background_color(bg);
-    }
-    if let Some(b) = &cv.block {
-        w = w.block(b.clone());
-    }
-    if let Some(mk) = cv.marker {
-        w = w.marker(mk);
-    }
-    w = w.paint(|p| {
-        for l in &cv.lines {
-            let col = crate::color_from_u32(l.style.fg).unwrap_or(Color::White);
-            p.draw(&RtCanvasLine {
-                x1: l.x1,
-                y1: l.y1,
-                x2: l.x2,
-                y2: l.y2,
-                color: col,
-            });
-        }
-        for r in &cv.rects {
-            let col = crate::color_from_u32(r.style.fg).unwrap_or(Color::White);
-            p.draw(&RtCanvasRect {
-                x: r.x,
-                y: r.y,
-                width: r.w,
-                height: r.h,
-                color: col,
-            });
-        }
-        for (pts, col) in &cv.pts {
-            p.draw(&RtCanvasPoints {
-                coords: &pts[..],
-                color: *col,
-            });
-        }
-    });
-    ratatui::widgets::Widget::render(w, area, &mut buf);
+    cv.render_into(area, &mut buf);
     let mut s = String::new();
     for y in 0..height {
         for x in 0..width {
@@ -399,3 +1071,118 @@ pub extern "C" fn ratatui_headless_render_canvas(
         Err(_) => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAIN: FfiStyle = FfiStyle {
+        fg: 0,
+        bg: 0,
+        mods: 0,
+    };
+
+    fn render(c: *const FfiCanvas) -> String {
+        let mut out: *mut c_char = std::ptr::null_mut();
+        assert!(ratatui_headless_render_canvas(4, 2, c, &mut out));
+        unsafe { CString::from_raw(out) }.into_string().unwrap()
+    }
+
+    fn ids(c: *mut FfiCanvas) -> Vec<u64> {
+        unsafe { &*c }.shapes.iter().map(|s| s.id).collect()
+    }
+
+    #[test]
+    fn ids_increase_and_survive_remove_and_clear() {
+        let c = ratatui_canvas_new(0.0, 4.0, 0.0, 2.0);
+        let pts = [1.0, 1.0];
+        let a = ratatui_canvas_add_line(c, 0.0, 0.0, 4.0, 2.0, PLAIN);
+        let b = ratatui_canvas_add_points(c, pts.as_ptr(), 1, PLAIN, 0);
+        let d = ratatui_canvas_add_circle(c, 2.0, 1.0, 1.0, PLAIN);
+        assert_eq!((a, b, d), (1, 2, 3));
+        let null = std::ptr::null();
+        assert_eq!(ratatui_canvas_add_points(c, null, 1, PLAIN, 0), 0);
+        assert_eq!(ratatui_canvas_add_points(c, pts.as_ptr(), 0, PLAIN, 0), 0);
+
+        assert!(ratatui_canvas_remove_shape(c, b));
+        assert!(!ratatui_canvas_remove_shape(c, b));
+        assert_eq!(ids(c), [a, d]);
+        let e = ratatui_canvas_add_rect(c, 0.0, 0.0, 1.0, 1.0, PLAIN, false);
+        assert_eq!(e, 4);
+
+        ratatui_canvas_clear(c);
+        assert!(ids(c).is_empty());
+        assert!(!ratatui_canvas_set_shape_hidden(c, d, true));
+        assert_eq!(ratatui_canvas_add_map(c, 0, PLAIN), 5);
+        assert_eq!(add_shape(std::ptr::null_mut(), map_kind(0, 0)), 0);
+        ratatui_canvas_free(c);
+    }
+
+    #[test]
+    fn update_by_id_replaces_the_shape_in_place() {
+        let c = ratatui_canvas_new(0.0, 4.0, 0.0, 2.0);
+        let a = ratatui_canvas_add_line(c, 0.0, 0.0, 1.0, 1.0, PLAIN);
+        let b = ratatui_canvas_add_line(c, 0.0, 0.0, 1.0, 1.0, PLAIN);
+        let pts = [3.0, 1.0, 0.5, 0.5];
+        assert!(ratatui_canvas_update_points(c, a, pts.as_ptr(), 2, PLAIN));
+        assert!(!ratatui_canvas_update_points(c, 99, pts.as_ptr(), 2, PLAIN));
+        let null = std::ptr::null();
+        assert!(!ratatui_canvas_update_points(c, b, null, 2, PLAIN));
+        assert!(!ratatui_canvas_update_points(c, b, pts.as_ptr(), 0, PLAIN));
+        assert!(ratatui_canvas_update_circle(c, b, 2.0, 1.0, 0.5, PLAIN));
+        let none = std::ptr::null_mut();
+        let moved = ratatui_canvas_update_line(none, a, 0.0, 0.0, 1.0, 1.0, PLAIN);
+        assert!(!moved);
+
+        let cv = unsafe { &*c };
+        assert_eq!(ids(c), [a, b]);
+        match &cv.shapes[0].kind {
+            CanvasShapeKind::Points { coords, .. } => assert_eq!(coords, &[(3.0, 1.0), (0.5, 0.5)]),
+            _ => panic!("shape {a} is not points"),
+        }
+        assert!(matches!(cv.shapes[1].kind, CanvasShapeKind::Circle { .. }));
+        ratatui_canvas_free(c);
+    }
+
+    #[test]
+    fn hidden_shapes_keep_their_id_but_are_not_drawn() {
+        let c = ratatui_canvas_new(0.0, 4.0, 0.0, 2.0);
+        ratatui_canvas_set_marker(c, 2);
+        let pts = [0.0, 2.0];
+        let id = ratatui_canvas_add_points(c, pts.as_ptr(), 1, PLAIN, 0);
+        assert_eq!(render(c), "█   \n    ");
+        assert!(ratatui_canvas_set_shape_hidden(c, id, true));
+        assert_eq!(render(c), "    \n    ");
+        assert!(ratatui_canvas_set_shape_hidden(c, id, false));
+        assert_eq!(render(c), "█   \n    ");
+        ratatui_canvas_free(c);
+    }
+
+    #[test]
+    fn points_batch_reports_ids_and_per_set_markers() {
+        let c = ratatui_canvas_new(0.0, 4.0, 0.0, 2.0);
+        let pts = [1.0, 1.0];
+        let set = |points_xy, len_pairs, marker| FfiCanvasPoints {
+            points_xy,
+            len_pairs,
+            style: PLAIN,
+            marker,
+        };
+        let sets = [
+            set(pts.as_ptr(), 1, 1),
+            set(std::ptr::null(), 1, 1),
+            set(pts.as_ptr(), 1, u32::MAX),
+        ];
+        let mut out = [7u64; 3];
+        let added = ratatui_canvas_add_points_batch(c, sets.as_ptr(), 3, out.as_mut_ptr());
+        assert_eq!((added, out), (2, [1, 0, 2]));
+
+        let cv = unsafe { &*c };
+        assert_eq!(cv.shapes[0].marker, Some(RtMarker::Braille));
+        assert_eq!(cv.shapes[1].marker, None);
+        let added = ratatui_canvas_add_points_batch(c, sets.as_ptr(), 3, std::ptr::null_mut());
+        assert_eq!(added, 2);
+        assert_eq!(ids(c), [1, 2, 3, 4]);
+        ratatui_canvas_free(c);
+    }
+}
@@ -15,9 +15,12 @@
 
 // use crate::*; // enable when moving implementations
 
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::{
     ratatui_block_adv_fn, ratatui_block_title_fn, ratatui_block_title_spans_fn,
-    ratatui_set_style_fn, FfiLineSpans, FfiRect, FfiStyle, FfiTerminal,
+    ratatui_set_style_ex_fn, ratatui_set_style_fn, ratatui_set_style_slot_fn, FfiLineSpans,
+    FfiLineSpansEx, FfiRect, FfiStyle, FfiStyleEx, FfiTerminal, FfiTickFormat,
 };
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Rect};
@@ -26,14 +29,28 @@ use ratatui::widgets::{
     Axis as RtAxis, Block, Chart as RtChart, Dataset as RtDataset, GraphType as RtGraphType,
     LegendPosition as RtLegendPosition,
 };
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::ffi::{c_char, CStr, CString};
+use unicode_width::UnicodeWidthStr;
 
 #[repr(C)]
 pub struct FfiChartDataset {
     pub name: String,
-    pub points: Vec<(f64, f64)>,
-    pub style: Option<Style>,
+    pub points: VecDeque<(f64, f64)>,
+    pub style: Option<StyleRef>,
     pub kind: u32,
+    pub capacity: usize, // 0 = unbounded
+}
+
+impl FfiChartDataset {
+    // Ratatui wants one slice; a ring that has wrapped is copied out for the frame.
+    pub(crate) fn points_slice(&self) -> Cow<'_, [(f64, f64)]> {
+        match self.points.as_slices() {
+            (front, []) => Cow::Borrowed(front),
+            _ => Cow::Owned(self.points.iter().copied().collect()),
+        }
+    }
 }
 
 #[repr(C)]
@@ -51,6 +68,7 @@ pub struct FfiChart {
     pub x_title: Option<String>,
     pub y_title: Option<String>,
     pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
     pub x_min: Option<f64>,
     pub x_max: Option<f64>,
     pub y_min: Option<f64>,
@@ -58,21 +76,290 @@ pub struct FfiChart {
     pub legend_pos: Option<u32>,
     pub hidden_legend_kinds: Option<[u32; 2]>,
     pub hidden_legend_values: Option<[u16; 2]>,
-    pub chart_style: Option<Style>,
-    pub x_axis_style: Option<Style>,
-    pub y_axis_style: Option<Style>,
+    pub chart_style: Option<StyleRef>,
+    pub x_axis_style: Option<StyleRef>,
+    pub y_axis_style: Option<StyleRef>,
     pub x_labels: Option<Vec<Line<'static>>>,
     pub y_labels: Option<Vec<Line<'static>>>,
     pub x_labels_align: Option<Alignment>,
     pub y_labels_align: Option<Alignment>,
+    pub x_ticks: Option<FfiAxisTicks>,
+    pub y_ticks: Option<FfiAxisTicks>,
+    pub x_window: Option<f64>,
+}
+
+// Automatic axis labels: ticks at "nice" steps (1/2/5 x 10^n, or clock/calendar steps for the
+// time formats) covering the axis bounds, formatted per `FfiTickFormat` and thinned to fit.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiAxisTicks {
+    pub format: u32,          // FfiTickFormat
+    pub decimals: i32,        // Fixed/Si/Percent; negative = derived from the tick step
+    pub max_ticks: u16,       // 0 = as many as fit the axis
+    pub utc_offset_secs: i32, // Time/Date/DateTime: timestamps are Unix seconds
+}
+
+const MAX_AUTO_TICKS: usize = 11;
+const TIME_STEPS: [f64; 22] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0,
+    10800.0, 21600.0, 43200.0, 86400.0, 172800.0, 604800.0, 1209600.0, 2592000.0,
+];
+const YEAR_SECS: f64 = 31_536_000.0;
+const SI_PREFIXES: [&str; 11] = ["p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E"];
+
+fn is_time_format(format: u32) -> bool {
+    format >= FfiTickFormat::Time as u32 && format <= FfiTickFormat::DateTime as u32
+}
+
+// Smallest nice step >= `raw`.
+fn nice_step(raw: f64, format: u32) -> f64 {
+    if is_time_format(format) {
+        let raw = if format == FfiTickFormat::Date as u32 {
+            raw.max(86400.0)
+        } else {
+            raw
+        };
+        return match TIME_STEPS.iter().find(|&&s| s >= raw) {
+            Some(s) => *s,
+            None => YEAR_SECS * nice_step(raw / YEAR_SECS, FfiTickFormat::Fixed as u32),
+        };
+    }
+    let mag = 10f64.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0]
+        .iter()
+        .map(|m| m * mag)
+        .find(|s| *s >= raw)
+        .unwrap_or(10.0 * mag)
+}
+
+// (first tick, step, count) with at most `n` ticks covering [lo, hi]; time steps are aligned in
+// local time so day ticks land on midnight.
+fn tick_range(lo: f64, hi: f64, n: usize, t: &FfiAxisTicks) -> (f64, f64, usize) {
+    let shift = if is_time_format(t.format) {
+        t.utc_offset_secs as f64
+    } else {
+        0.0
+    };
+    let mut raw = (hi - lo) / (n.max(2) - 1) as f64;
+    for _ in 0..64 {
+        let step = nice_step(raw, t.format);
+        let start = ((lo + shift) / step).floor() * step - shift;
+        let end = ((hi + shift) / step).ceil() * step - shift;
+        let count = ((end - start) / step).round() as usize + 1;
+        if count <= n.max(2) {
+            return (start, step, count);
+        }
+        // 1.5x lands between consecutive nice steps, so this picks the next one up
+        raw = step * 1.5;
+    }
+    (lo, hi - lo, 2)
+}
+
+// Howard Hinnant's days-since-epoch to (year, month, day).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+fn format_si(v: f64, decimals: i32) -> String {
+    if v == 0.0 {
+        return "0".to_string();
+    }
+    let e = ((v.abs().log10() / 3.0).floor() as i32).clamp(-4, 6);
+    let scaled = v / 1000f64.powi(e);
+    // automatic precision: one decimal, dropped when it is zero
+    let mut s = format!(
+        "{:.*}",
+        if decimals >= 0 { decimals as usize } else { 1 },
+        scaled
+    );
+    if decimals < 0 && s.contains('.') {
+        s = s.trim_end_matches('0').trim_end_matches('.').to_string();
+    }
+    s.push_str(SI_PREFIXES[(e + 4) as usize]);
+    s
+}
+
+fn format_tick(v: f64, step: f64, t: &FfiAxisTicks) -> String {
+    let decimals = if t.decimals >= 0 {
+        t.decimals as usize
+    } else {
+        (-step.log10().floor()).max(0.0) as usize
+    };
+    if is_time_format(t.format) {
+        let secs = v.round() as i64 + t.utc_offset_secs as i64;
+        let (days, sod) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+        let (hh, mm, ss) = (sod / 3600, sod / 60 % 60, sod % 60);
+        let (y, mo, d) = civil_from_days(days);
+        return match t.format {
+            x if x == FfiTickFormat::Time as u32 => format!("{hh:02}:{mm:02}:{ss:02}"),
+            x if x == FfiTickFormat::Date as u32 => format!("{y:04}-{mo:02}-{d:02}"),
+            _ => format!("{y:04}-{mo:02}-{d:02} {hh:02}:{mm:02}"),
+        };
+    }
+    match t.format {
+        x if x == FfiTickFormat::Si as u32 => format_si(v, t.decimals),
+        x if x == FfiTickFormat::Percent as u32 => format!("{:.*}%", decimals, v),
+        _ => format!("{:.*}", decimals, v),
+    }
+}
+
+impl FfiChart {
+    // Slides the x bounds to end at the newest x of any dataset.
+    fn follow_x_window(&mut self) {
+        let Some(w) = self.x_window else {
+            return;
+        };
+        let latest = self
+            .datasets
+            .iter()
+            .filter_map(|d| d.points.back())
+            .fold(f64::NEG_INFINITY, |m, p| m.max(p.0));
+        if latest.is_finite() {
+            self.x_min = Some(latest - w);
+            self.x_max = Some(latest);
+        }
+    }
+
+    // Bounds snapped out to the first/last tick plus their labels, for an axis with auto ticks and
+    // no explicit labels. Bounds fall back to the data extents; `area` is the whole widget area.
+    pub(crate) fn auto_ticks(
+        &self,
+        x_axis: bool,
+        area: Rect,
+    ) -> Option<([f64; 2], Vec<Line<'static>>)> {
+        let (t, labels, min, max) = if x_axis {
+            (self.x_ticks?, &self.x_labels, self.x_min, self.x_max)
+        } else {
+            (self.y_ticks?, &self.y_labels, self.y_min, self.y_max)
+        };
+        if labels.is_some() {
+            return None;
+        }
+        let (mut lo, mut hi) = match (min, max) {
+            (Some(lo), Some(hi)) => (lo, hi),
+            _ => {
+                let vals = self
+                    .datasets
+                    .iter()
+                    .flat_map(|d| d.points.iter())
+                    .map(|p| if x_axis { p.0 } else { p.1 })
+                    .filter(|v| v.is_finite());
+                let (dlo, dhi) = vals.fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), v| {
+                    (a.min(v), b.max(v))
+                });
+                (min.unwrap_or(dlo), max.unwrap_or(dhi))
+            }
+        };
+        if !lo.is_finite() || !hi.is_finite() {
+            return None;
+        }
+        if hi < lo {
+            std::mem::swap(&mut lo, &mut hi);
+        }
+        if hi == lo {
+            let pad = if lo == 0.0 { 1.0 } else { lo.abs() * 0.1 };
+            (lo, hi) = (lo - pad, hi + pad);
+        }
+        let inner = self
+            .block_extras
+            .apply(&self.block)
+            .map_or(area, |b| b.inner(area));
+        let mut n = if t.max_ticks >= 2 {
+            t.max_ticks as usize
+        } else {
+            MAX_AUTO_TICKS
+        };
+        // y labels get a row each with a gap between them, above the x axis line and labels;
+        // x labels share the width left of the y label column (which also holds the first one)
+        let y_width = if x_axis {
+            match &self.y_labels {
+                Some(l) => l.iter().map(|l| l.width()).max().unwrap_or(0),
+                None => self
+                    .auto_ticks(false, area)
+                    .map_or(0, |(_, l)| l.iter().map(|l| l.width()).max().unwrap_or(0)),
+            }
+        } else {
+            n = n.min((inner.height.saturating_sub(2) as usize).div_ceil(2));
+            0
+        };
+        loop {
+            let (start, step, count) = tick_range(lo, hi, n, &t);
+            let texts: Vec<String> = (0..count)
+                .map(|i| {
+                    let v = start + step * i as f64;
+                    let v = if (v / step).abs() < 1e-9 { 0.0 } else { v };
+                    format_tick(v, step, &t)
+                })
+                .collect();
+            let widest = texts.iter().map(|s| s.width()).max().unwrap_or(0);
+            let graph_width = (inner.width as usize).saturating_sub(y_width.max(widest) + 1);
+            if !x_axis || count <= 2 || count * (widest + 1) <= graph_width {
+                let bounds = [start, start + step * (count - 1) as f64];
+                return Some((bounds, texts.into_iter().map(Line::from).collect()));
+            }
+            n = count - 1;
+        }
+    }
 }
 
 // ----- Chart -----
 
 ratatui_set_style_fn!(ratatui_chart_set_style, FfiChart, chart_style);
+ratatui_set_style_ex_fn!(ratatui_chart_set_style_ex, FfiChart, chart_style);
+ratatui_set_style_slot_fn!(ratatui_chart_set_style_slot, FfiChart, chart_style);
+ratatui_set_style_slot_fn!(ratatui_chart_set_x_axis_style_slot, FfiChart, x_axis_style);
+ratatui_set_style_slot_fn!(ratatui_chart_set_y_axis_style_slot, FfiChart, y_axis_style);
 ratatui_block_title_fn!(ratatui_chart_set_block_title, FfiChart);
 ratatui_block_title_spans_fn!(ratatui_chart_set_block_title_spans, FfiChart);
+crate::ratatui_block_title_markup_fn!(ratatui_chart_set_block_title_markup, FfiChart);
+
+crate::ratatui_block_title_text_fn!(ratatui_chart_set_block_title_text, FfiChart);
 ratatui_block_adv_fn!(ratatui_chart_set_block_adv, FfiChart);
+crate::ratatui_block_add_title_fn!(
+    ratatui_chart_add_block_title_spans,
+    ratatui_chart_add_block_title_markup,
+    FfiChart
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_chart_set_block_title_spans_ex,
+    ratatui_chart_add_block_title_spans_ex,
+    FfiChart
+);
+crate::ratatui_block_style_fn!(ratatui_chart_set_block_style, FfiChart, style);
+crate::ratatui_block_style_fn!(ratatui_chart_set_block_border_style, FfiChart, border_style);
+crate::ratatui_block_style_fn!(ratatui_chart_set_block_title_style, FfiChart, title_style);
+crate::ratatui_block_style_ex_fn!(ratatui_chart_set_block_style_ex, FfiChart, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_chart_set_block_border_style_ex,
+    FfiChart,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_chart_set_block_title_style_ex,
+    FfiChart,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_chart_set_block_style_slot, FfiChart, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_chart_set_block_border_style_slot,
+    FfiChart,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_chart_set_block_title_style_slot,
+    FfiChart,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_chart_set_block_border_set, FfiChart);
+crate::ratatui_block_set_fn!(ratatui_chart_set_block, FfiChart);
 
 #[no_mangle]
 pub extern "C" fn ratatui_chart_new() -> *mut FfiChart {
@@ -81,6 +368,7 @@ pub extern "C" fn ratatui_chart_new() -> *mut FfiChart {
         x_title: None,
         y_title: None,
         block: None,
+        block_extras: BlockExtras::default(),
         x_min: None,
         x_max: None,
         y_min: None,
@@ -95,6 +383,9 @@ pub extern "C" fn ratatui_chart_new() -> *mut FfiChart {
         y_labels: None,
         x_labels_align: None,
         y_labels_align: None,
+        x_ticks: None,
+        y_ticks: None,
+        x_window: None,
     }))
 }
 
@@ -108,13 +399,29 @@ pub extern "C" fn ratatui_chart_free(c: *mut FfiChart) {
     }
 }
 
-#[no_mangle]
-pub extern "C" fn ratatui_chart_add_line(
+// `len_pairs` interleaved x,y pairs; None when the length overflows or exceeds the batch cap.
+fn points_from_ffi(points_xy: *const f64, len_pairs: usize) -> Option<Vec<(f64, f64)>> {
+    if points_xy.is_null() || len_pairs == 0 {
+        return Some(Vec::new());
+    }
+    let len = len_pairs.checked_mul(2)?;
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len_pairs) {
+            return None;
+        }
+    }
+    let slice = unsafe { std::slice::from_raw_parts(points_xy, len) };
+    Some(slice.chunks_exact(2).map(|p| (p[0], p[1])).collect())
+}
+
+fn push_dataset(
     c: *mut FfiChart,
     name_utf8: *const c_char,
     points_xy: *const f64,
     len_pairs: usize,
-    style: FfiStyle,
+    sty: Style,
+    kind: u32,
 ) {
     if c.is_null() {
         return;
@@ -128,25 +435,42 @@ pub extern "C" fn ratatui_chart_add_line(
             .unwrap_or("")
             .to_string()
     };
-    let sty = crate::style_from_ffi(style);
-    let pts = if points_xy.is_null() || len_pairs == 0 {
-        Vec::new()
-    } else {
-        let slice = unsafe { std::slice::from_raw_parts(points_xy, len_pairs * 2) };
-        let mut pts = Vec::with_capacity(len_pairs);
-        for i in 0..len_pairs {
-            pts.push((slice[i * 2], slice[i * 2 + 1]));
-        }
-        pts
+    let Some(pts) = points_from_ffi(points_xy, len_pairs) else {
+        return;
     };
     ch.datasets.push(FfiChartDataset {
         name,
-        points: pts,
-        style: Some(sty),
-        kind: 0,
+        points: pts.into(),
+        style: Some(sty.into()),
+        kind,
+        capacity: 0,
     });
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_chart_add_line(
+    c: *mut FfiChart,
+    name_utf8: *const c_char,
+    points_xy: *const f64,
+    len_pairs: usize,
+    style: FfiStyle,
+) {
+    let sty = crate::style_from_ffi(style);
+    push_dataset(c, name_utf8, points_xy, len_pairs, sty, 0);
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_chart_add_line_ex(
+    c: *mut FfiChart,
+    name_utf8: *const c_char,
+    points_xy: *const f64,
+    len_pairs: usize,
+    style: FfiStyleEx,
+) {
+    let sty = crate::style_from_ffi_ex(style);
+    push_dataset(c, name_utf8, points_xy, len_pairs, sty, 0);
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_chart_add_dataset_with_type(
     c: *mut FfiChart,
@@ -156,35 +480,21 @@ pub extern "C" fn ratatui_chart_add_dataset_with_type(
     style: FfiStyle,
     kind: u32,
 ) {
-    if c.is_null() {
-        return;
-    }
-    let ch = unsafe { &mut *c };
-    let name = if name_utf8.is_null() {
-        String::new()
-    } else {
-        unsafe { CStr::from_ptr(name_utf8) }
-            .to_str()
-            .unwrap_or("")
-            .to_string()
-    };
     let sty = crate::style_from_ffi(style);
-    let pts = if points_xy.is_null() || len_pairs == 0 {
-        Vec::new()
-    } else {
-        let slice = unsafe { std::slice::from_raw_parts(points_xy, len_pairs * 2) };
-        let mut pts = Vec::with_capacity(len_pairs);
-        for i in 0..len_pairs {
-            pts.push((slice[i * 2], slice[i * 2 + 1]));
-        }
-        pts
-    };
-    ch.datasets.push(FfiChartDataset {
-        name,
-        points: pts,
-        style: Some(sty),
-        kind,
-    });
+    push_dataset(c, name_utf8, points_xy, len_pairs, sty, kind);
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_chart_add_dataset_with_type_ex(
+    c: *mut FfiChart,
+    name_utf8: *const c_char,
+    points_xy: *const f64,
+    len_pairs: usize,
+    style: FfiStyleEx,
+    kind: u32,
+) {
+    let sty = crate::style_from_ffi_ex(style);
+    push_dataset(c, name_utf8, points_xy, len_pairs, sty, kind);
 }
 
 #[no_mangle]
@@ -207,25 +517,125 @@ pub extern "C" fn ratatui_chart_add_datasets(
                 .unwrap_or("")
                 .to_string()
         };
-        let pts = if s.points_xy.is_null() || s.len_pairs == 0 {
-            Vec::new()
-        } else {
-            let slice2 = unsafe { std::slice::from_raw_parts(s.points_xy, s.len_pairs * 2) };
-            let mut pts = Vec::with_capacity(s.len_pairs);
-            for i in 0..s.len_pairs {
-                pts.push((slice2[i * 2], slice2[i * 2 + 1]));
-            }
-            pts
+        let Some(pts) = points_from_ffi(s.points_xy, s.len_pairs) else {
+            continue;
         };
         ch.datasets.push(FfiChartDataset {
             name,
-            points: pts,
-            style: Some(crate::style_from_ffi(s.style)),
+            points: pts.into(),
+            style: Some(crate::style_from_ffi(s.style).into()),
             kind: s.kind,
+            capacity: 0,
         });
     }
 }
 
+// Streaming datasets: cap a dataset (by add order) to its newest `capacity` points (0 =
+// unbounded), then push only new samples each frame; the oldest points fall off the front.
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_dataset_capacity(
+    c: *mut FfiChart,
+    index: usize,
+    capacity: usize,
+) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    let ch = unsafe { &mut *c };
+    let Some(ds) = ch.datasets.get_mut(index) else {
+        return false;
+    };
+    ds.capacity = capacity;
+    crate::push_bounded(&mut ds.points, capacity, &[]);
+    true
+}
+
+// Binds a dataset's style (by add order) to a theme slot, resolved at draw time; slot 0 removes
+// the style.
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_dataset_style_slot(
+    c: *mut FfiChart,
+    index: usize,
+    slot: u32,
+) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    let ch = unsafe { &mut *c };
+    let Some(ds) = ch.datasets.get_mut(index) else {
+        return false;
+    };
+    ds.style = (slot != 0).then_some(StyleRef::Slot(slot));
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_chart_dataset_push(
+    c: *mut FfiChart,
+    index: usize,
+    x: f64,
+    y: f64,
+) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    let ch = unsafe { &mut *c };
+    let Some(ds) = ch.datasets.get_mut(index) else {
+        return false;
+    };
+    crate::push_bounded(&mut ds.points, ds.capacity, &[(x, y)]);
+    ch.follow_x_window();
+    true
+}
+
+// `points_xy` holds `len_pairs` interleaved x,y pairs, oldest first.
+#[no_mangle]
+pub extern "C" fn ratatui_chart_dataset_push_many(
+    c: *mut FfiChart,
+    index: usize,
+    points_xy: *const f64,
+    len_pairs: usize,
+) -> bool {
+    if c.is_null() || points_xy.is_null() {
+        return false;
+    }
+    let ch = unsafe { &mut *c };
+    let Some(ds) = ch.datasets.get_mut(index) else {
+        return false;
+    };
+    let Some(pts) = points_from_ffi(points_xy, len_pairs) else {
+        return false;
+    };
+    crate::push_bounded(&mut ds.points, ds.capacity, &pts);
+    ch.follow_x_window();
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_chart_dataset_clear(c: *mut FfiChart, index: usize) -> bool {
+    if c.is_null() {
+        return false;
+    }
+    let ch = unsafe { &mut *c };
+    let Some(ds) = ch.datasets.get_mut(index) else {
+        return false;
+    };
+    ds.points.clear();
+    true
+}
+
+// Auto-scrolling x axis: after each push the x bounds become [newest x - width, newest x]
+// (e.g. the last 60 s of timestamps). Zero, negative or NaN turns it off and keeps the bounds.
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_x_window(c: *mut FfiChart, width: f64) {
+    if c.is_null() {
+        return;
+    }
+    let ch = unsafe { &mut *c };
+    ch.x_window = (width > 0.0 && width.is_finite()).then_some(width);
+    ch.follow_x_window();
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_chart_set_axes_titles(
     c: *mut FfiChart,
@@ -307,8 +717,42 @@ pub extern "C" fn ratatui_chart_set_axis_styles(
         return;
     }
     let ch = unsafe { &mut *c };
-    ch.x_axis_style = Some(crate::style_from_ffi(x_style));
-    ch.y_axis_style = Some(crate::style_from_ffi(y_style));
+    ch.x_axis_style = Some(crate::style_from_ffi(x_style).into());
+    ch.y_axis_style = Some(crate::style_from_ffi(y_style).into());
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_axis_styles_ex(
+    c: *mut FfiChart,
+    x_style: FfiStyleEx,
+    y_style: FfiStyleEx,
+) {
+    if c.is_null() {
+        return;
+    }
+    let ch = unsafe { &mut *c };
+    ch.x_axis_style = Some(crate::style_from_ffi_ex(x_style).into());
+    ch.y_axis_style = Some(crate::style_from_ffi_ex(y_style).into());
+}
+
+// Labels are generated at draw time and the axis bounds snapped out to the first/last tick;
+// labels set through `*_labels_spans*` take precedence. Null turns auto ticks off.
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_x_auto_ticks(c: *mut FfiChart, ticks: *const FfiAxisTicks) {
+    if c.is_null() {
+        return;
+    }
+    let ch = unsafe { &mut *c };
+    ch.x_ticks = (!ticks.is_null()).then(|| unsafe { *ticks });
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_y_auto_ticks(c: *mut FfiChart, ticks: *const FfiAxisTicks) {
+    if c.is_null() {
+        return;
+    }
+    let ch = unsafe { &mut *c };
+    ch.y_ticks = (!ticks.is_null()).then(|| unsafe { *ticks });
 }
 
 #[no_mangle]
@@ -371,6 +815,42 @@ pub extern "C" fn ratatui_chart_set_y_labels_spans(
     ch.y_labels = Some(lines);
 }
 
+// Per-line style applies; axis labels are positioned by `ratatui_chart_set_labels_alignment`, so
+// the per-line alignment is ignored here.
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_x_labels_spans_ex(
+    c: *mut FfiChart,
+    labels: *const FfiLineSpansEx,
+    len: usize,
+) {
+    if c.is_null() {
+        return;
+    }
+    let ch = unsafe { &mut *c };
+    if labels.is_null() || len == 0 {
+        ch.x_labels = None;
+        return;
+    }
+    ch.x_labels = Some(crate::lines_from_ffi_ex(labels, len));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_chart_set_y_labels_spans_ex(
+    c: *mut FfiChart,
+    labels: *const FfiLineSpansEx,
+    len: usize,
+) {
+    if c.is_null() {
+        return;
+    }
+    let ch = unsafe { &mut *c };
+    if labels.is_null() || len == 0 {
+        ch.y_labels = None;
+        return;
+    }
+    ch.y_labels = Some(crate::lines_from_ffi_ex(labels, len));
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_terminal_draw_chart_in(
     term: *mut FfiTerminal,
@@ -395,11 +875,12 @@ pub extern "C" fn ratatui_terminal_draw_chart_in(
                 return false;
             }
         }
+        let points: Vec<_> = ch.datasets.iter().map(|ds| ds.points_slice()).collect();
         let mut datasets: Vec<RtDataset> = Vec::new();
-        for ds in &ch.datasets {
-            let mut d = RtDataset::default().name(ds.name.clone()).data(&ds.points);
+        for (ds, pts) in ch.datasets.iter().zip(&points) {
+            let mut d = RtDataset::default().name(ds.name.clone()).data(pts);
             if let Some(sty) = &ds.style {
-                d = d.style(sty.clone());
+                d = d.style(sty.resolve());
             }
             d = d.graph_type(match ds.kind {
                 1 => RtGraphType::Bar,
@@ -435,6 +916,12 @@ pub extern "C" fn ratatui_terminal_draw_chart_in(
         if let Some(al) = ch.y_labels_align {
             y_axis = y_axis.labels_alignment(al);
         }
+        if let Some((b, l)) = ch.auto_ticks(true, area) {
+            x_axis = x_axis.bounds(b).labels(l);
+        }
+        if let Some((b, l)) = ch.auto_ticks(false, area) {
+            y_axis = y_axis.bounds(b).labels(l);
+        }
         w = w.x_axis(x_axis).y_axis(y_axis);
         if let Some(lp) = ch.legend_pos {
             w = w.legend_position(Some(match lp {
@@ -459,8 +946,8 @@ pub extern "C" fn ratatui_terminal_draw_chart_in(
             };
             w = w.hidden_legend_constraints([to_cons(k[0], v[0]), to_cons(k[1], v[1])].into());
         }
-        if let Some(b) = &ch.block {
-            w = w.block(b.clone());
+        if let Some(b) = ch.block_extras.apply(&ch.block) {
+            w = w.block(b);
         }
         let res = t.terminal.draw(|frame| {
             frame.render_widget(w.clone(), area);
@@ -487,11 +974,12 @@ pub extern "C" fn ratatui_headless_render_chart(
         height,
     };
     let mut buf = Buffer::empty(area);
+    let points: Vec<_> = ch.datasets.iter().map(|ds| ds.points_slice()).collect();
     let mut datasets: Vec<RtDataset> = Vec::new();
-    for ds in &ch.datasets {
-        let mut d = RtDataset::default().name(ds.name.clone()).data(&ds.points);
+    for (ds, pts) in ch.datasets.iter().zip(&points) {
+        let mut d = RtDataset::default().name(ds.name.clone()).data(pts);
         if let Some(sty) = &ds.style {
-            d = d.style(sty.clone());
+            d = d.style(sty.resolve());
         }
         d = d.graph_type(match ds.kind {
             1 => RtGraphType::Bar,
@@ -510,10 +998,10 @@ pub extern "C" fn ratatui_headless_render_chart(
         y_axis = y_axis.title(ti.clone());
     }
     if let Some(st) = &ch.x_axis_style {
-        x_axis = x_axis.style(st.clone());
+        x_axis = x_axis.style(st.resolve());
     }
     if let Some(st) = &ch.y_axis_style {
-        y_axis = y_axis.style(st.clone());
+        y_axis = y_axis.style(st.resolve());
     }
     if let (Some(min), Some(max)) = (ch.x_min, ch.x_max) {
         x_axis = x_axis.bounds([min, max]);
@@ -533,6 +1021,12 @@ pub extern "C" fn ratatui_headless_render_chart(
     if let Some(al) = ch.y_labels_align {
         y_axis = y_axis.labels_alignment(al);
     }
+    if let Some((b, l)) = ch.auto_ticks(true, area) {
+        x_axis = x_axis.bounds(b).labels(l);
+    }
+    if let Some((b, l)) = ch.auto_ticks(false, area) {
+        y_axis = y_axis.bounds(b).labels(l);
+    }
     w = w.x_axis(x_axis).y_axis(y_axis);
     if let Some(lp) = ch.legend_pos {
         w = w.legend_position(Some(match lp {
@@ -557,11 +1051,11 @@ pub extern "C" fn ratatui_headless_render_chart(
         };
         w = w.hidden_legend_constraints([to_cons(k[0], v[0]), to_cons(k[1], v[1])].into());
     }
-    if let Some(b) = &ch.block {
-        w = w.block(b.clone());
+    if let Some(b) = ch.block_extras.apply(&ch.block) {
+        w = w.block(b);
     }
     if let Some(st) = &ch.chart_style {
-        w = w.style(st.clone());
+        w = w.style(st.resolve());
     }
     ratatui::widgets::Widget::render(w, area, &mut buf);
     let mut s = String::new();
@@ -584,3 +1078,64 @@ pub extern "C" fn ratatui_headless_render_chart(
         Err(_) => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAIN: FfiStyle = FfiStyle {
+        fg: 0,
+        bg: 0,
+        mods: 0,
+    };
+
+    fn xs(c: *mut FfiChart, index: usize) -> Vec<f64> {
+        unsafe { &*c }.datasets[index]
+            .points
+            .iter()
+            .map(|p| p.0)
+            .collect()
+    }
+
+    #[test]
+    fn ring_keeps_the_newest_items() {
+        let mut ring = VecDeque::new();
+        crate::push_bounded(&mut ring, 3, &[1, 2]);
+        crate::push_bounded(&mut ring, 3, &[3, 4]);
+        assert_eq!(ring, [2, 3, 4]);
+        // a batch larger than the capacity keeps only its own tail
+        crate::push_bounded(&mut ring, 3, &[5, 6, 7, 8, 9]);
+        assert_eq!(ring, [7, 8, 9]);
+        crate::push_bounded(&mut ring, 1, &[]);
+        assert_eq!(ring, [9]);
+        crate::push_bounded(&mut ring, 0, &[10, 11]);
+        assert_eq!(ring, [9, 10, 11]);
+    }
+
+    #[test]
+    fn dataset_capacity_trims_and_the_x_window_follows() {
+        let c = ratatui_chart_new();
+        let pts = [0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0];
+        ratatui_chart_add_line(c, std::ptr::null(), pts.as_ptr(), 5, PLAIN);
+        assert!(!ratatui_chart_set_dataset_capacity(c, 1, 3));
+        assert!(ratatui_chart_set_dataset_capacity(c, 0, 3));
+        assert_eq!(xs(c, 0), [2.0, 3.0, 4.0]);
+        assert!(ratatui_chart_dataset_push(c, 0, 5.0, 5.0));
+        assert_eq!(xs(c, 0), [3.0, 4.0, 5.0]);
+        assert!(ratatui_chart_dataset_push_many(c, 0, pts.as_ptr(), 2));
+        assert_eq!(xs(c, 0), [5.0, 0.0, 1.0]);
+        let huge = usize::MAX;
+        assert!(!ratatui_chart_dataset_push_many(c, 0, pts.as_ptr(), huge));
+        assert!(!ratatui_chart_dataset_push(c, 1, 0.0, 0.0));
+
+        ratatui_chart_set_x_window(c, 2.5);
+        assert!(ratatui_chart_dataset_push(c, 0, 10.0, 0.0));
+        let ch = unsafe { &*c };
+        assert_eq!((ch.x_min, ch.x_max), (Some(7.5), Some(10.0)));
+        ratatui_chart_set_x_window(c, f64::NAN);
+        assert!(ratatui_chart_dataset_push(c, 0, 20.0, 0.0));
+        let ch = unsafe { &*c };
+        assert_eq!((ch.x_min, ch.x_max), (Some(7.5), Some(10.0)));
+        ratatui_chart_free(c);
+    }
+}
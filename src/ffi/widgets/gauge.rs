@@ -1,8 +1,10 @@
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::*;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::Line;
-use ratatui::style::Style;
+use ratatui::symbols::line;
 use ratatui::widgets::{Block, Gauge, LineGauge as RtLineGauge};
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
@@ -13,6 +15,7 @@ pub extern "C" fn ratatui_gauge_new() -> *mut FfiGauge {
         ratio: 0.0,
         label: None,
         block: None,
+        block_extras: BlockExtras::default(),
         style: None,
         label_style: None,
         gauge_style: None,
@@ -94,20 +97,129 @@ pub extern "C" fn ratatui_gauge_set_styles(
         return;
     }
     let gg = unsafe { &mut *g };
-    gg.style = Some(style_from_ffi(style));
-    gg.label_style = Some(style_from_ffi(label_style));
-    gg.gauge_style = Some(style_from_ffi(gauge_style));
+    gg.style = Some(style_from_ffi(style).into());
+    gg.label_style = Some(style_from_ffi(label_style).into());
+    gg.gauge_style = Some(style_from_ffi(gauge_style).into());
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_gauge_set_styles_ex(
+    g: *mut FfiGauge,
+    style: FfiStyleEx,
+    label_style: FfiStyleEx,
+    gauge_style: FfiStyleEx,
+) {
+    if g.is_null() {
+        return;
+    }
+    let gg = unsafe { &mut *g };
+    gg.style = Some(style_from_ffi_ex(style).into());
+    gg.label_style = Some(style_from_ffi_ex(label_style).into());
+    gg.gauge_style = Some(style_from_ffi_ex(gauge_style).into());
 }
 
 crate::ratatui_block_title_fn!(ratatui_gauge_set_block_title, FfiGauge);
 crate::ratatui_block_title_spans_fn!(ratatui_gauge_set_block_title_spans, FfiGauge);
+crate::ratatui_block_title_markup_fn!(ratatui_gauge_set_block_title_markup, FfiGauge);
+
+crate::ratatui_block_title_text_fn!(ratatui_gauge_set_block_title_text, FfiGauge);
 crate::ratatui_block_title_alignment_fn!(ratatui_gauge_set_block_title_alignment, FfiGauge);
 crate::ratatui_block_adv_fn!(ratatui_gauge_set_block_adv, FfiGauge);
+crate::ratatui_block_add_title_fn!(
+    ratatui_gauge_add_block_title_spans,
+    ratatui_gauge_add_block_title_markup,
+    FfiGauge
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_gauge_set_block_title_spans_ex,
+    ratatui_gauge_add_block_title_spans_ex,
+    FfiGauge
+);
+crate::ratatui_block_style_fn!(ratatui_gauge_set_block_style, FfiGauge, style);
+crate::ratatui_block_style_fn!(ratatui_gauge_set_block_border_style, FfiGauge, border_style);
+crate::ratatui_block_style_fn!(ratatui_gauge_set_block_title_style, FfiGauge, title_style);
+crate::ratatui_block_style_ex_fn!(ratatui_gauge_set_block_style_ex, FfiGauge, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_gauge_set_block_border_style_ex,
+    FfiGauge,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_gauge_set_block_title_style_ex,
+    FfiGauge,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_gauge_set_block_style_slot, FfiGauge, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_gauge_set_block_border_style_slot,
+    FfiGauge,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_gauge_set_block_title_style_slot,
+    FfiGauge,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_gauge_set_block_border_set, FfiGauge);
+crate::ratatui_block_set_fn!(ratatui_gauge_set_block, FfiGauge);
 crate::ratatui_block_title_fn!(ratatui_linegauge_set_block_title, FfiLineGauge);
 crate::ratatui_block_title_spans_fn!(ratatui_linegauge_set_block_title_spans, FfiLineGauge);
+crate::ratatui_block_title_markup_fn!(ratatui_linegauge_set_block_title_markup, FfiLineGauge);
+
+crate::ratatui_block_title_text_fn!(ratatui_linegauge_set_block_title_text, FfiLineGauge);
 crate::ratatui_block_title_alignment_fn!(ratatui_linegauge_set_block_title_alignment, FfiLineGauge);
 crate::ratatui_block_adv_fn!(ratatui_linegauge_set_block_adv, FfiLineGauge);
+crate::ratatui_block_add_title_fn!(
+    ratatui_linegauge_add_block_title_spans,
+    ratatui_linegauge_add_block_title_markup,
+    FfiLineGauge
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_linegauge_set_block_title_spans_ex,
+    ratatui_linegauge_add_block_title_spans_ex,
+    FfiLineGauge
+);
+crate::ratatui_block_style_fn!(ratatui_linegauge_set_block_style, FfiLineGauge, style);
+crate::ratatui_block_style_fn!(
+    ratatui_linegauge_set_block_border_style,
+    FfiLineGauge,
+    border_style
+);
+crate::ratatui_block_style_fn!(
+    ratatui_linegauge_set_block_title_style,
+    FfiLineGauge,
+    title_style
+);
+crate::ratatui_block_style_ex_fn!(ratatui_linegauge_set_block_style_ex, FfiLineGauge, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_linegauge_set_block_border_style_ex,
+    FfiLineGauge,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_linegauge_set_block_title_style_ex,
+    FfiLineGauge,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_linegauge_set_block_style_slot, FfiLineGauge, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_linegauge_set_block_border_style_slot,
+    FfiLineGauge,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_linegauge_set_block_title_style_slot,
+    FfiLineGauge,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_linegauge_set_block_border_set, FfiLineGauge);
+crate::ratatui_block_set_fn!(ratatui_linegauge_set_block, FfiLineGauge);
 crate::ratatui_set_style_fn!(ratatui_linegauge_set_style, FfiLineGauge, style);
+crate::ratatui_set_style_ex_fn!(ratatui_linegauge_set_style_ex, FfiLineGauge, style);
+crate::ratatui_set_style_slot_fn!(ratatui_linegauge_set_style_slot, FfiLineGauge, style);
+crate::ratatui_set_style_slot_fn!(ratatui_gauge_set_style_slot, FfiGauge, style);
+crate::ratatui_set_style_slot_fn!(ratatui_gauge_set_label_style_slot, FfiGauge, label_style);
+crate::ratatui_set_style_slot_fn!(ratatui_gauge_set_gauge_style_slot, FfiGauge, gauge_style);
 
 #[no_mangle]
 pub extern "C" fn ratatui_terminal_draw_gauge_in(
@@ -141,19 +253,19 @@ pub extern "C" fn ratatui_terminal_draw_gauge_in(
         }
         let mut widget = Gauge::default().ratio(gg.ratio as f64);
         if let Some(st) = &gg.style {
-            widget = widget.style(st.clone());
+            widget = widget.style(st.resolve());
         }
         if let Some(label) = &gg.label {
             widget = widget.label(label.clone());
         }
         if let Some(st) = &gg.label_style {
-            widget = widget.style(st.clone());
+            widget = widget.style(st.resolve());
         }
         if let Some(st) = &gg.gauge_style {
-            widget = widget.gauge_style(st.clone());
+            widget = widget.gauge_style(st.resolve());
         }
-        if let Some(b) = &gg.block {
-            widget = widget.block(b.clone());
+        if let Some(b) = gg.block_extras.apply(&gg.block) {
+            widget = widget.block(b);
         }
         let res = t.terminal.draw(|frame| {
             frame.render_widget(widget.clone(), area);
@@ -182,19 +294,19 @@ pub extern "C" fn ratatui_headless_render_gauge(
     let mut buf = Buffer::empty(area);
     let mut w = Gauge::default().ratio(gg.ratio as f64);
     if let Some(st) = &gg.style {
-        w = w.style(st.clone());
+        w = w.style(st.resolve());
     }
     if let Some(label) = &gg.label {
         w = w.label(label.clone());
     }
     if let Some(st) = &gg.label_style {
-        w = w.style(st.clone());
+        w = w.style(st.resolve());
     }
     if let Some(st) = &gg.gauge_style {
-        w = w.gauge_style(st.clone());
+        w = w.gauge_style(st.resolve());
     }
-    if let Some(b) = &gg.block {
-        w = w.block(b.clone());
+    if let Some(b) = gg.block_extras.apply(&gg.block) {
+        w = w.block(b);
     }
     ratatui::widgets::Widget::render(w, area, &mut buf);
     let mut s = String::new();
@@ -222,9 +334,10 @@ pub struct FfiGauge {
     pub ratio: f32,
     pub label: Option<String>,
     pub block: Option<Block<'static>>,
-    pub style: Option<Style>,
-    pub label_style: Option<Style>,
-    pub gauge_style: Option<Style>,
+    pub block_extras: BlockExtras,
+    pub style: Option<StyleRef>,
+    pub label_style: Option<StyleRef>,
+    pub gauge_style: Option<StyleRef>,
 }
 
 #[repr(C)]
@@ -233,7 +346,9 @@ pub struct FfiLineGauge {
     pub label: Option<String>,
     pub label_line: Option<Line<'static>>,
     pub block: Option<Block<'static>>,
-    pub style: Option<Style>,
+    pub block_extras: BlockExtras,
+    pub style: Option<StyleRef>,
+    pub line_set: Option<line::Set>,
 }
 
 #[no_mangle]
@@ -243,7 +358,9 @@ pub extern "C" fn ratatui_linegauge_new() -> *mut FfiLineGauge {
         label: None,
         label_line: None,
         block: None,
+        block_extras: BlockExtras::default(),
         style: None,
+        line_set: None,
     }))
 }
 
@@ -308,6 +425,22 @@ pub extern "C" fn ratatui_linegauge_set_label_spans(
     }
 }
 
+// Glyphs for the filled/unfilled line (only `horizontal` is drawn); false on invalid UTF-8.
+#[no_mangle]
+pub extern "C" fn ratatui_linegauge_set_line_set(
+    g: *mut FfiLineGauge,
+    set: FfiSymbolsLineSet,
+) -> bool {
+    if g.is_null() {
+        return false;
+    }
+    let Some(set) = crate::ffi::symbols::line_set_from_ffi(set) else {
+        return false;
+    };
+    unsafe { &mut *g }.line_set = Some(set);
+    true
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_terminal_draw_linegauge_in(
     term: *mut FfiTerminal,
@@ -327,16 +460,19 @@ pub extern "C" fn ratatui_terminal_draw_linegauge_in(
             height: rect.height,
         };
         let mut w = RtLineGauge::default().ratio(gg.ratio as f64);
+        if let Some(set) = &gg.line_set {
+            w = w.line_set(*set);
+        }
         if let Some(lbl) = &gg.label_line {
             w = w.label(lbl.clone());
         } else if let Some(label) = &gg.label {
             w = w.label(label.clone());
         }
         if let Some(st) = &gg.style {
-            w = w.style(st.clone());
+            w = w.style(st.resolve());
         }
-        if let Some(b) = &gg.block {
-            w = w.block(b.clone());
+        if let Some(b) = gg.block_extras.apply(&gg.block) {
+            w = w.block(b);
         }
         let res = t.terminal.draw(|frame| {
             frame.render_widget(w.clone(), area);
@@ -364,16 +500,19 @@ pub extern "C" fn ratatui_headless_render_linegauge(
     };
     let mut buf = Buffer::empty(area);
     let mut w = RtLineGauge::default().ratio(gg.ratio as f64);
+    if let Some(set) = &gg.line_set {
+        w = w.line_set(*set);
+    }
     if let Some(lbl) = &gg.label_line {
         w = w.label(lbl.clone());
     } else if let Some(label) = &gg.label {
         w = w.label(label.clone());
     }
     if let Some(st) = &gg.style {
-        w = w.style(st.clone());
+        w = w.style(st.resolve());
     }
-    if let Some(b) = &gg.block {
-        w = w.block(b.clone());
+    if let Some(b) = gg.block_extras.apply(&gg.block) {
+        w = w.block(b);
     }
     ratatui::widgets::Widget::render(w, area, &mut buf);
     let mut s = String::new();
@@ -5,7 +5,7 @@
 
 // use crate::*; // enable when moving implementations
 
-use crate::{FfiEvent, FfiRect, FfiTerminal, INJECTED_EVENTS};
+use crate::{FfiConstraint, FfiEvent, FfiFlex, FfiRect, FfiSpacing, FfiTerminal, INJECTED_EVENTS};
 use crate::{FfiKeyCode, FfiKeyMods, FfiMouseKind};
 use crossterm::event::{
     Event as CtEvent, KeyCode as CtKeyCode, KeyEvent as CtKeyEvent, KeyModifiers as CtKeyModifiers,
@@ -15,7 +15,7 @@ use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use crossterm::{event, execute};
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect, Spacing};
 use std::io::stdout;
 
 #[no_mangle]
@@ -301,6 +301,135 @@ pub extern "C" fn ratatui_layout_split_ex2(
     n
 }
 
+// `FfiConstraint` kind with its values: `a` for Min/Max/Length/Percentage/Fill (weight), `a/b`
+// for Ratio (`b == 0` is treated as 1). Unknown kinds fall back to Length.
+pub(crate) fn constraint_from_ffi(kind: u32, a: u16, b: u16) -> Constraint {
+    match kind {
+        k if k == FfiConstraint::Min as u32 => Constraint::Min(a),
+        k if k == FfiConstraint::Max as u32 => Constraint::Max(a),
+        k if k == FfiConstraint::Percentage as u32 => Constraint::Percentage(a),
+        k if k == FfiConstraint::Ratio as u32 => Constraint::Ratio(a as u32, b.max(1) as u32),
+        k if k == FfiConstraint::Fill as u32 => Constraint::Fill(a),
+        _ => Constraint::Length(a),
+    }
+}
+
+// `FfiFlex`; unknown values fall back to Start (ratatui's default).
+pub(crate) fn flex_from_u32(flex: u32) -> Flex {
+    match flex {
+        f if f == FfiFlex::Center as u32 => Flex::Center,
+        f if f == FfiFlex::End as u32 => Flex::End,
+        f if f == FfiFlex::Legacy as u32 => Flex::Legacy,
+        f if f == FfiFlex::SpaceAround as u32 => Flex::SpaceAround,
+        f if f == FfiFlex::SpaceBetween as u32 => Flex::SpaceBetween,
+        _ => Flex::Start,
+    }
+}
+
+// `FfiSpacing`: Space leaves `amount` cells between segments, Overlap makes adjacent segments
+// share `amount` cells (e.g. collapsing the borders of neighbouring blocks).
+pub(crate) fn spacing_from_ffi(kind: u32, amount: u16) -> Spacing {
+    if kind == FfiSpacing::Overlap as u32 {
+        Spacing::Overlap(amount)
+    } else {
+        Spacing::Space(amount)
+    }
+}
+
+// Shrinks `r` by per-side margins; an axis whose margins don't fit is left unchanged.
+pub(crate) fn rect_with_margins(mut r: Rect, l: u16, t: u16, rt: u16, b: u16) -> Rect {
+    let (h, v) = (l as u32 + rt as u32, t as u32 + b as u32);
+    if h < r.width as u32 {
+        r.x += l;
+        r.width -= h as u16;
+    }
+    if v < r.height as u32 {
+        r.y += t;
+        r.height -= v as u16;
+    }
+    r
+}
+
+// Full splitter: all six `FfiConstraint` kinds (parallel `kinds`/`values_a`/`values_b`, the
+// latter only read for Ratio and may be null), `FfiFlex`, `FfiSpacing` (space or overlap),
+// per-side margins and an arbitrary parent rect. Returns the number of rects written.
+#[no_mangle]
+pub extern "C" fn ratatui_layout_split_ex3(
+    parent: FfiRect,
+    dir: u32, // 0=Vertical, 1=Horizontal
+    kinds: *const u32,
+    values_a: *const u16,
+    values_b: *const u16,
+    len: usize,
+    flex: u32,
+    spacing_kind: u32,
+    spacing: u16,
+    margin_l: u16,
+    margin_t: u16,
+    margin_r: u16,
+    margin_b: u16,
+    out_rects: *mut FfiRect,
+    out_cap: usize,
+) -> usize {
+    if kinds.is_null() || values_a.is_null() || out_rects.is_null() || len == 0 || out_cap == 0 {
+        return 0;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return 0;
+        }
+    }
+    let kinds_slice = unsafe { std::slice::from_raw_parts(kinds, len) };
+    let a_slice = unsafe { std::slice::from_raw_parts(values_a, len) };
+    let b_slice = if values_b.is_null() {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(values_b, len) }
+    };
+    let constraints: Vec<Constraint> = (0..len)
+        .map(|i| {
+            let b = b_slice.get(i).copied().unwrap_or(0);
+            constraint_from_ffi(kinds_slice[i], a_slice[i], b)
+        })
+        .collect();
+    let area = rect_with_margins(
+        Rect {
+            x: parent.x,
+            y: parent.y,
+            width: parent.width,
+            height: parent.height,
+        },
+        margin_l,
+        margin_t,
+        margin_r,
+        margin_b,
+    );
+    let layout = Layout::new(
+        if dir == 1 {
+            Direction::Horizontal
+        } else {
+            Direction::Vertical
+        },
+        constraints,
+    )
+    .flex(flex_from_u32(flex))
+    .spacing(spacing_from_ffi(spacing_kind, spacing));
+    let chunks = layout.split(area);
+    let n = chunks.len().min(out_cap);
+    for (i, r) in chunks.iter().take(n).enumerate() {
+        unsafe {
+            *out_rects.add(i) = FfiRect {
+                x: r.x,
+                y: r.y,
+                width: r.width,
+                height: r.height,
+            };
+        }
+    }
+    n
+}
+
 #[allow(dead_code)]
 #[repr(u32)]
 pub enum FfiConstraintKind {
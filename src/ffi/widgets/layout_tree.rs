@@ -0,0 +1,293 @@
+// Nested layouts resolved in one call: the host describes the tree as a flat node array with
+// parent indices and gets back a rect per node. Results are cached until the area changes.
+
+use crate::ffi::widgets::layout::{
+    constraint_from_ffi, flex_from_u32, rect_with_margins, spacing_from_ffi,
+};
+use crate::{FfiRect, FfiTerminal};
+use ratatui::layout::{Direction, Layout, Rect};
+
+// One node of a layout tree. `parent` must be a lower index (or negative for a root, which
+// covers the whole area); a node's constraint sizes it within its parent. The remaining fields
+// describe how the node splits its own (margin-shrunk) area among its children, taken in array
+// order.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiLayoutNode {
+    pub parent: i32,
+    pub constraint_kind: u32, // FfiConstraint
+    pub constraint_a: u16,
+    pub constraint_b: u16, // Ratio denominator
+    pub direction: u32,    // 0=Vertical, 1=Horizontal
+    pub flex: u32,         // FfiFlex
+    pub spacing_kind: u32, // FfiSpacing
+    pub spacing: u16,
+    pub margin_l: u16,
+    pub margin_t: u16,
+    pub margin_r: u16,
+    pub margin_b: u16,
+}
+
+pub struct FfiLayoutTree {
+    nodes: Vec<FfiLayoutNode>,
+    children: Vec<Vec<usize>>,
+    cache: Option<(Rect, Vec<Rect>)>,
+}
+
+impl FfiLayoutTree {
+    fn from_nodes(nodes: Vec<FfiLayoutNode>) -> Option<Self> {
+        let mut children = vec![Vec::new(); nodes.len()];
+        for (i, n) in nodes.iter().enumerate() {
+            if n.parent >= 0 {
+                let p = n.parent as usize;
+                if p >= i {
+                    return None;
+                }
+                children[p].push(i);
+            }
+        }
+        Some(Self {
+            nodes,
+            children,
+            cache: None,
+        })
+    }
+
+    fn resolve(&mut self, area: Rect) -> &[Rect] {
+        if !matches!(&self.cache, Some((a, _)) if *a == area) {
+            let mut rects = vec![Rect::default(); self.nodes.len()];
+            // parents precede children, so one forward pass sees every parent rect first
+            for i in 0..self.nodes.len() {
+                if self.nodes[i].parent < 0 {
+                    rects[i] = area;
+                }
+                let kids = &self.children[i];
+                if kids.is_empty() {
+                    continue;
+                }
+                let n = &self.nodes[i];
+                let inner =
+                    rect_with_margins(rects[i], n.margin_l, n.margin_t, n.margin_r, n.margin_b);
+                let constraints = kids.iter().map(|&k| {
+                    let c = &self.nodes[k];
+                    constraint_from_ffi(c.constraint_kind, c.constraint_a, c.constraint_b)
+                });
+                let dir = if n.direction == 1 {
+                    Direction::Horizontal
+                } else {
+                    Direction::Vertical
+                };
+                let chunks = Layout::new(dir, constraints)
+                    .flex(flex_from_u32(n.flex))
+                    .spacing(spacing_from_ffi(n.spacing_kind, n.spacing))
+                    .split(inner);
+                for (&k, r) in kids.iter().zip(chunks.iter()) {
+                    rects[k] = *r;
+                }
+            }
+            self.cache = Some((area, rects));
+        }
+        &self.cache.as_ref().unwrap().1
+    }
+}
+
+fn nodes_from_ffi(nodes: *const FfiLayoutNode, len: usize) -> Option<Vec<FfiLayoutNode>> {
+    if nodes.is_null() || len == 0 {
+        return None;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return None;
+        }
+    }
+    Some(unsafe { std::slice::from_raw_parts(nodes, len) }.to_vec())
+}
+
+fn write_rects(rects: &[Rect], out_rects: *mut FfiRect, out_cap: usize) -> usize {
+    if !out_rects.is_null() {
+        for (i, r) in rects.iter().take(out_cap).enumerate() {
+            unsafe {
+                *out_rects.add(i) = FfiRect {
+                    x: r.x,
+                    y: r.y,
+                    width: r.width,
+                    height: r.height,
+                };
+            }
+        }
+    }
+    rects.len()
+}
+
+// Null if the array is empty or a node's parent is not a lower index.
+#[no_mangle]
+pub extern "C" fn ratatui_layout_tree_new(
+    nodes: *const FfiLayoutNode,
+    len: usize,
+) -> *mut FfiLayoutTree {
+    match nodes_from_ffi(nodes, len).and_then(FfiLayoutTree::from_nodes) {
+        Some(tree) => Box::into_raw(Box::new(tree)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+// Replaces the whole tree (and drops the cache); false leaves the old tree in place.
+#[no_mangle]
+pub extern "C" fn ratatui_layout_tree_set_nodes(
+    tree: *mut FfiLayoutTree,
+    nodes: *const FfiLayoutNode,
+    len: usize,
+) -> bool {
+    if tree.is_null() {
+        return false;
+    }
+    match nodes_from_ffi(nodes, len).and_then(FfiLayoutTree::from_nodes) {
+        Some(t) => {
+            unsafe {
+                *tree = t;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_layout_tree_free(tree: *mut FfiLayoutTree) {
+    if tree.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(tree));
+    }
+}
+
+// Writes one rect per node (index-aligned with the node array, up to `out_cap`) and returns the
+// node count; call with `out_cap == 0` to size the buffer.
+#[no_mangle]
+pub extern "C" fn ratatui_layout_tree_resolve(
+    tree: *mut FfiLayoutTree,
+    area: FfiRect,
+    out_rects: *mut FfiRect,
+    out_cap: usize,
+) -> usize {
+    if tree.is_null() {
+        return 0;
+    }
+    let t = unsafe { &mut *tree };
+    let area = Rect {
+        x: area.x,
+        y: area.y,
+        width: area.width,
+        height: area.height,
+    };
+    write_rects(t.resolve(area), out_rects, out_cap)
+}
+
+// Same as `ratatui_layout_tree_resolve` against the terminal's current full area.
+#[no_mangle]
+pub extern "C" fn ratatui_layout_tree_resolve_terminal(
+    tree: *mut FfiLayoutTree,
+    term: *const FfiTerminal,
+    out_rects: *mut FfiRect,
+    out_cap: usize,
+) -> usize {
+    if tree.is_null() || term.is_null() {
+        return 0;
+    }
+    let Ok(size) = unsafe { &*term }.terminal.size() else {
+        return 0;
+    };
+    let t = unsafe { &mut *tree };
+    write_rects(
+        t.resolve(Rect::new(0, 0, size.width, size.height)),
+        out_rects,
+        out_cap,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FfiConstraint;
+
+    fn node(parent: i32, kind: FfiConstraint, a: u16, direction: u32) -> FfiLayoutNode {
+        FfiLayoutNode {
+            parent,
+            constraint_kind: kind as u32,
+            constraint_a: a,
+            constraint_b: 0,
+            direction,
+            flex: 0,
+            spacing_kind: 0,
+            spacing: 0,
+            margin_l: 0,
+            margin_t: 0,
+            margin_r: 0,
+            margin_b: 0,
+        }
+    }
+
+    fn resolve(tree: *mut FfiLayoutTree, width: u16, height: u16) -> Vec<(u16, u16, u16, u16)> {
+        let area = FfiRect {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        };
+        let n = ratatui_layout_tree_resolve(tree, area, std::ptr::null_mut(), 0);
+        let mut out = vec![area; n];
+        let written = ratatui_layout_tree_resolve(tree, area, out.as_mut_ptr(), n);
+        assert_eq!(written, n);
+        out.iter().map(|r| (r.x, r.y, r.width, r.height)).collect()
+    }
+
+    #[test]
+    fn parents_must_be_lower_indices() {
+        let root = node(-1, FfiConstraint::Fill, 1, 0);
+        let bad = [
+            [root, node(1, FfiConstraint::Fill, 1, 0)],
+            [root, node(2, FfiConstraint::Fill, 1, 0)],
+            [node(1, FfiConstraint::Fill, 1, 0), root],
+        ];
+        for nodes in &bad {
+            assert!(ratatui_layout_tree_new(nodes.as_ptr(), nodes.len()).is_null());
+        }
+        assert!(ratatui_layout_tree_new(std::ptr::null(), 2).is_null());
+        assert!(ratatui_layout_tree_new(bad[0].as_ptr(), 0).is_null());
+
+        let good = [root, node(0, FfiConstraint::Fill, 1, 0)];
+        let tree = ratatui_layout_tree_new(good.as_ptr(), good.len());
+        assert!(!tree.is_null());
+        assert!(!ratatui_layout_tree_set_nodes(tree, bad[1].as_ptr(), 2));
+        assert_eq!(resolve(tree, 4, 2), [(0, 0, 4, 2), (0, 0, 4, 2)]);
+        ratatui_layout_tree_free(tree);
+    }
+
+    #[test]
+    fn nested_splits_resolve_within_margins() {
+        let mut root = node(-1, FfiConstraint::Fill, 1, 0);
+        root.margin_l = 1;
+        root.margin_t = 1;
+        let nodes = [
+            root,
+            node(0, FfiConstraint::Length, 2, 0),
+            node(0, FfiConstraint::Fill, 1, 1),
+            node(2, FfiConstraint::Length, 5, 0),
+            node(2, FfiConstraint::Fill, 1, 0),
+        ];
+        let tree = ratatui_layout_tree_new(nodes.as_ptr(), nodes.len());
+        let rects = [
+            (0, 0, 20, 10),
+            (1, 1, 19, 2),
+            (1, 3, 19, 7),
+            (1, 3, 5, 7),
+            (6, 3, 14, 7),
+        ];
+        assert_eq!(resolve(tree, 20, 10), rects);
+        assert_eq!(resolve(tree, 20, 10), rects);
+        assert_eq!(resolve(tree, 10, 4)[4], (6, 3, 4, 1));
+        ratatui_layout_tree_free(tree);
+    }
+}
@@ -1,25 +1,53 @@
+use crate::ffi::ansi::ansi_lines_from_ffi;
+use crate::ffi::markup::{markup_str_from_c, parse_markup};
+use crate::ffi::source::{visible_window, FfiFetchRowsFn, VirtualSource};
+use crate::ffi::text::{shared_from_ffi, snapshot_text, FfiText, SharedText};
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::*;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
 use ratatui::text::Line;
 use ratatui::widgets::{
     Block, HighlightSpacing as RtHighlightSpacing, List as RtList,
-    ListDirection as RtListDirection, ListItem,
+    ListDirection as RtListDirection, ListItem, ListState,
 };
-use std::ffi::{CStr, CString};
+use std::ffi::{c_void, CStr, CString};
 use std::os::raw::c_char;
 
+// A list item: an owned line, or a shared FfiText (which may span several lines).
+pub enum ListEntry {
+    Line(Line<'static>),
+    Text(SharedText),
+}
+
+impl From<Line<'static>> for ListEntry {
+    fn from(line: Line<'static>) -> Self {
+        ListEntry::Line(line)
+    }
+}
+
+impl ListEntry {
+    fn to_item(&self) -> ListItem<'static> {
+        match self {
+            ListEntry::Line(line) => ListItem::new(line.clone()),
+            ListEntry::Text(t) => ListItem::new(snapshot_text(t)),
+        }
+    }
+}
+
 #[repr(C)]
 pub struct FfiList {
-    pub items: Vec<Line<'static>>,
+    pub items: Vec<ListEntry>,
     pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
     pub selected: Option<usize>,
-    pub highlight_style: Option<Style>,
+    pub highlight_style: Option<StyleRef>,
     pub highlight_symbol: Option<String>,
     pub direction: Option<RtListDirection>,
     pub scroll_offset: Option<usize>,
     pub highlight_spacing: Option<RtHighlightSpacing>,
+    pub source: Option<VirtualSource>,
 }
 
 #[repr(C)]
@@ -28,6 +56,39 @@ pub struct FfiListState {
     pub offset: usize,
 }
 
+pub(crate) fn list_item_count(l: &FfiList) -> usize {
+    l.source.map_or(l.items.len(), |src| src.count)
+}
+
+// Items to render plus the matching state. `selected`/`offset` are absolute indices; with a
+// virtual source only the visible window is fetched and the state is rebased onto it. The
+// window is sized one row per item, so a virtual item keeps only its first line.
+pub(crate) fn list_items_window(
+    l: &FfiList,
+    area: Rect,
+    selected: Option<usize>,
+    offset: usize,
+) -> (Vec<ListItem<'static>>, ListState) {
+    let mut state = ListState::default();
+    let Some(src) = &l.source else {
+        state.select(selected);
+        let items = l.items.iter().map(ListEntry::to_item).collect();
+        return (items, state.with_offset(offset));
+    };
+    let inner = l
+        .block_extras
+        .apply(&l.block)
+        .map_or(area, |b| b.inner(area));
+    let (start, len) = visible_window(src.count, inner.height as usize, selected, offset);
+    let items = src
+        .fetch_rows(start, len)
+        .into_iter()
+        .map(|cells| ListItem::new(cells.into_iter().flatten().next().unwrap_or_default()))
+        .collect();
+    state.select(selected.map(|sel| sel.min(src.count.saturating_sub(1)) - start));
+    (items, state)
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_headless_render_list(
     width: u16,
@@ -46,16 +107,16 @@ pub extern "C" fn ratatui_headless_render_list(
         height,
     };
     let mut buf = Buffer::empty(area);
-    let items: Vec<ListItem> = l.items.iter().cloned().map(ListItem::new).collect();
+    let (items, mut state) = list_items_window(l, area, l.selected, l.scroll_offset.unwrap_or(0));
     let mut widget = RtList::new(items);
     if let Some(d) = l.direction {
         widget = widget.direction(d);
     }
-    if let Some(b) = &l.block {
-        widget = widget.block(b.clone());
+    if let Some(b) = l.block_extras.apply(&l.block) {
+        widget = widget.block(b);
     }
     if let Some(sty) = &l.highlight_style {
-        widget = widget.highlight_style(sty.clone());
+        widget = widget.highlight_style(sty.resolve());
     }
     if let Some(sym) = &l.highlight_symbol {
         widget = widget.highlight_symbol(sym.as_str());
@@ -63,18 +124,7 @@ pub extern "C" fn ratatui_headless_render_list(
     if let Some(sp) = &l.highlight_spacing {
         widget = widget.highlight_spacing(sp.clone());
     }
-    if l.selected.is_some() || l.scroll_offset.is_some() {
-        let mut state = ratatui::widgets::ListState::default();
-        if let Some(sel) = l.selected {
-            state.select(Some(sel));
-        }
-        if let Some(off) = l.scroll_offset {
-            state = state.with_offset(off);
-        }
-        ratatui::widgets::StatefulWidget::render(widget, area, &mut buf, &mut state);
-    } else {
-        ratatui::widgets::Widget::render(widget, area, &mut buf);
-    }
+    ratatui::widgets::StatefulWidget::render(widget, area, &mut buf, &mut state);
     let mut s = String::new();
     for y in 0..height {
         for x in 0..width {
@@ -100,12 +150,14 @@ pub extern "C" fn ratatui_list_new() -> *mut FfiList {
     Box::into_raw(Box::new(FfiList {
         items: Vec::new(),
         block: None,
+        block_extras: BlockExtras::default(),
         selected: None,
         highlight_style: None,
         highlight_symbol: None,
         direction: None,
         scroll_offset: None,
         highlight_spacing: None,
+        source: None,
     }))
 }
 
@@ -176,16 +228,22 @@ pub extern "C" fn ratatui_terminal_draw_list_state_in(
                 return false;
             }
         }
-        let items: Vec<ListItem> = l.items.iter().cloned().map(ListItem::new).collect();
+        let (sel, off) = (s.selected, s.offset);
+        #[cfg(feature = "ffi_safety")]
+        let (sel, off) = {
+            let count = list_item_count(l);
+            (sel.map(|v| v.min(count.saturating_sub(1))), off.min(count))
+        };
+        let (items, mut state) = list_items_window(l, area, sel, off);
         let mut widget = RtList::new(items);
         if let Some(d) = l.direction {
             widget = widget.direction(d);
         }
-        if let Some(b) = &l.block {
-            widget = widget.block(b.clone());
+        if let Some(b) = l.block_extras.apply(&l.block) {
+            widget = widget.block(b);
         }
         if let Some(sty) = &l.highlight_style {
-            widget = widget.highlight_style(sty.clone());
+            widget = widget.highlight_style(sty.resolve());
         }
         if let Some(sym) = &l.highlight_symbol {
             widget = widget.highlight_symbol(sym.as_str());
@@ -193,21 +251,6 @@ pub extern "C" fn ratatui_terminal_draw_list_state_in(
         if let Some(sp) = &l.highlight_spacing {
             widget = widget.highlight_spacing(sp.clone());
         }
-        let mut state = ratatui::widgets::ListState::default();
-        let count = l.items.len();
-        if let Some(mut sel) = s.selected {
-            #[cfg(feature = "ffi_safety")]
-            {
-                sel = sel.min(count.saturating_sub(1));
-            }
-            state.select(Some(sel));
-        }
-        let mut off = s.offset;
-        #[cfg(feature = "ffi_safety")]
-        {
-            off = off.min(count);
-        }
-        state = state.with_offset(off);
         let res = t.terminal.draw(|frame| {
             frame.render_stateful_widget(widget.clone(), area, &mut state);
         });
@@ -235,16 +278,16 @@ pub extern "C" fn ratatui_headless_render_list_state(
         height,
     };
     let mut buf = Buffer::empty(area);
-    let items: Vec<ListItem> = l.items.iter().cloned().map(ListItem::new).collect();
+    let (items, mut state) = list_items_window(l, area, s.selected, s.offset);
     let mut widget = RtList::new(items);
     if let Some(d) = l.direction {
         widget = widget.direction(d);
     }
-    if let Some(b) = &l.block {
-        widget = widget.block(b.clone());
+    if let Some(b) = l.block_extras.apply(&l.block) {
+        widget = widget.block(b);
     }
     if let Some(sty) = &l.highlight_style {
-        widget = widget.highlight_style(sty.clone());
+        widget = widget.highlight_style(sty.resolve());
     }
     if let Some(sym) = &l.highlight_symbol {
         widget = widget.highlight_symbol(sym.as_str());
@@ -252,11 +295,6 @@ pub extern "C" fn ratatui_headless_render_list_state(
     if let Some(sp) = &l.highlight_spacing {
         widget = widget.highlight_spacing(sp.clone());
     }
-    let mut state = ratatui::widgets::ListState::default();
-    if let Some(sel) = s.selected {
-        state.select(Some(sel));
-    }
-    state = state.with_offset(s.offset);
     ratatui::widgets::StatefulWidget::render(widget, area, &mut buf, &mut state);
     let mut s = String::new();
     for y in 0..height {
@@ -297,10 +335,20 @@ pub extern "C" fn ratatui_list_append_item(
     }
     if let Ok(s) = c_str.to_str() {
         let st = style_from_ffi(style);
-        l.items.push(Line::from(Span::styled(s.to_string(), st)));
+        l.items
+            .push(Line::from(Span::styled(s.to_string(), st)).into());
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_list_append_item_ex(
+    lst: *mut FfiList,
+    text_utf8: *const c_char,
+    style: FfiStyleEx,
+) {
+    ratatui_list_append_item_spans_ex(lst, &FfiSpanEx { text_utf8, style }, 1);
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_list_append_item_spans(
     lst: *mut FfiList,
@@ -312,7 +360,47 @@ pub extern "C" fn ratatui_list_append_item_spans(
     }
     let l = unsafe { &mut *lst };
     if let Some(sp) = spans_from_ffi(spans, len) {
-        l.items.push(Line::from(sp));
+        l.items.push(Line::from(sp).into());
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_append_item_spans_ex(
+    lst: *mut FfiList,
+    spans: *const FfiSpanEx,
+    len: usize,
+) {
+    if lst.is_null() || spans.is_null() {
+        return;
+    }
+    let l = unsafe { &mut *lst };
+    if let Some(sp) = spans_from_ffi_ex(spans, len) {
+        l.items.push(Line::from(sp).into());
+    }
+}
+
+// One item per line of input; SGR escapes become item styles.
+#[no_mangle]
+pub extern "C" fn ratatui_list_append_items_ansi(lst: *mut FfiList, bytes: *const u8, len: usize) {
+    if lst.is_null() {
+        return;
+    }
+    let l = unsafe { &mut *lst };
+    if let Some(lines) = ansi_lines_from_ffi(bytes, len) {
+        l.items.extend(lines.into_iter().map(ListEntry::from));
+    }
+}
+
+// One item per line of markup.
+#[no_mangle]
+pub extern "C" fn ratatui_list_append_items_markup(lst: *mut FfiList, markup_utf8: *const c_char) {
+    if lst.is_null() {
+        return;
+    }
+    let l = unsafe { &mut *lst };
+    if let Some(m) = markup_str_from_c(markup_utf8) {
+        l.items
+            .extend(parse_markup(m).into_iter().map(ListEntry::from));
     }
 }
 
@@ -335,23 +423,276 @@ pub extern "C" fn ratatui_list_append_items_spans(
     let slice = unsafe { std::slice::from_raw_parts(items, len) };
     for it in slice.iter() {
         if it.spans.is_null() || it.len == 0 {
-            l.items.push(Line::default());
+            l.items.push(Line::default().into());
             continue;
         }
         if let Some(sp) = spans_from_ffi(it.spans, it.len) {
-            l.items.push(Line::from(sp));
+            l.items.push(Line::from(sp).into());
         } else {
-            l.items.push(Line::default());
+            l.items.push(Line::default().into());
         }
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_list_append_items_spans_ex(
+    lst: *mut FfiList,
+    items: *const FfiLineSpansEx,
+    len: usize,
+) {
+    if lst.is_null() || items.is_null() || len == 0 {
+        return;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return;
+        }
+    }
+    let l = unsafe { &mut *lst };
+    l.items.extend(
+        lines_from_ffi_ex(items, len)
+            .into_iter()
+            .map(ListEntry::from),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_insert_item(
+    lst: *mut FfiList,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyle,
+) -> bool {
+    if lst.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match line_from_utf8(text_utf8, style) {
+        Some(line) => vec_insert_at(&mut l.items, index, line.into()),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_insert_item_ex(
+    lst: *mut FfiList,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyleEx,
+) -> bool {
+    if lst.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match line_from_utf8_ex(text_utf8, style) {
+        Some(line) => vec_insert_at(&mut l.items, index, line.into()),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_insert_item_spans(
+    lst: *mut FfiList,
+    index: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    if lst.is_null() || spans.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match spans_from_ffi(spans, len) {
+        Some(sp) => vec_insert_at(&mut l.items, index, Line::from(sp).into()),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_insert_item_spans_ex(
+    lst: *mut FfiList,
+    index: usize,
+    spans: *const FfiSpanEx,
+    len: usize,
+) -> bool {
+    if lst.is_null() || spans.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match spans_from_ffi_ex(spans, len) {
+        Some(sp) => vec_insert_at(&mut l.items, index, Line::from(sp).into()),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_replace_item(
+    lst: *mut FfiList,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyle,
+) -> bool {
+    if lst.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match line_from_utf8(text_utf8, style) {
+        Some(line) => vec_replace_at(&mut l.items, index, line.into()),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_replace_item_ex(
+    lst: *mut FfiList,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyleEx,
+) -> bool {
+    if lst.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match line_from_utf8_ex(text_utf8, style) {
+        Some(line) => vec_replace_at(&mut l.items, index, line.into()),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_replace_item_spans(
+    lst: *mut FfiList,
+    index: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    if lst.is_null() || spans.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match spans_from_ffi(spans, len) {
+        Some(sp) => vec_replace_at(&mut l.items, index, Line::from(sp).into()),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_replace_item_spans_ex(
+    lst: *mut FfiList,
+    index: usize,
+    spans: *const FfiSpanEx,
+    len: usize,
+) -> bool {
+    if lst.is_null() || spans.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match spans_from_ffi_ex(spans, len) {
+        Some(sp) => vec_replace_at(&mut l.items, index, Line::from(sp).into()),
+        None => false,
+    }
+}
+
+// Items backed by an FfiText follow later edits of that text.
+#[no_mangle]
+pub extern "C" fn ratatui_list_append_item_text(lst: *mut FfiList, txt: *const FfiText) {
+    if lst.is_null() {
+        return;
+    }
+    let l = unsafe { &mut *lst };
+    if let Some(t) = shared_from_ffi(txt) {
+        l.items.push(ListEntry::Text(t));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_insert_item_text(
+    lst: *mut FfiList,
+    index: usize,
+    txt: *const FfiText,
+) -> bool {
+    if lst.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match shared_from_ffi(txt) {
+        Some(t) => vec_insert_at(&mut l.items, index, ListEntry::Text(t)),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_replace_item_text(
+    lst: *mut FfiList,
+    index: usize,
+    txt: *const FfiText,
+) -> bool {
+    if lst.is_null() {
+        return false;
+    }
+    let l = unsafe { &mut *lst };
+    match shared_from_ffi(txt) {
+        Some(t) => vec_replace_at(&mut l.items, index, ListEntry::Text(t)),
+        None => false,
+    }
+}
+
+crate::ratatui_vec_remove_fn!(ratatui_list_remove_item, FfiList, items);
+crate::ratatui_vec_swap_fn!(ratatui_list_swap_items, FfiList, items);
+crate::ratatui_vec_truncate_fn!(ratatui_list_truncate_items, FfiList, items);
+crate::ratatui_vec_clear_fn!(ratatui_list_clear_items, FfiList, items);
+
 crate::ratatui_block_title_fn!(ratatui_list_set_block_title, FfiList);
 crate::ratatui_block_title_spans_fn!(ratatui_list_set_block_title_spans, FfiList);
+crate::ratatui_block_title_markup_fn!(ratatui_list_set_block_title_markup, FfiList);
+crate::ratatui_block_title_text_fn!(ratatui_list_set_block_title_text, FfiList);
 crate::ratatui_block_adv_fn!(ratatui_list_set_block_adv, FfiList);
 crate::ratatui_block_title_alignment_fn!(ratatui_list_set_block_title_alignment, FfiList);
+crate::ratatui_block_add_title_fn!(
+    ratatui_list_add_block_title_spans,
+    ratatui_list_add_block_title_markup,
+    FfiList
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_list_set_block_title_spans_ex,
+    ratatui_list_add_block_title_spans_ex,
+    FfiList
+);
+crate::ratatui_block_style_fn!(ratatui_list_set_block_style, FfiList, style);
+crate::ratatui_block_style_fn!(ratatui_list_set_block_border_style, FfiList, border_style);
+crate::ratatui_block_style_fn!(ratatui_list_set_block_title_style, FfiList, title_style);
+crate::ratatui_block_style_ex_fn!(ratatui_list_set_block_style_ex, FfiList, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_list_set_block_border_style_ex,
+    FfiList,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(ratatui_list_set_block_title_style_ex, FfiList, title_style);
+crate::ratatui_block_style_slot_fn!(ratatui_list_set_block_style_slot, FfiList, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_list_set_block_border_style_slot,
+    FfiList,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_list_set_block_title_style_slot,
+    FfiList,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_list_set_block_border_set, FfiList);
+crate::ratatui_block_set_fn!(ratatui_list_set_block, FfiList);
 crate::ratatui_set_selected_i32_fn!(ratatui_list_set_selected, FfiList, selected);
 crate::ratatui_set_style_fn!(ratatui_list_set_highlight_style, FfiList, highlight_style);
+crate::ratatui_set_style_ex_fn!(
+    ratatui_list_set_highlight_style_ex,
+    FfiList,
+    highlight_style
+);
+crate::ratatui_set_style_slot_fn!(
+    ratatui_list_set_highlight_style_slot,
+    FfiList,
+    highlight_style
+);
 
 #[no_mangle]
 pub extern "C" fn ratatui_list_set_highlight_symbol(lst: *mut FfiList, sym_utf8: *const c_char) {
@@ -427,33 +768,109 @@ pub extern "C" fn ratatui_terminal_draw_list_in(
                 return false;
             }
         }
-        let items: Vec<ListItem> = l.items.iter().cloned().map(ListItem::new).collect();
+        let sel = l.selected;
+        #[cfg(feature = "ffi_safety")]
+        let sel = {
+            let count = list_item_count(l);
+            sel.map(|v| v.min(count.saturating_sub(1)))
+        };
+        let (items, mut state) = list_items_window(l, area, sel, 0);
         let mut widget = RtList::new(items);
-        if let Some(b) = &l.block {
-            widget = widget.block(b.clone());
+        if let Some(b) = l.block_extras.apply(&l.block) {
+            widget = widget.block(b);
         }
         if let Some(sty) = &l.highlight_style {
-            widget = widget.highlight_style(sty.clone());
+            widget = widget.highlight_style(sty.resolve());
         }
         if let Some(sym) = &l.highlight_symbol {
             widget = widget.highlight_symbol(sym.as_str());
         }
         let res = t.terminal.draw(|frame| {
-            if let Some(mut sel) = l.selected {
-                #[cfg(feature = "ffi_safety")]
-                {
-                    let count = l.items.len();
-                    sel = sel.min(count.saturating_sub(1));
-                }
-                let mut state = ratatui::widgets::ListState::default();
-                state.select(Some(sel));
-                frame.render_stateful_widget(widget.clone(), area, &mut state);
-            } else {
-                frame.render_widget(widget.clone(), area);
-            }
+            frame.render_stateful_widget(widget.clone(), area, &mut state);
         });
         res.is_ok()
     })
 }
 
 crate::ratatui_reserve_vec_fn!(ratatui_list_reserve_items, FfiList, items);
+
+// Virtual data source: `fetch` is called at render time for the visible window only.
+// Passing a null `fetch` detaches the source and renders the appended items again.
+#[no_mangle]
+pub extern "C" fn ratatui_list_set_source(
+    lst: *mut FfiList,
+    count: usize,
+    fetch: FfiFetchRowsFn,
+    user_data: *mut c_void,
+) {
+    if lst.is_null() {
+        return;
+    }
+    let l = unsafe { &mut *lst };
+    l.source = VirtualSource::new(count, fetch, user_data);
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_set_source_count(lst: *mut FfiList, count: usize) {
+    if lst.is_null() {
+        return;
+    }
+    let l = unsafe { &mut *lst };
+    if let Some(src) = &mut l.source {
+        src.count = count;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_list_item_count(lst: *const FfiList) -> usize {
+    if lst.is_null() {
+        return 0;
+    }
+    list_item_count(unsafe { &*lst })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    const PLAIN: FfiStyle = FfiStyle {
+        fg: 0,
+        bg: 0,
+        mods: 0,
+    };
+
+    fn items(l: *mut FfiList) -> Vec<String> {
+        unsafe { &*l }
+            .items
+            .iter()
+            .map(|e| match e {
+                ListEntry::Line(line) => line.to_string(),
+                ListEntry::Text(_) => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn item_indices_at_and_past_the_end() {
+        let l = ratatui_list_new();
+        let (a, b) = (CString::new("a").unwrap(), CString::new("b").unwrap());
+        assert!(ratatui_list_insert_item(l, 0, a.as_ptr(), PLAIN));
+        assert!(ratatui_list_insert_item(l, 1, b.as_ptr(), PLAIN));
+        assert!(!ratatui_list_insert_item(l, 3, a.as_ptr(), PLAIN));
+        assert!(!ratatui_list_replace_item(l, 2, a.as_ptr(), PLAIN));
+        assert!(!ratatui_list_insert_item(l, 0, std::ptr::null(), PLAIN));
+        assert!(!ratatui_list_remove_item(l, 2));
+        assert!(!ratatui_list_swap_items(l, 0, 2));
+        assert!(ratatui_list_swap_items(l, 0, 1));
+        assert_eq!(items(l), ["b", "a"]);
+        ratatui_list_truncate_items(l, 5);
+        assert_eq!(items(l).len(), 2);
+        assert!(ratatui_list_remove_item(l, 1));
+        assert!(ratatui_list_replace_item(l, 0, a.as_ptr(), PLAIN));
+        assert_eq!(items(l), ["a"]);
+        ratatui_list_clear_items(l);
+        assert!(!ratatui_list_remove_item(l, 0));
+        ratatui_list_free(l);
+    }
+}
@@ -1,9 +1,11 @@
 pub mod barchart;
+pub mod block;
 pub mod canvas;
 pub mod chart;
 pub mod clear;
 pub mod gauge;
 pub mod layout;
+pub mod layout_tree;
 pub mod linegauge;
 pub mod list;
 pub mod logo;
@@ -1,8 +1,14 @@
+use crate::ffi::ansi::ansi_lines_from_ffi;
+use crate::ffi::markup::{markup_str_from_c, parse_markup};
+use crate::ffi::text::{
+    borrow_lines, borrow_text, read_text, shared_from_ffi, FfiText, SharedText,
+};
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::*;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::{Alignment, Line, Span};
-use ratatui::style::Style;
 use ratatui::widgets::{Block, Paragraph};
 use std::ffi::CStr;
 use std::ffi::CString;
@@ -12,11 +18,57 @@ use std::os::raw::c_char;
 pub struct FfiParagraph {
     pub lines: Vec<Line<'static>>,     // content
     pub block: Option<Block<'static>>, // optional block with borders/title
+    pub block_extras: BlockExtras,
     pub align: Option<Alignment>,
     pub wrap_trim: Option<bool>,
     pub scroll_x: Option<u16>,
     pub scroll_y: Option<u16>,
-    pub base_style: Option<Style>,
+    pub base_style: Option<StyleRef>,
+    pub follow_tail: bool,
+    // attached FfiText replacing `lines` as content
+    pub text: Option<SharedText>,
+}
+
+// Shared by every paragraph render path; content is borrowed (from `lines` or the attached
+// FfiText) for the duration of `f`. With follow-tail on, the vertical scroll is derived from the
+// wrapped height so the last line stays at the bottom of `area`.
+pub(crate) fn with_paragraph_widget<R>(
+    p: &FfiParagraph,
+    area: Rect,
+    f: impl FnOnce(Paragraph<'_>) -> R,
+) -> R {
+    let shared = p.text.as_ref().map(read_text);
+    let (text, text_align) = match &shared {
+        Some(t) => (borrow_text(t), t.alignment),
+        None => (Text::from(borrow_lines(&p.lines)), None),
+    };
+    let mut widget = Paragraph::new(text);
+    if let Some(a) = p.align.or(text_align) {
+        widget = widget.alignment(a);
+    }
+    if let Some(trim) = p.wrap_trim {
+        widget = widget.wrap(ratatui::widgets::Wrap { trim });
+    }
+    if let Some(st) = &p.base_style {
+        widget = widget.style(st.resolve());
+    }
+    if p.follow_tail {
+        let inner = p
+            .block_extras
+            .apply(&p.block)
+            .map_or(area, |b| b.inner(area));
+        let total = widget.line_count(inner.width);
+        let sy = total
+            .saturating_sub(inner.height as usize)
+            .min(u16::MAX as usize) as u16;
+        widget = widget.scroll((sy, p.scroll_y.unwrap_or(0)));
+    } else if let (Some(sx), Some(sy)) = (p.scroll_x, p.scroll_y) {
+        widget = widget.scroll((sx, sy));
+    }
+    if let Some(b) = p.block_extras.apply(&p.block) {
+        widget = widget.block(b);
+    }
+    f(widget)
 }
 
 #[no_mangle]
@@ -37,23 +89,9 @@ pub extern "C" fn ratatui_headless_render_paragraph(
         height,
     };
     let mut buf = Buffer::empty(area);
-    let mut widget = Paragraph::new(p.lines.clone());
-    if let Some(a) = p.align {
-        widget = widget.alignment(a);
-    }
-    if let Some(trim) = p.wrap_trim {
-        widget = widget.wrap(ratatui::widgets::Wrap { trim });
-    }
-    if let (Some(sx), Some(sy)) = (p.scroll_x, p.scroll_y) {
-        widget = widget.scroll((sx, sy));
-    }
-    if let Some(st) = &p.base_style {
-        widget = widget.style(st.clone());
-    }
-    if let Some(b) = &p.block {
-        widget = widget.block(b.clone());
-    }
-    ratatui::widgets::Widget::render(widget, area, &mut buf);
+    with_paragraph_widget(p, area, |w| {
+        ratatui::widgets::Widget::render(w, area, &mut buf)
+    });
 
     let mut s = String::new();
     for y in 0..height {
@@ -98,11 +136,14 @@ pub extern "C" fn ratatui_paragraph_new(text_utf8: *const c_char) -> *mut FfiPar
     Box::into_raw(Box::new(FfiParagraph {
         lines,
         block: None,
+        block_extras: BlockExtras::default(),
         align: None,
         wrap_trim: None,
         scroll_x: None,
         scroll_y: None,
         base_style: None,
+        follow_tail: false,
+        text: None,
     }))
 }
 
@@ -111,11 +152,14 @@ pub extern "C" fn ratatui_paragraph_new_empty() -> *mut FfiParagraph {
     Box::into_raw(Box::new(FfiParagraph {
         lines: Vec::new(),
         block: None,
+        block_extras: BlockExtras::default(),
         align: None,
         wrap_trim: None,
         scroll_x: None,
         scroll_y: None,
         base_style: None,
+        follow_tail: false,
+        text: None,
     }))
 }
 
@@ -146,6 +190,11 @@ pub extern "C" fn ratatui_paragraph_append_span(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_span_ex(para: *mut FfiParagraph, span: FfiSpanEx) {
+    ratatui_paragraph_append_spans_ex(para, &span, 1);
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_paragraph_append_spans(
     para: *mut FfiParagraph,
@@ -171,6 +220,25 @@ pub extern "C" fn ratatui_paragraph_append_spans(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_spans_ex(
+    para: *mut FfiParagraph,
+    spans: *const FfiSpanEx,
+    len: usize,
+) {
+    if para.is_null() || spans.is_null() || len == 0 {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    if let Some(sp) = spans_from_ffi_ex(spans, len) {
+        if let Some(last) = p.lines.last_mut() {
+            last.spans.extend(sp);
+        } else {
+            p.lines.push(Line::from(sp));
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_paragraph_append_line_spans(
     para: *mut FfiParagraph,
@@ -192,6 +260,21 @@ pub extern "C" fn ratatui_paragraph_append_line_spans(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_line_spans_ex(
+    para: *mut FfiParagraph,
+    spans: *const FfiSpanEx,
+    len: usize,
+) {
+    if para.is_null() || spans.is_null() || len == 0 {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    if let Some(sp) = spans_from_ffi_ex(spans, len) {
+        p.lines.push(Line::from(sp));
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_paragraph_append_lines_spans(
     para: *mut FfiParagraph,
@@ -222,6 +305,197 @@ pub extern "C" fn ratatui_paragraph_append_lines_spans(
     }
 }
 
+// Like `_append_lines_spans`, with a style and alignment per line.
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_lines_spans_ex(
+    para: *mut FfiParagraph,
+    lines: *const FfiLineSpansEx,
+    len: usize,
+) {
+    if para.is_null() || lines.is_null() || len == 0 {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return;
+        }
+    }
+    p.lines.extend(lines_from_ffi_ex(lines, len));
+}
+
+// Appends the text as new lines, converting SGR escapes into styles.
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_ansi(
+    para: *mut FfiParagraph,
+    bytes: *const u8,
+    len: usize,
+) {
+    if para.is_null() {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    if let Some(lines) = ansi_lines_from_ffi(bytes, len) {
+        p.lines.extend(lines);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_markup(
+    para: *mut FfiParagraph,
+    markup_utf8: *const c_char,
+) {
+    if para.is_null() {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    if let Some(m) = markup_str_from_c(markup_utf8) {
+        p.lines.extend(parse_markup(m));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_insert_line(
+    para: *mut FfiParagraph,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyle,
+) -> bool {
+    if para.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match line_from_utf8(text_utf8, style) {
+        Some(line) => vec_insert_at(&mut p.lines, index, line),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_insert_line_ex(
+    para: *mut FfiParagraph,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyleEx,
+) -> bool {
+    if para.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match line_from_utf8_ex(text_utf8, style) {
+        Some(line) => vec_insert_at(&mut p.lines, index, line),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_insert_line_spans(
+    para: *mut FfiParagraph,
+    index: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    if para.is_null() || spans.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match spans_from_ffi(spans, len) {
+        Some(sp) => vec_insert_at(&mut p.lines, index, Line::from(sp)),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_insert_line_spans_ex(
+    para: *mut FfiParagraph,
+    index: usize,
+    spans: *const FfiSpanEx,
+    len: usize,
+) -> bool {
+    if para.is_null() || spans.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match spans_from_ffi_ex(spans, len) {
+        Some(sp) => vec_insert_at(&mut p.lines, index, Line::from(sp)),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_replace_line(
+    para: *mut FfiParagraph,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyle,
+) -> bool {
+    if para.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match line_from_utf8(text_utf8, style) {
+        Some(line) => vec_replace_at(&mut p.lines, index, line),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_replace_line_ex(
+    para: *mut FfiParagraph,
+    index: usize,
+    text_utf8: *const c_char,
+    style: FfiStyleEx,
+) -> bool {
+    if para.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match line_from_utf8_ex(text_utf8, style) {
+        Some(line) => vec_replace_at(&mut p.lines, index, line),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_replace_line_spans(
+    para: *mut FfiParagraph,
+    index: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    if para.is_null() || spans.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match spans_from_ffi(spans, len) {
+        Some(sp) => vec_replace_at(&mut p.lines, index, Line::from(sp)),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_replace_line_spans_ex(
+    para: *mut FfiParagraph,
+    index: usize,
+    spans: *const FfiSpanEx,
+    len: usize,
+) -> bool {
+    if para.is_null() || spans.is_null() {
+        return false;
+    }
+    let p = unsafe { &mut *para };
+    match spans_from_ffi_ex(spans, len) {
+        Some(sp) => vec_replace_at(&mut p.lines, index, Line::from(sp)),
+        None => false,
+    }
+}
+
+ratatui_vec_remove_fn!(ratatui_paragraph_remove_line, FfiParagraph, lines);
+ratatui_vec_swap_fn!(ratatui_paragraph_swap_lines, FfiParagraph, lines);
+ratatui_vec_truncate_fn!(ratatui_paragraph_truncate_lines, FfiParagraph, lines);
+ratatui_vec_clear_fn!(ratatui_paragraph_clear_lines, FfiParagraph, lines);
+
 #[no_mangle]
 pub extern "C" fn ratatui_paragraph_line_break(para: *mut FfiParagraph) {
     if para.is_null() {
@@ -263,12 +537,108 @@ pub extern "C" fn ratatui_paragraph_set_scroll(para: *mut FfiParagraph, x: u16,
     p.scroll_y = Some(y);
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_set_follow_tail(para: *mut FfiParagraph, enabled: bool) {
+    if para.is_null() {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    p.follow_tail = enabled;
+}
+
+// Rendered height (including block borders) when drawn `width` columns wide.
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_line_count(para: *const FfiParagraph, width: u16) -> usize {
+    if para.is_null() {
+        return 0;
+    }
+    let p = unsafe { &*para };
+    let area = Rect::new(0, 0, width, u16::MAX);
+    let inner_width = p
+        .block_extras
+        .apply(&p.block)
+        .map_or(width, |b| b.inner(area).width);
+    if inner_width == 0 {
+        return 0;
+    }
+    with_paragraph_widget(p, area, |w| w.line_count(inner_width))
+}
+
+// Width (including block borders) needed to show every line without wrapping.
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_line_width(para: *const FfiParagraph) -> usize {
+    if para.is_null() {
+        return 0;
+    }
+    let p = unsafe { &*para };
+    with_paragraph_widget(p, Rect::default(), |w| w.line_width())
+}
+
+// Shows the FfiText instead of the paragraph's own lines (null detaches).
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_set_text(para: *mut FfiParagraph, txt: *const FfiText) {
+    if para.is_null() {
+        return;
+    }
+    let p = unsafe { &mut *para };
+    p.text = shared_from_ffi(txt);
+}
+
 ratatui_set_style_fn!(ratatui_paragraph_set_style, FfiParagraph, base_style);
+ratatui_set_style_ex_fn!(ratatui_paragraph_set_style_ex, FfiParagraph, base_style);
+ratatui_set_style_slot_fn!(ratatui_paragraph_set_style_slot, FfiParagraph, base_style);
 ratatui_block_title_fn!(ratatui_paragraph_set_block_title, FfiParagraph);
 ratatui_block_title_spans_fn!(ratatui_paragraph_set_block_title_spans, FfiParagraph);
+ratatui_block_title_markup_fn!(ratatui_paragraph_set_block_title_markup, FfiParagraph);
+ratatui_block_title_text_fn!(ratatui_paragraph_set_block_title_text, FfiParagraph);
 ratatui_block_adv_fn!(ratatui_paragraph_set_block_adv, FfiParagraph);
 ratatui_reserve_vec_fn!(ratatui_paragraph_reserve_lines, FfiParagraph, lines);
 ratatui_block_title_alignment_fn!(ratatui_paragraph_set_block_title_alignment, FfiParagraph);
+crate::ratatui_block_add_title_fn!(
+    ratatui_paragraph_add_block_title_spans,
+    ratatui_paragraph_add_block_title_markup,
+    FfiParagraph
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_paragraph_set_block_title_spans_ex,
+    ratatui_paragraph_add_block_title_spans_ex,
+    FfiParagraph
+);
+crate::ratatui_block_style_fn!(ratatui_paragraph_set_block_style, FfiParagraph, style);
+crate::ratatui_block_style_fn!(
+    ratatui_paragraph_set_block_border_style,
+    FfiParagraph,
+    border_style
+);
+crate::ratatui_block_style_fn!(
+    ratatui_paragraph_set_block_title_style,
+    FfiParagraph,
+    title_style
+);
+crate::ratatui_block_style_ex_fn!(ratatui_paragraph_set_block_style_ex, FfiParagraph, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_paragraph_set_block_border_style_ex,
+    FfiParagraph,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_paragraph_set_block_title_style_ex,
+    FfiParagraph,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_paragraph_set_block_style_slot, FfiParagraph, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_paragraph_set_block_border_style_slot,
+    FfiParagraph,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_paragraph_set_block_title_style_slot,
+    FfiParagraph,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_paragraph_set_block_border_set, FfiParagraph);
+crate::ratatui_block_set_fn!(ratatui_paragraph_set_block, FfiParagraph);
 
 #[no_mangle]
 pub extern "C" fn ratatui_paragraph_free(para: *mut FfiParagraph) {
@@ -295,6 +665,15 @@ pub extern "C" fn ratatui_paragraph_append_line(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_paragraph_append_line_ex(
+    para: *mut FfiParagraph,
+    text_utf8: *const c_char,
+    style: FfiStyleEx,
+) {
+    ratatui_paragraph_append_line_spans_ex(para, &FfiSpanEx { text_utf8, style }, 1);
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_terminal_draw_paragraph(
     term: *mut FfiTerminal,
@@ -306,26 +685,9 @@ pub extern "C" fn ratatui_terminal_draw_paragraph(
         }
         let t = unsafe { &mut *term };
         let p = unsafe { &*para };
-        let lines = p.lines.clone();
-        let mut widget = Paragraph::new(lines);
-        if let Some(a) = p.align {
-            widget = widget.alignment(a);
-        }
-        if let Some(trim) = p.wrap_trim {
-            widget = widget.wrap(ratatui::widgets::Wrap { trim });
-        }
-        if let (Some(sx), Some(sy)) = (p.scroll_x, p.scroll_y) {
-            widget = widget.scroll((sx, sy));
-        }
-        if let Some(st) = &p.base_style {
-            widget = widget.style(st.clone());
-        }
-        if let Some(b) = &p.block {
-            widget = widget.block(b.clone());
-        }
         let res = t.terminal.draw(|frame| {
             let area: Rect = frame.area();
-            frame.render_widget(widget.clone(), area);
+            with_paragraph_widget(p, area, |w| frame.render_widget(w, area));
         });
         res.is_ok()
     })
@@ -349,23 +711,6 @@ pub extern "C" fn ratatui_terminal_draw_paragraph_in(
             width: rect.width,
             height: rect.height,
         };
-        let lines = p.lines.clone();
-        let mut widget = Paragraph::new(lines);
-        if let Some(a) = p.align {
-            widget = widget.alignment(a);
-        }
-        if let Some(trim) = p.wrap_trim {
-            widget = widget.wrap(ratatui::widgets::Wrap { trim });
-        }
-        if let (Some(sx), Some(sy)) = (p.scroll_x, p.scroll_y) {
-            widget = widget.scroll((sx, sy));
-        }
-        if let Some(st) = &p.base_style {
-            widget = widget.style(st.clone());
-        }
-        if let Some(b) = &p.block {
-            widget = widget.block(b.clone());
-        }
         #[cfg(feature = "ffi_safety")]
         {
             if !crate::ffi::safety::check_rect_dims(rect) {
@@ -373,7 +718,7 @@ pub extern "C" fn ratatui_terminal_draw_paragraph_in(
             }
         }
         let res = t.terminal.draw(|frame| {
-            frame.render_widget(widget.clone(), area);
+            with_paragraph_widget(p, area, |w| frame.render_widget(w, area));
         });
         res.is_ok()
     })
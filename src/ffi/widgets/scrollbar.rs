@@ -12,12 +12,14 @@
 
 // use crate::*; // enable when moving implementations
 
+use crate::ffi::widgets::block::BlockExtras;
 use crate::{
     ratatui_block_adv_fn, ratatui_block_title_alignment_fn, ratatui_block_title_fn,
-    ratatui_block_title_spans_fn, FfiRect, FfiTerminal,
+    ratatui_block_title_spans_fn, FfiRect, FfiSymbolsScrollbarSet, FfiTerminal,
 };
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::symbols::scrollbar;
 use ratatui::widgets::{
     Block, Scrollbar as RtScrollbar, ScrollbarOrientation as RtScrollbarOrientation,
     ScrollbarState as RtScrollbarState,
@@ -34,11 +36,103 @@ ratatui_block_title_fn!(ratatui_scrollbar_set_block_title, FfiScrollbar);
 #[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
 ratatui_block_title_spans_fn!(ratatui_scrollbar_set_block_title_spans, FfiScrollbar);
 
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_title_markup_fn!(ratatui_scrollbar_set_block_title_markup, FfiScrollbar);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_title_text_fn!(ratatui_scrollbar_set_block_title_text, FfiScrollbar);
+
 #[cfg(feature = "scrollbar")]
 #[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
 ratatui_block_adv_fn!(ratatui_scrollbar_set_block_adv, FfiScrollbar);
 ratatui_block_title_alignment_fn!(ratatui_scrollbar_set_block_title_alignment, FfiScrollbar);
 
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_add_title_fn!(
+    ratatui_scrollbar_add_block_title_spans,
+    ratatui_scrollbar_add_block_title_markup,
+    FfiScrollbar
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_scrollbar_set_block_title_spans_ex,
+    ratatui_scrollbar_add_block_title_spans_ex,
+    FfiScrollbar
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_fn!(ratatui_scrollbar_set_block_style, FfiScrollbar, style);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_fn!(
+    ratatui_scrollbar_set_block_border_style,
+    FfiScrollbar,
+    border_style
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_fn!(
+    ratatui_scrollbar_set_block_title_style,
+    FfiScrollbar,
+    title_style
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_ex_fn!(ratatui_scrollbar_set_block_style_ex, FfiScrollbar, style);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_ex_fn!(
+    ratatui_scrollbar_set_block_border_style_ex,
+    FfiScrollbar,
+    border_style
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_ex_fn!(
+    ratatui_scrollbar_set_block_title_style_ex,
+    FfiScrollbar,
+    title_style
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_slot_fn!(ratatui_scrollbar_set_block_style_slot, FfiScrollbar, style);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_slot_fn!(
+    ratatui_scrollbar_set_block_border_style_slot,
+    FfiScrollbar,
+    border_style
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_style_slot_fn!(
+    ratatui_scrollbar_set_block_title_style_slot,
+    FfiScrollbar,
+    title_style
+);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_border_set_fn!(ratatui_scrollbar_set_block_border_set, FfiScrollbar);
+
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+crate::ratatui_block_set_fn!(ratatui_scrollbar_set_block, FfiScrollbar);
+
 #[cfg(feature = "scrollbar")]
 #[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
 #[repr(u32)]
@@ -52,11 +146,48 @@ pub enum FfiScrollbarOrient {
 #[repr(C)]
 pub struct FfiScrollbar {
     pub orient: u32,
-    pub position: u16,
-    pub content_len: u16,
-    pub viewport_len: u16,
+    pub position: usize,
+    pub content_len: usize,
+    pub viewport_len: usize,
     pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
     pub side: Option<u32>,
+    pub symbols: Option<scrollbar::Set>,
+}
+
+#[cfg(feature = "scrollbar")]
+impl FfiScrollbar {
+    fn orientation(&self) -> RtScrollbarOrientation {
+        // side mapping: 0=VerticalLeft, 1=VerticalRight, 2=HorizontalTop, 3=HorizontalBottom
+        match self.side {
+            Some(0) => RtScrollbarOrientation::VerticalLeft,
+            Some(2) => RtScrollbarOrientation::HorizontalTop,
+            Some(3) => RtScrollbarOrientation::HorizontalBottom,
+            Some(_) => RtScrollbarOrientation::VerticalRight,
+            None if self.orient == FfiScrollbarOrient::Horizontal as u32 => {
+                RtScrollbarOrientation::HorizontalTop
+            }
+            None => RtScrollbarOrientation::VerticalRight,
+        }
+    }
+
+    // The block (if any) is drawn first and the scrollbar placed in its inner area.
+    pub(crate) fn render_into(&self, area: Rect, buf: &mut Buffer) {
+        let mut area = area;
+        if let Some(b) = self.block_extras.apply(&self.block) {
+            let inner = b.inner(area);
+            ratatui::widgets::Widget::render(b, area, buf);
+            area = inner;
+        }
+        let mut state = RtScrollbarState::new(self.content_len)
+            .position(self.position)
+            .viewport_content_length(self.viewport_len);
+        let mut w = RtScrollbar::new(self.orientation());
+        if let Some(set) = &self.symbols {
+            w = w.symbols(set.clone());
+        }
+        ratatui::widgets::StatefulWidget::render(w, area, buf, &mut state);
+    }
 }
 
 #[cfg(feature = "scrollbar")]
@@ -87,7 +218,9 @@ pub extern "C" fn ratatui_scrollbar_new() -> *mut FfiScrollbar {
         content_len: 0,
         viewport_len: 0,
         block: None,
+        block_extras: BlockExtras::default(),
         side: None,
+        symbols: None,
     }))
 }
 
@@ -112,6 +245,27 @@ pub extern "C" fn ratatui_scrollbar_configure(
     position: u16,
     content_len: u16,
     viewport_len: u16,
+) {
+    if s.is_null() {
+        return;
+    }
+    let sb = unsafe { &mut *s };
+    sb.orient = orient;
+    sb.position = position as usize;
+    sb.content_len = content_len as usize;
+    sb.viewport_len = viewport_len as usize;
+}
+
+// Same as `ratatui_scrollbar_configure` with lengths wide enough for virtual list/table counts.
+#[no_mangle]
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+pub extern "C" fn ratatui_scrollbar_configure_ex(
+    s: *mut FfiScrollbar,
+    orient: u32,
+    position: usize,
+    content_len: usize,
+    viewport_len: usize,
 ) {
     if s.is_null() {
         return;
@@ -135,6 +289,25 @@ pub extern "C" fn ratatui_scrollbar_set_orientation_side(s: *mut FfiScrollbar, s
     sb.side = Some(side);
 }
 
+// Track, thumb and begin/end arrows (empty begin/end strings draw no arrows); false on invalid
+// UTF-8.
+#[no_mangle]
+#[cfg(feature = "scrollbar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
+pub extern "C" fn ratatui_scrollbar_set_symbols(
+    s: *mut FfiScrollbar,
+    set: FfiSymbolsScrollbarSet,
+) -> bool {
+    if s.is_null() {
+        return false;
+    }
+    let Some(set) = crate::ffi::symbols::scrollbar_set_from_ffi(set) else {
+        return false;
+    };
+    unsafe { &mut *s }.symbols = Some(set);
+    true
+}
+
 #[no_mangle]
 #[cfg(feature = "scrollbar")]
 #[cfg_attr(docsrs, doc(cfg(feature = "scrollbar")))]
@@ -148,38 +321,21 @@ pub extern "C" fn ratatui_terminal_draw_scrollbar_in(
     }
     let t = unsafe { &mut *term };
     let sb = unsafe { &*s };
-        let area = Rect {
-            x: rect.x,
-            y: rect.y,
-            width: rect.width,
-            height: rect.height,
-        };
-        #[cfg(feature = "ffi_safety")]
-        {
-            if !crate::ffi::safety::check_rect_dims(rect) {
-                return false;
-            }
-        }
-    let orient = if let Some(side) = sb.side {
-        match side {
-            0 => RtScrollbarOrientation::VerticalLeft,
-            1 => RtScrollbarOrientation::VerticalRight,
-            2 => RtScrollbarOrientation::HorizontalTop,
-            3 => RtScrollbarOrientation::HorizontalBottom,
-            _ => RtScrollbarOrientation::VerticalRight,
-        }
-    } else if sb.orient == FfiScrollbarOrient::Horizontal as u32 {
-        RtScrollbarOrientation::HorizontalTop
-    } else {
-        RtScrollbarOrientation::VerticalRight
+    let area = Rect {
+        x: rect.x,
+        y: rect.y,
+        width: rect.width,
+        height: rect.height,
     };
-    let mut state = RtScrollbarState::new(sb.content_len as usize)
-        .position(sb.position as usize)
-        .viewport_content_length(sb.viewport_len as usize);
-    let w = RtScrollbar::new(orient);
-    let res = t.terminal.draw(|frame| {
-        frame.render_stateful_widget(w.clone(), area, &mut state);
-    });
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_rect_dims(rect) {
+            return false;
+        }
+    }
+    let res = t
+        .terminal
+        .draw(|frame| sb.render_into(area, frame.buffer_mut()));
     res.is_ok()
 }
 
@@ -203,24 +359,7 @@ pub extern "C" fn ratatui_headless_render_scrollbar(
         height,
     };
     let mut buf = Buffer::empty(area);
-    let orient = if let Some(side) = sb.side {
-        match side {
-            0 => RtScrollbarOrientation::VerticalLeft,
-            1 => RtScrollbarOrientation::VerticalRight,
-            2 => RtScrollbarOrientation::HorizontalTop,
-            3 => RtScrollbarOrientation::HorizontalBottom,
-            _ => RtScrollbarOrientation::VerticalRight,
-        }
-    } else if sb.orient == FfiScrollbarOrient::Horizontal as u32 {
-        RtScrollbarOrientation::HorizontalTop
-    } else {
-        RtScrollbarOrientation::VerticalRight
-    };
-    let mut state = RtScrollbarState::new(sb.content_len as usize)
-        .position(sb.position as usize)
-        .viewport_content_length(sb.viewport_len as usize);
-    let w = RtScrollbar::new(orient);
-    ratatui::widgets::StatefulWidget::render(w, area, &mut buf, &mut state);
+    sb.render_into(area, &mut buf);
     let mut s = String::new();
     for y in 0..height {
         for x in 0..width {
@@ -11,38 +11,231 @@
 
 // use crate::*; // enable when moving implementations
 
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::{
     ratatui_block_adv_fn, ratatui_block_title_fn, ratatui_block_title_spans_fn,
-    ratatui_set_style_fn, FfiRect, FfiTerminal,
+    ratatui_set_style_ex_fn, ratatui_set_style_fn, ratatui_set_style_slot_fn, FfiRect,
+    FfiRenderDirection, FfiStyle, FfiStyleEx, FfiSymbolsBarSet, FfiTerminal,
 };
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::prelude::Style;
-use ratatui::widgets::{Block, Sparkline as RtSparkline};
-use std::ffi::{c_char, CString};
+use ratatui::style::Style;
+use ratatui::symbols::bar;
+use ratatui::widgets::{Block, RenderDirection, Sparkline as RtSparkline, SparklineBar};
+use std::collections::VecDeque;
+use std::ffi::{c_char, CStr, CString};
 
 // ----- Sparkline -----
 
 ratatui_set_style_fn!(ratatui_sparkline_set_style, FfiSparkline, style);
+ratatui_set_style_ex_fn!(ratatui_sparkline_set_style_ex, FfiSparkline, style);
+ratatui_set_style_slot_fn!(ratatui_sparkline_set_style_slot, FfiSparkline, style);
+ratatui_set_style_fn!(
+    ratatui_sparkline_set_absent_value_style,
+    FfiSparkline,
+    absent_style
+);
+ratatui_set_style_ex_fn!(
+    ratatui_sparkline_set_absent_value_style_ex,
+    FfiSparkline,
+    absent_style
+);
+ratatui_set_style_slot_fn!(
+    ratatui_sparkline_set_absent_value_style_slot,
+    FfiSparkline,
+    absent_style
+);
 ratatui_block_title_fn!(ratatui_sparkline_set_block_title, FfiSparkline);
 ratatui_block_title_spans_fn!(ratatui_sparkline_set_block_title_spans, FfiSparkline);
+crate::ratatui_block_title_markup_fn!(ratatui_sparkline_set_block_title_markup, FfiSparkline);
+
+crate::ratatui_block_title_text_fn!(ratatui_sparkline_set_block_title_text, FfiSparkline);
 ratatui_block_adv_fn!(ratatui_sparkline_set_block_adv, FfiSparkline);
+crate::ratatui_block_add_title_fn!(
+    ratatui_sparkline_add_block_title_spans,
+    ratatui_sparkline_add_block_title_markup,
+    FfiSparkline
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_sparkline_set_block_title_spans_ex,
+    ratatui_sparkline_add_block_title_spans_ex,
+    FfiSparkline
+);
+crate::ratatui_block_style_fn!(ratatui_sparkline_set_block_style, FfiSparkline, style);
+crate::ratatui_block_style_fn!(
+    ratatui_sparkline_set_block_border_style,
+    FfiSparkline,
+    border_style
+);
+crate::ratatui_block_style_fn!(
+    ratatui_sparkline_set_block_title_style,
+    FfiSparkline,
+    title_style
+);
+crate::ratatui_block_style_ex_fn!(ratatui_sparkline_set_block_style_ex, FfiSparkline, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_sparkline_set_block_border_style_ex,
+    FfiSparkline,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_sparkline_set_block_title_style_ex,
+    FfiSparkline,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_sparkline_set_block_style_slot, FfiSparkline, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_sparkline_set_block_border_style_slot,
+    FfiSparkline,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_sparkline_set_block_title_style_slot,
+    FfiSparkline,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_sparkline_set_block_border_set, FfiSparkline);
+crate::ratatui_block_set_fn!(ratatui_sparkline_set_block, FfiSparkline);
 
 #[repr(C)]
 pub struct FfiSparkline {
-    pub values: Vec<u64>,
+    pub values: VecDeque<SparklineValue>,
     pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
     pub max: Option<u64>,
+    pub style: Option<StyleRef>,
+    pub bar_set: Option<bar::Set>,
+    pub capacity: usize, // 0 = unbounded
+    pub direction: Option<RenderDirection>,
+    pub absent_symbol: Option<String>,
+    pub absent_style: Option<StyleRef>,
+    pub threshold: Option<(u64, StyleRef)>,
+}
+
+// One bar; `None` is an absent sample (drawn with the absent symbol), distinct from 0.
+#[derive(Copy, Clone)]
+pub struct SparklineValue {
+    pub value: Option<u64>,
     pub style: Option<Style>,
 }
 
+impl From<u64> for SparklineValue {
+    fn from(v: u64) -> Self {
+        Self {
+            value: Some(v),
+            style: None,
+        }
+    }
+}
+
+impl From<FfiSparklineBar> for SparklineValue {
+    fn from(b: FfiSparklineBar) -> Self {
+        Self {
+            value: (!b.absent).then_some(b.value),
+            style: Some(crate::style_from_ffi(b.style)),
+        }
+    }
+}
+
+impl From<FfiSparklineBarEx> for SparklineValue {
+    fn from(b: FfiSparklineBarEx) -> Self {
+        Self {
+            value: (!b.absent).then_some(b.value),
+            style: Some(crate::style_from_ffi_ex(b.style)),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiSparklineBar {
+    pub value: u64,
+    pub absent: bool,
+    pub style: FfiStyle, // patched over the sparkline (and threshold) style
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiSparklineBarEx {
+    pub value: u64,
+    pub absent: bool,
+    pub style: FfiStyleEx,
+}
+
+fn values_from_bars<T: Copy + Into<SparklineValue>>(
+    bars: *const T,
+    len: usize,
+) -> Option<Vec<SparklineValue>> {
+    if bars.is_null() {
+        return None;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return None;
+        }
+    }
+    let slice = unsafe { std::slice::from_raw_parts(bars, len) };
+    Some(slice.iter().map(|&b| b.into()).collect())
+}
+
+impl FfiSparkline {
+    pub(crate) fn widget(&self) -> RtSparkline<'static> {
+        let threshold = self.threshold.map(|(t, st)| (t, st.resolve()));
+        let bars: Vec<SparklineBar> = self
+            .values
+            .iter()
+            .map(|v| {
+                let style = match threshold {
+                    Some((t, st)) if v.value.is_some_and(|x| x > t) => {
+                        Some(st.patch(v.style.unwrap_or_default()))
+                    }
+                    _ => v.style,
+                };
+                SparklineBar::from(v.value).style(style)
+            })
+            .collect();
+        let mut w = RtSparkline::default().data(bars);
+        if let Some(set) = &self.bar_set {
+            w = w.bar_set(set.clone());
+        }
+        if let Some(m) = self.max {
+            w = w.max(m);
+        }
+        if let Some(st) = &self.style {
+            w = w.style(st.resolve());
+        }
+        if let Some(d) = self.direction {
+            w = w.direction(d);
+        }
+        if let Some(sym) = &self.absent_symbol {
+            w = w.absent_value_symbol(sym.clone());
+        }
+        if let Some(st) = &self.absent_style {
+            w = w.absent_value_style(st.resolve());
+        }
+        if let Some(bl) = self.block_extras.apply(&self.block) {
+            w = w.block(bl);
+        }
+        w
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_sparkline_new() -> *mut FfiSparkline {
     Box::into_raw(Box::new(FfiSparkline {
-        values: Vec::new(),
+        values: VecDeque::new(),
         block: None,
+        block_extras: BlockExtras::default(),
         max: None,
         style: None,
+        bar_set: None,
+        capacity: 0,
+        direction: None,
+        absent_symbol: None,
+        absent_style: None,
+        threshold: None,
     }))
 }
 
@@ -56,6 +249,22 @@ pub extern "C" fn ratatui_sparkline_free(s: *mut FfiSparkline) {
     }
 }
 
+// Bar glyphs from empty to full in eighths; false on invalid UTF-8.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_bar_set(
+    s: *mut FfiSparkline,
+    set: FfiSymbolsBarSet,
+) -> bool {
+    if s.is_null() {
+        return false;
+    }
+    let Some(set) = crate::ffi::symbols::bar_set_from_ffi(set) else {
+        return false;
+    };
+    unsafe { &mut *s }.bar_set = Some(set);
+    true
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_sparkline_set_values(
     s: *mut FfiSparkline,
@@ -67,7 +276,200 @@ pub extern "C" fn ratatui_sparkline_set_values(
     }
     let sp = unsafe { &mut *s };
     let slice = unsafe { std::slice::from_raw_parts(values, len) };
-    sp.values = slice.to_vec();
+    let vals: Vec<SparklineValue> = slice.iter().map(|&v| v.into()).collect();
+    sp.values.clear();
+    crate::push_bounded(&mut sp.values, sp.capacity, &vals);
+}
+
+// Streaming values: keep only the newest `capacity` values (0 = unbounded) and push new samples
+// each frame instead of resending the whole series.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_capacity(s: *mut FfiSparkline, capacity: usize) {
+    if s.is_null() {
+        return;
+    }
+    let sp = unsafe { &mut *s };
+    sp.capacity = capacity;
+    crate::push_bounded(&mut sp.values, capacity, &[]);
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_push(s: *mut FfiSparkline, value: u64) {
+    if s.is_null() {
+        return;
+    }
+    let sp = unsafe { &mut *s };
+    crate::push_bounded(&mut sp.values, sp.capacity, &[value.into()]);
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_push_many(
+    s: *mut FfiSparkline,
+    values: *const u64,
+    len: usize,
+) {
+    if s.is_null() || values.is_null() {
+        return;
+    }
+    #[cfg(feature = "ffi_safety")]
+    {
+        if !crate::ffi::safety::check_batch_len(len) {
+            return;
+        }
+    }
+    let sp = unsafe { &mut *s };
+    let slice = unsafe { std::slice::from_raw_parts(values, len) };
+    let vals: Vec<SparklineValue> = slice.iter().map(|&v| v.into()).collect();
+    crate::push_bounded(&mut sp.values, sp.capacity, &vals);
+}
+
+// Bars with per-bar styles and absent samples; replaces all values.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_bars(
+    s: *mut FfiSparkline,
+    bars: *const FfiSparklineBar,
+    len: usize,
+) {
+    set_bars(s, values_from_bars(bars, len));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_bars_ex(
+    s: *mut FfiSparkline,
+    bars: *const FfiSparklineBarEx,
+    len: usize,
+) {
+    set_bars(s, values_from_bars(bars, len));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_push_bars(
+    s: *mut FfiSparkline,
+    bars: *const FfiSparklineBar,
+    len: usize,
+) {
+    push_bars(s, values_from_bars(bars, len));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_push_bars_ex(
+    s: *mut FfiSparkline,
+    bars: *const FfiSparklineBarEx,
+    len: usize,
+) {
+    push_bars(s, values_from_bars(bars, len));
+}
+
+fn set_bars(s: *mut FfiSparkline, vals: Option<Vec<SparklineValue>>) {
+    if s.is_null() {
+        return;
+    }
+    let Some(vals) = vals else {
+        return;
+    };
+    let sp = unsafe { &mut *s };
+    sp.values.clear();
+    crate::push_bounded(&mut sp.values, sp.capacity, &vals);
+}
+
+fn push_bars(s: *mut FfiSparkline, vals: Option<Vec<SparklineValue>>) {
+    if s.is_null() {
+        return;
+    }
+    let Some(vals) = vals else {
+        return;
+    };
+    let sp = unsafe { &mut *s };
+    crate::push_bounded(&mut sp.values, sp.capacity, &vals);
+}
+
+// Pushes a gap: drawn with the absent value symbol/style rather than as a zero bar.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_push_absent(s: *mut FfiSparkline) {
+    if s.is_null() {
+        return;
+    }
+    let sp = unsafe { &mut *s };
+    let gap = SparklineValue {
+        value: None,
+        style: None,
+    };
+    crate::push_bounded(&mut sp.values, sp.capacity, &[gap]);
+}
+
+// `FfiRenderDirection`: RightToLeft puts the first value on the right.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_direction(s: *mut FfiSparkline, direction: u32) {
+    if s.is_null() {
+        return;
+    }
+    unsafe { &mut *s }.direction = Some(if direction == FfiRenderDirection::RightToLeft as u32 {
+        RenderDirection::RightToLeft
+    } else {
+        RenderDirection::LeftToRight
+    });
+}
+
+// Symbol drawn for absent samples (ratatui's default is a bottom-row bar); null restores it.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_absent_value_symbol(
+    s: *mut FfiSparkline,
+    symbol_utf8: *const c_char,
+) {
+    if s.is_null() {
+        return;
+    }
+    let sp = unsafe { &mut *s };
+    sp.absent_symbol = if symbol_utf8.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(symbol_utf8) }
+            .to_str()
+            .ok()
+            .map(|v| v.to_string())
+    };
+}
+
+// Bars above `threshold` get `style` (a bar's own style still wins where both set a field);
+// pass `u64::MAX` to turn it off.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_threshold_style(
+    s: *mut FfiSparkline,
+    threshold: u64,
+    style: FfiStyle,
+) {
+    if s.is_null() {
+        return;
+    }
+    unsafe { &mut *s }.threshold =
+        (threshold != u64::MAX).then(|| (threshold, crate::style_from_ffi(style).into()));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_threshold_style_ex(
+    s: *mut FfiSparkline,
+    threshold: u64,
+    style: FfiStyleEx,
+) {
+    if s.is_null() {
+        return;
+    }
+    unsafe { &mut *s }.threshold =
+        (threshold != u64::MAX).then(|| (threshold, crate::style_from_ffi_ex(style).into()));
+}
+
+// Threshold style bound to a theme slot, resolved at draw time.
+#[no_mangle]
+pub extern "C" fn ratatui_sparkline_set_threshold_style_slot(
+    s: *mut FfiSparkline,
+    threshold: u64,
+    slot: u32,
+) {
+    if s.is_null() {
+        return;
+    }
+    unsafe { &mut *s }.threshold =
+        (threshold != u64::MAX).then_some((threshold, StyleRef::Slot(slot)));
 }
 
 #[no_mangle]
@@ -104,10 +506,7 @@ pub extern "C" fn ratatui_terminal_draw_sparkline_in(
                 return false;
             }
         }
-        let mut w = RtSparkline::default().data(&sp.values);
-        if let Some(bl) = &sp.block {
-            w = w.block(bl.clone());
-        }
+        let w = sp.widget();
         let res = t.terminal.draw(|frame| {
             frame.render_widget(w.clone(), area);
         });
@@ -133,16 +532,7 @@ pub extern "C" fn ratatui_headless_render_sparkline(
         height,
     };
     let mut buf = Buffer::empty(area);
-    let mut w = RtSparkline::default().data(&sp.values);
-    if let Some(m) = sp.max {
-        w = w.max(m);
-    }
-    if let Some(st) = &sp.style {
-        w = w.style(st.clone());
-    }
-    if let Some(bl) = &sp.block {
-        w = w.block(bl.clone());
-    }
+    let w = sp.widget();
     ratatui::widgets::Widget::render(w, area, &mut buf);
     let mut s = String::new();
     for y in 0..height {
@@ -164,3 +554,69 @@ pub extern "C" fn ratatui_headless_render_sparkline(
         Err(_) => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(s: *mut FfiSparkline) -> Vec<Option<u64>> {
+        unsafe { &*s }.values.iter().map(|v| v.value).collect()
+    }
+
+    #[test]
+    fn capacity_keeps_the_newest_values() {
+        let s = ratatui_sparkline_new();
+        ratatui_sparkline_set_values(s, [1, 2, 3, 4].as_ptr(), 4);
+        ratatui_sparkline_set_capacity(s, 3);
+        assert_eq!(values(s), [Some(2), Some(3), Some(4)]);
+        ratatui_sparkline_push(s, 5);
+        ratatui_sparkline_push_absent(s);
+        assert_eq!(values(s), [Some(4), Some(5), None]);
+        ratatui_sparkline_push_many(s, [6, 7, 8, 9].as_ptr(), 4);
+        assert_eq!(values(s), [Some(7), Some(8), Some(9)]);
+        // replacing the series still honors the capacity
+        ratatui_sparkline_set_values(s, [1, 2, 3, 4, 5].as_ptr(), 5);
+        assert_eq!(values(s), [Some(3), Some(4), Some(5)]);
+        ratatui_sparkline_set_capacity(s, 0);
+        ratatui_sparkline_push(s, 6);
+        assert_eq!(values(s).len(), 4);
+        ratatui_sparkline_free(s);
+    }
+
+    fn render(s: *const FfiSparkline, width: u16) -> String {
+        let mut out = std::ptr::null_mut();
+        assert!(ratatui_headless_render_sparkline(width, 1, s, &mut out));
+        unsafe { CString::from_raw(out) }.into_string().unwrap()
+    }
+
+    #[test]
+    fn absent_values_and_direction() {
+        let s = ratatui_sparkline_new();
+        ratatui_sparkline_set_max(s, 8);
+        ratatui_sparkline_push(s, 8);
+        ratatui_sparkline_push_absent(s);
+        ratatui_sparkline_push(s, 0);
+        ratatui_sparkline_push(s, 4);
+        assert_eq!(values(s), [Some(8), None, Some(0), Some(4)]);
+        assert_eq!(render(s, 5), "█  ▄ ");
+        // a gap is drawn with the absent symbol, a zero sample as an empty bar
+        let x = CString::new("x").unwrap();
+        ratatui_sparkline_set_absent_value_symbol(s, x.as_ptr());
+        assert_eq!(render(s, 5), "█x ▄ ");
+        ratatui_sparkline_set_direction(s, FfiRenderDirection::RightToLeft as u32);
+        assert_eq!(render(s, 5), " ▄ x█");
+
+        let red = FfiStyle {
+            fg: 2,
+            bg: 0,
+            mods: 0,
+        };
+        ratatui_sparkline_set_absent_value_style(s, red);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        ratatui::widgets::Widget::render(unsafe { &*s }.widget(), area, &mut buf);
+        assert_eq!(buf[(3, 0)].fg, crate::color_from_u32(2).unwrap());
+        assert_eq!(buf[(4, 0)].fg, ratatui::style::Color::Reset);
+        ratatui_sparkline_free(s);
+    }
+}
@@ -1,10 +1,16 @@
+use crate::ffi::markup::{markup_str_from_c, parse_markup, parse_markup_line};
+use crate::ffi::source::{visible_window, FfiFetchRowsFn, VirtualSource};
+use crate::ffi::text::{read_text, shared_from_ffi, snapshot_text, FfiText, SharedText};
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::*;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Rect};
-use ratatui::style::Style;
-use ratatui::text::Line;
-use ratatui::widgets::{Block, Cell, HighlightSpacing as RtHighlightSpacing, Row, Table};
-use std::ffi::{CStr, CString};
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{
+    Block, Cell, HighlightSpacing as RtHighlightSpacing, Row, Table, TableState,
+};
+use std::ffi::{c_void, CStr, CString};
 use std::os::raw::c_char;
 
 #[repr(C)]
@@ -13,25 +19,140 @@ pub struct FfiTableState {
     pub offset: usize,
 }
 
+// A cell in the rich storage: owned lines, or a shared FfiText rendered as-is.
+pub enum TableCell {
+    Lines(Vec<Line<'static>>),
+    Text(SharedText),
+}
+
+impl From<Vec<Line<'static>>> for TableCell {
+    fn from(lines: Vec<Line<'static>>) -> Self {
+        TableCell::Lines(lines)
+    }
+}
+
+impl TableCell {
+    fn to_cell(&self) -> Cell<'static> {
+        match self {
+            TableCell::Lines(lines) => Cell::from(Text::from(lines.clone())),
+            TableCell::Text(t) => Cell::from(snapshot_text(t)),
+        }
+    }
+}
+
 #[repr(C)]
 pub struct FfiTable {
     pub headers: Vec<String>,
     pub rows: Vec<Vec<String>>,
     pub block: Option<Block<'static>>,
+    pub block_extras: BlockExtras,
     pub selected: Option<usize>,
-    pub row_highlight_style: Option<Style>,
+    pub row_highlight_style: Option<StyleRef>,
     pub highlight_symbol: Option<String>,
     pub widths_pct: Option<Vec<u16>>,
     pub widths_constraints: Option<Vec<Constraint>>,
     pub headers_spans: Option<Vec<Line<'static>>>,
     pub rows_spans: Option<Vec<Vec<Line<'static>>>>,
-    pub rows_cells_lines: Option<Vec<Vec<Vec<Line<'static>>>>>,
-    pub header_style: Option<Style>,
+    pub rows_cells_lines: Option<Vec<Vec<TableCell>>>,
+    pub header_style: Option<StyleRef>,
     pub row_height: Option<u16>,
     pub column_spacing: Option<u16>,
-    pub column_highlight_style: Option<Style>,
-    pub cell_highlight_style: Option<Style>,
+    pub column_highlight_style: Option<StyleRef>,
+    pub cell_highlight_style: Option<StyleRef>,
     pub highlight_spacing: Option<RtHighlightSpacing>,
+    pub source: Option<VirtualSource>,
+}
+
+pub(crate) fn table_row_count(tb: &FfiTable) -> usize {
+    if let Some(src) = &tb.source {
+        src.count
+    } else if let Some(rc) = &tb.rows_cells_lines {
+        rc.len()
+    } else if let Some(rs) = &tb.rows_spans {
+        rs.len()
+    } else {
+        tb.rows.len()
+    }
+}
+
+fn row_with_height(cells: Vec<Cell<'static>>, height: Option<u16>) -> Row<'static> {
+    let row = Row::new(cells);
+    match height {
+        Some(h) => row.height(h),
+        None => row,
+    }
+}
+
+// Rows to render, their column count and the matching state. `selected`/`offset` are absolute
+// indices; with a virtual source only the visible window is fetched and the state is rebased.
+pub(crate) fn table_rows_window(
+    tb: &FfiTable,
+    area: Rect,
+    selected: Option<usize>,
+    offset: usize,
+) -> (Vec<Row<'static>>, usize, TableState) {
+    let mut state = TableState::default();
+    let Some(src) = &tb.source else {
+        let rows: Vec<Row> = if let Some(rows_cells) = &tb.rows_cells_lines {
+            rows_cells
+                .iter()
+                .map(|cells| {
+                    let rc = cells.iter().map(TableCell::to_cell).collect();
+                    row_with_height(rc, tb.row_height)
+                })
+                .collect()
+        } else if let Some(rss) = &tb.rows_spans {
+            rss.iter()
+                .map(|r| {
+                    row_with_height(r.iter().cloned().map(Cell::from).collect(), tb.row_height)
+                })
+                .collect()
+        } else {
+            tb.rows
+                .iter()
+                .map(|r| {
+                    row_with_height(r.iter().cloned().map(Cell::from).collect(), tb.row_height)
+                })
+                .collect()
+        };
+        let longest = if let Some(rc) = &tb.rows_cells_lines {
+            rc.iter().map(|r| r.len()).max()
+        } else if let Some(rs) = &tb.rows_spans {
+            rs.iter().map(|r| r.len()).max()
+        } else {
+            tb.rows.iter().map(|r| r.len()).max()
+        };
+        let columns = longest.unwrap_or(0).max(tb.headers.len());
+        state.select(selected);
+        return (rows, columns, state.with_offset(offset));
+    };
+    let inner = tb
+        .block_extras
+        .apply(&tb.block)
+        .map_or(area, |b| b.inner(area));
+    let header_h = u16::from(tb.headers_spans.is_some() || !tb.headers.is_empty());
+    let row_h = tb.row_height.unwrap_or(1).max(1);
+    let visible = (inner.height.saturating_sub(header_h) / row_h) as usize;
+    let (start, len) = visible_window(src.count, visible, selected, offset);
+    let fetched = src.fetch_rows(start, len);
+    let columns = fetched
+        .iter()
+        .map(|r| r.len())
+        .max()
+        .unwrap_or(0)
+        .max(tb.headers.len());
+    let rows = fetched
+        .into_iter()
+        .map(|cells| {
+            let rc = cells
+                .into_iter()
+                .map(|lines| Cell::from(Text::from(lines)))
+                .collect();
+            row_with_height(rc, tb.row_height)
+        })
+        .collect();
+    state.select(selected.map(|sel| sel.min(src.count.saturating_sub(1)) - start));
+    (rows, columns, state)
 }
 
 #[no_mangle]
@@ -55,7 +176,7 @@ pub extern "C" fn ratatui_headless_render_table(
     let header_row = if let Some(hs) = &tb.headers_spans {
         let mut r = Row::new(hs.iter().cloned().map(Cell::from).collect::<Vec<_>>());
         if let Some(hsty) = &tb.header_style {
-            r = r.style(hsty.clone());
+            r = r.style(hsty.resolve());
         }
         Some(r)
     } else if tb.headers.is_empty() {
@@ -69,50 +190,11 @@ pub extern "C" fn ratatui_headless_render_table(
                 .collect::<Vec<_>>(),
         ))
     };
-    let rows: Vec<Row> = if let Some(rows_cells) = &tb.rows_cells_lines {
-        rows_cells
-            .iter()
-            .map(|cells| {
-                let mut row_cells: Vec<Cell> = Vec::with_capacity(cells.len());
-                for cell_lines in cells.iter() {
-                    let text = ratatui::text::Text::from(cell_lines.clone());
-                    row_cells.push(Cell::from(text));
-                }
-                let mut row = Row::new(row_cells);
-                if let Some(h) = tb.row_height {
-                    row = row.height(h);
-                }
-                row
-            })
-            .collect()
-    } else if let Some(rss) = &tb.rows_spans {
-        rss.iter()
-            .map(|r| {
-                let mut row = Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>());
-                if let Some(h) = tb.row_height {
-                    row = row.height(h);
-                }
-                row
-            })
-            .collect()
-    } else {
-        tb.rows
-            .iter()
-            .map(|r| {
-                let mut row = Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>());
-                if let Some(h) = tb.row_height {
-                    row = row.height(h);
-                }
-                row
-            })
-            .collect()
-    };
+    let (rows, columns, mut state) = table_rows_window(tb, area, tb.selected, 0);
     let col_count = if let Some(w) = &tb.widths_pct {
         w.len().max(1)
-    } else if !tb.rows.is_empty() {
-        tb.rows.iter().map(|r| r.len()).max().unwrap_or(1)
     } else {
-        tb.headers.len().max(1)
+        columns.max(1)
     };
     let widths: Vec<Constraint> = if let Some(ws) = &tb.widths_pct {
         ws.iter().map(|p| Constraint::Percentage(*p)).collect()
@@ -128,31 +210,25 @@ pub extern "C" fn ratatui_headless_render_table(
     if let Some(hr) = header_row {
         widget = widget.header(hr);
     }
-    if let Some(b) = &tb.block {
-        widget = widget.block(b.clone());
+    if let Some(b) = tb.block_extras.apply(&tb.block) {
+        widget = widget.block(b);
     }
     if let Some(sty) = &tb.row_highlight_style {
-        widget = widget.row_highlight_style(sty.clone());
+        widget = widget.row_highlight_style(sty.resolve());
     }
     if let Some(sym) = &tb.highlight_symbol {
         widget = widget.highlight_symbol(sym.clone());
     }
     if let Some(sty) = &tb.column_highlight_style {
-        widget = widget.column_highlight_style(sty.clone());
+        widget = widget.column_highlight_style(sty.resolve());
     }
     if let Some(sty) = &tb.cell_highlight_style {
-        widget = widget.cell_highlight_style(sty.clone());
+        widget = widget.cell_highlight_style(sty.resolve());
     }
     if let Some(sp) = &tb.highlight_spacing {
         widget = widget.highlight_spacing(sp.clone());
     }
-    if let Some(sel) = tb.selected {
-        let mut state = ratatui::widgets::TableState::default();
-        state.select(Some(sel));
-        ratatui::widgets::StatefulWidget::render(widget, area, &mut buf, &mut state);
-    } else {
-        ratatui::widgets::Widget::render(widget, area, &mut buf);
-    }
+    ratatui::widgets::StatefulWidget::render(widget, area, &mut buf, &mut state);
     let mut s = String::new();
     for y in 0..height {
         for x in 0..width {
@@ -179,6 +255,7 @@ pub extern "C" fn ratatui_table_new() -> *mut FfiTable {
         headers: Vec::new(),
         rows: Vec::new(),
         block: None,
+        block_extras: BlockExtras::default(),
         selected: None,
         row_highlight_style: None,
         highlight_symbol: None,
@@ -193,6 +270,7 @@ pub extern "C" fn ratatui_table_new() -> *mut FfiTable {
         column_highlight_style: None,
         cell_highlight_style: None,
         highlight_spacing: None,
+        source: None,
     }))
 }
 
@@ -265,7 +343,7 @@ pub extern "C" fn ratatui_terminal_draw_table_state_in(
         let header_row = if let Some(hs) = &tb.headers_spans {
             let mut r = Row::new(hs.iter().cloned().map(Cell::from).collect::<Vec<_>>());
             if let Some(hsty) = &tb.header_style {
-                r = r.style(hsty.clone());
+                r = r.style(hsty.resolve());
             }
             Some(r)
         } else if tb.headers.is_empty() {
@@ -279,50 +357,11 @@ pub extern "C" fn ratatui_terminal_draw_table_state_in(
                     .collect::<Vec<_>>(),
             ))
         };
-        let rows: Vec<Row> = if let Some(rows_cells) = &tb.rows_cells_lines {
-            rows_cells
-                .iter()
-                .map(|cells| {
-                    let mut rc: Vec<Cell> = Vec::with_capacity(cells.len());
-                    for cell_lines in cells.iter() {
-                        let text = ratatui::text::Text::from(cell_lines.clone());
-                        rc.push(Cell::from(text));
-                    }
-                    let mut row = Row::new(rc);
-                    if let Some(h) = tb.row_height {
-                        row = row.height(h);
-                    }
-                    row
-                })
-                .collect()
-        } else if let Some(rss) = &tb.rows_spans {
-            rss.iter()
-                .map(|r| {
-                    let mut row = Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>());
-                    if let Some(h) = tb.row_height {
-                        row = row.height(h);
-                    }
-                    row
-                })
-                .collect()
-        } else {
-            tb.rows
-                .iter()
-                .map(|r| {
-                    let mut row = Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>());
-                    if let Some(h) = tb.row_height {
-                        row = row.height(h);
-                    }
-                    row
-                })
-                .collect()
-        };
+        let (rows, columns, mut state) = table_rows_window(tb, area, ss.selected, ss.offset);
         let col_count = if let Some(w) = &tb.widths_pct {
             w.len().max(1)
-        } else if !tb.rows.is_empty() {
-            tb.rows.iter().map(|r| r.len()).max().unwrap_or(1)
         } else {
-            tb.headers.len().max(1)
+            columns.max(1)
         };
         let widths: Vec<Constraint> = if let Some(ws) = &tb.widths_pct {
             ws.iter().map(|p| Constraint::Percentage(*p)).collect()
@@ -338,29 +377,24 @@ pub extern "C" fn ratatui_terminal_draw_table_state_in(
         if let Some(hr) = header_row {
             widget = widget.header(hr);
         }
-        if let Some(b) = &tb.block {
-            widget = widget.block(b.clone());
+        if let Some(b) = tb.block_extras.apply(&tb.block) {
+            widget = widget.block(b);
         }
         if let Some(sty) = &tb.row_highlight_style {
-            widget = widget.row_highlight_style(sty.clone());
+            widget = widget.row_highlight_style(sty.resolve());
         }
         if let Some(sym) = &tb.highlight_symbol {
             widget = widget.highlight_symbol(sym.clone());
         }
         if let Some(sty) = &tb.column_highlight_style {
-            widget = widget.column_highlight_style(sty.clone());
+            widget = widget.column_highlight_style(sty.resolve());
         }
         if let Some(sty) = &tb.cell_highlight_style {
-            widget = widget.cell_highlight_style(sty.clone());
+            widget = widget.cell_highlight_style(sty.resolve());
         }
         if let Some(sp) = &tb.highlight_spacing {
             widget = widget.highlight_spacing(sp.clone());
         }
-        let mut state = ratatui::widgets::TableState::default();
-        if let Some(sel) = ss.selected {
-            state.select(Some(sel));
-        }
-        state = state.with_offset(ss.offset);
         let res = t.terminal.draw(|frame| {
             frame.render_stateful_widget(widget.clone(), area, &mut state);
         });
@@ -403,6 +437,25 @@ pub extern "C" fn ratatui_table_set_headers_spans(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_table_set_headers_spans_ex(
+    tbl: *mut FfiTable,
+    spans: *const FfiSpanEx,
+    len: usize,
+) {
+    if tbl.is_null() {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    if spans.is_null() || len == 0 {
+        t.headers_spans = None;
+        return;
+    }
+    if let Some(sp) = spans_from_ffi_ex(spans, len) {
+        t.headers_spans = Some(vec![Line::from(sp)]);
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_table_append_row(tbl: *mut FfiTable, tsv_utf8: *const c_char) {
     if tbl.is_null() || tsv_utf8.is_null() {
@@ -432,6 +485,23 @@ pub extern "C" fn ratatui_table_append_row_spans(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_table_append_row_spans_ex(
+    tbl: *mut FfiTable,
+    spans: *const FfiSpanEx,
+    len: usize,
+) {
+    if tbl.is_null() || spans.is_null() || len == 0 {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    if let Some(sp) = spans_from_ffi_ex(spans, len) {
+        t.rows_spans
+            .get_or_insert(Vec::new())
+            .push(vec![Line::from(sp)]);
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_table_append_row_cells_lines(
     tbl: *mut FfiTable,
@@ -443,7 +513,7 @@ pub extern "C" fn ratatui_table_append_row_cells_lines(
     }
     let t = unsafe { &mut *tbl };
     let slice = unsafe { std::slice::from_raw_parts(cells, len) };
-    let mut row: Vec<Vec<Line<'static>>> = Vec::with_capacity(len);
+    let mut row: Vec<TableCell> = Vec::with_capacity(len);
     for ls in slice.iter() {
         let mut lines = Vec::new();
         if ls.spans.is_null() || ls.len == 0 {
@@ -451,7 +521,7 @@ pub extern "C" fn ratatui_table_append_row_cells_lines(
         } else if let Some(sp) = spans_from_ffi(ls.spans, ls.len) {
             lines.push(Line::from(sp));
         }
-        row.push(lines);
+        row.push(lines.into());
     }
     if t.rows_cells_lines.is_none() {
         t.rows_cells_lines = Some(Vec::new());
@@ -459,16 +529,138 @@ pub extern "C" fn ratatui_table_append_row_cells_lines(
     t.rows_cells_lines.as_mut().unwrap().push(row);
 }
 
+// One line per cell, each with its own style and alignment.
+#[no_mangle]
+pub extern "C" fn ratatui_table_append_row_cells_lines_ex(
+    tbl: *mut FfiTable,
+    cells: *const FfiLineSpansEx,
+    len: usize,
+) {
+    if tbl.is_null() || cells.is_null() || len == 0 {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    let slice = unsafe { std::slice::from_raw_parts(cells, len) };
+    let row = slice.iter().map(|ls| vec![line_from_ffi_ex(ls)]).collect();
+    let end = table_row_count_materialized(t);
+    t.insert_row(end, RowData::Cells(row));
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_append_row_cells_ex(
+    tbl: *mut FfiTable,
+    cells: *const FfiCellLinesEx,
+    len: usize,
+) {
+    if tbl.is_null() || cells.is_null() || len == 0 {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    let slice = unsafe { std::slice::from_raw_parts(cells, len) };
+    let row = slice
+        .iter()
+        .map(|cell| lines_from_ffi_ex(cell.lines, cell.len))
+        .collect();
+    let end = table_row_count_materialized(t);
+    t.insert_row(end, RowData::Cells(row));
+}
+
+// Tab-separated cells, each parsed as inline markup ('\n' inside a cell gives a multi-line cell).
+#[no_mangle]
+pub extern "C" fn ratatui_table_append_row_markup(
+    tbl: *mut FfiTable,
+    tsv_markup_utf8: *const c_char,
+) {
+    if tbl.is_null() {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    if let Some(m) = markup_str_from_c(tsv_markup_utf8) {
+        let row = m.split('\t').map(parse_markup).collect();
+        let end = table_row_count_materialized(t);
+        t.insert_row(end, RowData::Cells(row));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_set_headers_markup(
+    tbl: *mut FfiTable,
+    tsv_markup_utf8: *const c_char,
+) {
+    if tbl.is_null() {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    match markup_str_from_c(tsv_markup_utf8) {
+        Some(m) => t.headers_spans = Some(m.split('\t').map(parse_markup_line).collect()),
+        None => t.headers_spans = None,
+    }
+}
+
 crate::ratatui_block_title_fn!(ratatui_table_set_block_title, FfiTable);
 crate::ratatui_block_title_spans_fn!(ratatui_table_set_block_title_spans, FfiTable);
+crate::ratatui_block_title_markup_fn!(ratatui_table_set_block_title_markup, FfiTable);
+crate::ratatui_block_title_text_fn!(ratatui_table_set_block_title_text, FfiTable);
 crate::ratatui_block_adv_fn!(ratatui_table_set_block_adv, FfiTable);
 crate::ratatui_block_title_alignment_fn!(ratatui_table_set_block_title_alignment, FfiTable);
+crate::ratatui_block_add_title_fn!(
+    ratatui_table_add_block_title_spans,
+    ratatui_table_add_block_title_markup,
+    FfiTable
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_table_set_block_title_spans_ex,
+    ratatui_table_add_block_title_spans_ex,
+    FfiTable
+);
+crate::ratatui_block_style_fn!(ratatui_table_set_block_style, FfiTable, style);
+crate::ratatui_block_style_fn!(ratatui_table_set_block_border_style, FfiTable, border_style);
+crate::ratatui_block_style_fn!(ratatui_table_set_block_title_style, FfiTable, title_style);
+crate::ratatui_block_style_ex_fn!(ratatui_table_set_block_style_ex, FfiTable, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_table_set_block_border_style_ex,
+    FfiTable,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_table_set_block_title_style_ex,
+    FfiTable,
+    title_style
+);
+crate::ratatui_block_style_slot_fn!(ratatui_table_set_block_style_slot, FfiTable, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_table_set_block_border_style_slot,
+    FfiTable,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_table_set_block_title_style_slot,
+    FfiTable,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_table_set_block_border_set, FfiTable);
+crate::ratatui_block_set_fn!(ratatui_table_set_block, FfiTable);
 crate::ratatui_set_selected_i32_fn!(ratatui_table_set_selected, FfiTable, selected);
 crate::ratatui_set_style_fn!(
     ratatui_table_set_row_highlight_style,
     FfiTable,
     row_highlight_style
 );
+crate::ratatui_set_style_ex_fn!(
+    ratatui_table_set_row_highlight_style_ex,
+    FfiTable,
+    row_highlight_style
+);
+crate::ratatui_set_style_ex_fn!(
+    ratatui_table_set_column_highlight_style_ex,
+    FfiTable,
+    column_highlight_style
+);
+crate::ratatui_set_style_ex_fn!(
+    ratatui_table_set_cell_highlight_style_ex,
+    FfiTable,
+    cell_highlight_style
+);
 
 #[no_mangle]
 pub extern "C" fn ratatui_table_set_highlight_symbol(tbl: *mut FfiTable, sym_utf8: *const c_char) {
@@ -492,7 +684,7 @@ pub extern "C" fn ratatui_table_set_column_highlight_style(tbl: *mut FfiTable, s
         return;
     }
     let t = unsafe { &mut *tbl };
-    t.column_highlight_style = Some(style_from_ffi(style));
+    t.column_highlight_style = Some(style_from_ffi(style).into());
 }
 
 #[no_mangle]
@@ -501,7 +693,7 @@ pub extern "C" fn ratatui_table_set_cell_highlight_style(tbl: *mut FfiTable, sty
         return;
     }
     let t = unsafe { &mut *tbl };
-    t.cell_highlight_style = Some(style_from_ffi(style));
+    t.cell_highlight_style = Some(style_from_ffi(style).into());
 }
 
 #[no_mangle]
@@ -518,6 +710,58 @@ pub extern "C" fn ratatui_table_set_highlight_spacing(tbl: *mut FfiTable, spacin
 }
 
 crate::ratatui_set_style_fn!(ratatui_table_set_header_style, FfiTable, header_style);
+crate::ratatui_set_style_ex_fn!(ratatui_table_set_header_style_ex, FfiTable, header_style);
+crate::ratatui_set_style_slot_fn!(ratatui_table_set_header_style_slot, FfiTable, header_style);
+crate::ratatui_set_style_slot_fn!(
+    ratatui_table_set_row_highlight_style_slot,
+    FfiTable,
+    row_highlight_style
+);
+crate::ratatui_set_style_slot_fn!(
+    ratatui_table_set_column_highlight_style_slot,
+    FfiTable,
+    column_highlight_style
+);
+crate::ratatui_set_style_slot_fn!(
+    ratatui_table_set_cell_highlight_style_slot,
+    FfiTable,
+    cell_highlight_style
+);
+
+// Virtual data source: `fetch` is called at render time for the visible rows only.
+// Passing a null `fetch` detaches the source and renders the appended rows again.
+#[no_mangle]
+pub extern "C" fn ratatui_table_set_source(
+    tbl: *mut FfiTable,
+    count: usize,
+    fetch: FfiFetchRowsFn,
+    user_data: *mut c_void,
+) {
+    if tbl.is_null() {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    t.source = VirtualSource::new(count, fetch, user_data);
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_set_source_count(tbl: *mut FfiTable, count: usize) {
+    if tbl.is_null() {
+        return;
+    }
+    let t = unsafe { &mut *tbl };
+    if let Some(src) = &mut t.source {
+        src.count = count;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_row_count(tbl: *const FfiTable) -> usize {
+    if tbl.is_null() {
+        return 0;
+    }
+    table_row_count(unsafe { &*tbl })
+}
 
 #[no_mangle]
 pub extern "C" fn ratatui_table_set_row_height(tbl: *mut FfiTable, height: u16) {
@@ -631,34 +875,11 @@ pub extern "C" fn ratatui_terminal_draw_table_in(
                     .collect::<Vec<_>>(),
             ))
         };
-        let rows: Vec<Row> = if let Some(rows_cells) = &tb.rows_cells_lines {
-            rows_cells
-                .iter()
-                .map(|cells| {
-                    let mut rc: Vec<Cell> = Vec::with_capacity(cells.len());
-                    for cell_lines in cells.iter() {
-                        let text = ratatui::text::Text::from(cell_lines.clone());
-                        rc.push(Cell::from(text));
-                    }
-                    Row::new(rc)
-                })
-                .collect()
-        } else if let Some(rss) = &tb.rows_spans {
-            rss.iter()
-                .map(|r| Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>()))
-                .collect()
-        } else {
-            tb.rows
-                .iter()
-                .map(|r| Row::new(r.iter().cloned().map(Cell::from).collect::<Vec<_>>()))
-                .collect()
-        };
+        let (rows, columns, mut state) = table_rows_window(tb, area, tb.selected, 0);
         let col_count = if let Some(w) = &tb.widths_pct {
             w.len().max(1)
-        } else if !tb.rows.is_empty() {
-            tb.rows.iter().map(|r| r.len()).max().unwrap_or(1)
         } else {
-            tb.headers.len().max(1)
+            columns.max(1)
         };
         let widths: Vec<Constraint> = if let Some(ws) = &tb.widths_pct {
             ws.iter().map(|p| Constraint::Percentage(*p)).collect()
@@ -674,32 +895,26 @@ pub extern "C" fn ratatui_terminal_draw_table_in(
         if let Some(hr) = header_row {
             widget = widget.header(hr);
         }
-        if let Some(b) = &tb.block {
-            widget = widget.block(b.clone());
+        if let Some(b) = tb.block_extras.apply(&tb.block) {
+            widget = widget.block(b);
         }
         if let Some(sty) = &tb.row_highlight_style {
-            widget = widget.row_highlight_style(sty.clone());
+            widget = widget.row_highlight_style(sty.resolve());
         }
         if let Some(sym) = &tb.highlight_symbol {
             widget = widget.highlight_symbol(sym.clone());
         }
         if let Some(sty) = &tb.column_highlight_style {
-            widget = widget.column_highlight_style(sty.clone());
+            widget = widget.column_highlight_style(sty.resolve());
         }
         if let Some(sty) = &tb.cell_highlight_style {
-            widget = widget.cell_highlight_style(sty.clone());
+            widget = widget.cell_highlight_style(sty.resolve());
         }
         if let Some(sp) = &tb.highlight_spacing {
             widget = widget.highlight_spacing(sp.clone());
         }
         let res = t.terminal.draw(|frame| {
-            if let Some(sel) = tb.selected {
-                let mut state = ratatui::widgets::TableState::default();
-                state.select(Some(sel));
-                frame.render_stateful_widget(widget.clone(), area, &mut state);
-            } else {
-                frame.render_widget(widget.clone(), area);
-            }
+            frame.render_stateful_widget(widget.clone(), area, &mut state);
         });
         res.is_ok()
     })
@@ -744,10 +959,10 @@ pub extern "C" fn ratatui_table_append_rows_cells_lines(
             continue;
         }
         let cells_slice = unsafe { std::slice::from_raw_parts(r.cells, r.len) };
-        let mut row: Vec<Vec<Line<'static>>> = Vec::with_capacity(cells_slice.len());
+        let mut row: Vec<TableCell> = Vec::with_capacity(cells_slice.len());
         for cell in cells_slice.iter() {
             if cell.lines.is_null() || cell.len == 0 {
-                row.push(Vec::new());
+                row.push(Vec::new().into());
                 continue;
             }
             let line_specs = unsafe { std::slice::from_raw_parts(cell.lines, cell.len) };
@@ -763,7 +978,7 @@ pub extern "C" fn ratatui_table_append_rows_cells_lines(
                     lines.push(Line::default());
                 }
             }
-            row.push(lines);
+            row.push(lines.into());
         }
         if t.rows_cells_lines.is_none() {
             t.rows_cells_lines = Some(Vec::new());
@@ -771,3 +986,754 @@ pub extern "C" fn ratatui_table_append_rows_cells_lines(
         t.rows_cells_lines.as_mut().unwrap().push(row);
     }
 }
+
+// ----- Incremental mutation -----
+//
+// Rows live in one of three storages (rows_cells_lines > rows_spans > rows, same precedence
+// as rendering). Mutations act on the active storage; when the new data is richer than that
+// storage (e.g. spans into plain TSV rows), the existing rows are promoted first.
+
+enum RowData {
+    Plain(Vec<String>),
+    Spans(Vec<Line<'static>>),
+    Cells(Vec<Vec<Line<'static>>>),
+}
+
+enum CellData {
+    Plain(String),
+    Spans(Line<'static>),
+    Lines(Vec<Line<'static>>),
+    Text(SharedText),
+}
+
+fn text_lines(s: String) -> Vec<Line<'static>> {
+    s.split('\n').map(|l| Line::from(l.to_string())).collect()
+}
+
+fn lines_to_plain(lines: &[Line<'static>]) -> String {
+    lines
+        .iter()
+        .map(|l| l.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl RowData {
+    fn rank(&self) -> u8 {
+        match self {
+            RowData::Plain(_) => 0,
+            RowData::Spans(_) => 1,
+            RowData::Cells(_) => 2,
+        }
+    }
+
+    fn into_spans(self) -> Vec<Line<'static>> {
+        match self {
+            RowData::Plain(v) => v.into_iter().map(Line::from).collect(),
+            RowData::Spans(v) => v,
+            RowData::Cells(v) => v
+                .into_iter()
+                .map(|c| c.into_iter().next().unwrap_or_default())
+                .collect(),
+        }
+    }
+
+    fn into_cells(self) -> Vec<TableCell> {
+        match self {
+            RowData::Plain(v) => v.into_iter().map(|s| text_lines(s).into()).collect(),
+            RowData::Spans(v) => v.into_iter().map(|l| vec![l].into()).collect(),
+            RowData::Cells(v) => v.into_iter().map(TableCell::from).collect(),
+        }
+    }
+}
+
+impl CellData {
+    fn rank(&self) -> u8 {
+        match self {
+            CellData::Plain(_) => 0,
+            CellData::Spans(_) => 1,
+            CellData::Lines(_) | CellData::Text(_) => 2,
+        }
+    }
+
+    fn into_plain(self) -> String {
+        match self {
+            CellData::Plain(s) => s,
+            CellData::Spans(l) => l.to_string(),
+            CellData::Lines(ls) => lines_to_plain(&ls),
+            CellData::Text(t) => lines_to_plain(&read_text(&t).lines),
+        }
+    }
+
+    fn into_line(self) -> Line<'static> {
+        match self {
+            CellData::Plain(s) => Line::from(s),
+            CellData::Spans(l) => l,
+            CellData::Lines(ls) => ls.into_iter().next().unwrap_or_default(),
+            CellData::Text(t) => read_text(&t).lines.first().cloned().unwrap_or_default(),
+        }
+    }
+
+    fn into_cell(self) -> TableCell {
+        match self {
+            CellData::Plain(s) => text_lines(s).into(),
+            CellData::Spans(l) => vec![l].into(),
+            CellData::Lines(ls) => ls.into(),
+            CellData::Text(t) => TableCell::Text(t),
+        }
+    }
+}
+
+impl FfiTable {
+    fn storage_rank(&self) -> u8 {
+        if self.rows_cells_lines.is_some() {
+            2
+        } else if self.rows_spans.is_some() {
+            1
+        } else {
+            0
+        }
+    }
+
+    // Convert the active storage into `rank` (no-op if it is already at least as rich).
+    fn promote_rows(&mut self, rank: u8) {
+        let cur = self.storage_rank();
+        if rank <= cur {
+            return;
+        }
+        if rank == 1 {
+            let rows = std::mem::take(&mut self.rows);
+            self.rows_spans = Some(
+                rows.into_iter()
+                    .map(|r| RowData::Plain(r).into_spans())
+                    .collect(),
+            );
+            return;
+        }
+        let rows: Vec<RowData> = if cur == 1 {
+            self.rows_spans
+                .take()
+                .unwrap_or_default()
+                .into_iter()
+                .map(RowData::Spans)
+                .collect()
+        } else {
+            std::mem::take(&mut self.rows)
+                .into_iter()
+                .map(RowData::Plain)
+                .collect()
+        };
+        self.rows_cells_lines = Some(rows.into_iter().map(RowData::into_cells).collect());
+    }
+
+    fn insert_row(&mut self, index: usize, row: RowData) -> bool {
+        if index > table_row_count_materialized(self) {
+            return false;
+        }
+        self.promote_rows(row.rank());
+        match self.storage_rank() {
+            0 => match row {
+                RowData::Plain(v) => self.rows.insert(index, v),
+                _ => unreachable!(),
+            },
+            1 => self
+                .rows_spans
+                .get_or_insert_with(Vec::new)
+                .insert(index, row.into_spans()),
+            _ => self
+                .rows_cells_lines
+                .get_or_insert_with(Vec::new)
+                .insert(index, row.into_cells()),
+        }
+        true
+    }
+
+    fn replace_row(&mut self, index: usize, row: RowData) -> bool {
+        if index >= table_row_count_materialized(self) {
+            return false;
+        }
+        self.insert_row(index, row) && self.remove_row(index + 1)
+    }
+
+    fn remove_row(&mut self, index: usize) -> bool {
+        if index >= table_row_count_materialized(self) {
+            return false;
+        }
+        if let Some(rc) = &mut self.rows_cells_lines {
+            rc.remove(index);
+        } else if let Some(rs) = &mut self.rows_spans {
+            rs.remove(index);
+        } else {
+            self.rows.remove(index);
+        }
+        true
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) -> bool {
+        let n = table_row_count_materialized(self);
+        if a >= n || b >= n {
+            return false;
+        }
+        if let Some(rc) = &mut self.rows_cells_lines {
+            rc.swap(a, b);
+        } else if let Some(rs) = &mut self.rows_spans {
+            rs.swap(a, b);
+        } else {
+            self.rows.swap(a, b);
+        }
+        true
+    }
+
+    fn truncate_rows(&mut self, len: usize) {
+        if let Some(rc) = &mut self.rows_cells_lines {
+            rc.truncate(len);
+        } else if let Some(rs) = &mut self.rows_spans {
+            rs.truncate(len);
+        } else {
+            self.rows.truncate(len);
+        }
+    }
+
+    fn row_cell_count(&self, row: usize) -> Option<usize> {
+        if let Some(rc) = &self.rows_cells_lines {
+            rc.get(row).map(|r| r.len())
+        } else if let Some(rs) = &self.rows_spans {
+            rs.get(row).map(|r| r.len())
+        } else {
+            self.rows.get(row).map(|r| r.len())
+        }
+    }
+
+    // `replace` overwrites the cell at `col`, otherwise the cell is inserted before `col`.
+    fn put_cell(&mut self, row: usize, col: usize, cell: CellData, replace: bool) -> bool {
+        let Some(n) = self.row_cell_count(row) else {
+            return false;
+        };
+        if (replace && col >= n) || col > n {
+            return false;
+        }
+        self.promote_rows(cell.rank());
+        fn put<T>(cells: &mut Vec<T>, col: usize, v: T, replace: bool) {
+            if replace {
+                cells[col] = v;
+            } else {
+                cells.insert(col, v);
+            }
+        }
+        if let Some(rc) = &mut self.rows_cells_lines {
+            put(&mut rc[row], col, cell.into_cell(), replace);
+        } else if let Some(rs) = &mut self.rows_spans {
+            put(&mut rs[row], col, cell.into_line(), replace);
+        } else {
+            put(&mut self.rows[row], col, cell.into_plain(), replace);
+        }
+        true
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> bool {
+        match self.row_cell_count(row) {
+            Some(n) if col < n => {}
+            _ => return false,
+        }
+        if let Some(rc) = &mut self.rows_cells_lines {
+            rc[row].remove(col);
+        } else if let Some(rs) = &mut self.rows_spans {
+            rs[row].remove(col);
+        } else {
+            self.rows[row].remove(col);
+        }
+        true
+    }
+
+    fn swap_cells(&mut self, row: usize, a: usize, b: usize) -> bool {
+        match self.row_cell_count(row) {
+            Some(n) if a < n && b < n => {}
+            _ => return false,
+        }
+        if let Some(rc) = &mut self.rows_cells_lines {
+            rc[row].swap(a, b);
+        } else if let Some(rs) = &mut self.rows_spans {
+            rs[row].swap(a, b);
+        } else {
+            self.rows[row].swap(a, b);
+        }
+        true
+    }
+
+    fn truncate_cells(&mut self, row: usize, len: usize) -> bool {
+        if self.row_cell_count(row).is_none() {
+            return false;
+        }
+        if let Some(rc) = &mut self.rows_cells_lines {
+            rc[row].truncate(len);
+        } else if let Some(rs) = &mut self.rows_spans {
+            rs[row].truncate(len);
+        } else {
+            self.rows[row].truncate(len);
+        }
+        true
+    }
+}
+
+// Row count of the appended (non-virtual) storage.
+fn table_row_count_materialized(tb: &FfiTable) -> usize {
+    if let Some(rc) = &tb.rows_cells_lines {
+        rc.len()
+    } else if let Some(rs) = &tb.rows_spans {
+        rs.len()
+    } else {
+        tb.rows.len()
+    }
+}
+
+fn row_from_tsv(tsv_utf8: *const c_char) -> Option<RowData> {
+    if tsv_utf8.is_null() {
+        return None;
+    }
+    let s = unsafe { CStr::from_ptr(tsv_utf8) }.to_str().ok()?;
+    Some(RowData::Plain(
+        s.split('\t').map(|x| x.to_string()).collect(),
+    ))
+}
+
+fn row_from_spans(spans: *const FfiSpan, len: usize) -> Option<RowData> {
+    spans_from_ffi(spans, len).map(|sp| RowData::Spans(vec![Line::from(sp)]))
+}
+
+fn row_from_cells_lines(cells: *const FfiLineSpans, len: usize) -> Option<RowData> {
+    if cells.is_null() {
+        return None;
+    }
+    let slice = unsafe { std::slice::from_raw_parts(cells, len) };
+    Some(RowData::Cells(
+        slice.iter().map(|ls| vec![line_from_ffi(ls)]).collect(),
+    ))
+}
+
+fn row_from_cells(cells: *const FfiCellLines, len: usize) -> Option<RowData> {
+    if cells.is_null() {
+        return None;
+    }
+    let slice = unsafe { std::slice::from_raw_parts(cells, len) };
+    Some(RowData::Cells(
+        slice
+            .iter()
+            .map(|cell| cell_lines_from_ffi(cell.lines, cell.len))
+            .collect(),
+    ))
+}
+
+fn cell_lines_from_ffi(lines: *const FfiLineSpans, len: usize) -> Vec<Line<'static>> {
+    if lines.is_null() || len == 0 {
+        return Vec::new();
+    }
+    unsafe { std::slice::from_raw_parts(lines, len) }
+        .iter()
+        .map(line_from_ffi)
+        .collect()
+}
+
+fn cell_from_text(text_utf8: *const c_char) -> Option<CellData> {
+    if text_utf8.is_null() {
+        return None;
+    }
+    let s = unsafe { CStr::from_ptr(text_utf8) }.to_str().ok()?;
+    Some(CellData::Plain(s.to_string()))
+}
+
+fn cell_from_spans(spans: *const FfiSpan, len: usize) -> Option<CellData> {
+    spans_from_ffi(spans, len).map(|sp| CellData::Spans(Line::from(sp)))
+}
+
+fn cell_from_lines(lines: *const FfiLineSpans, len: usize) -> Option<CellData> {
+    if lines.is_null() {
+        return None;
+    }
+    Some(CellData::Lines(cell_lines_from_ffi(lines, len)))
+}
+
+fn with_table(tbl: *mut FfiTable, f: impl FnOnce(&mut FfiTable) -> bool) -> bool {
+    if tbl.is_null() {
+        return false;
+    }
+    f(unsafe { &mut *tbl })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_row(
+    tbl: *mut FfiTable,
+    index: usize,
+    tsv_utf8: *const c_char,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_tsv(tsv_utf8).is_some_and(|r| t.insert_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_row_spans(
+    tbl: *mut FfiTable,
+    index: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_spans(spans, len).is_some_and(|r| t.insert_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_row_cells_lines(
+    tbl: *mut FfiTable,
+    index: usize,
+    cells: *const FfiLineSpans,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_cells_lines(cells, len).is_some_and(|r| t.insert_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_row_cells(
+    tbl: *mut FfiTable,
+    index: usize,
+    cells: *const FfiCellLines,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_cells(cells, len).is_some_and(|r| t.insert_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_row(
+    tbl: *mut FfiTable,
+    index: usize,
+    tsv_utf8: *const c_char,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_tsv(tsv_utf8).is_some_and(|r| t.replace_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_row_spans(
+    tbl: *mut FfiTable,
+    index: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_spans(spans, len).is_some_and(|r| t.replace_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_row_cells_lines(
+    tbl: *mut FfiTable,
+    index: usize,
+    cells: *const FfiLineSpans,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_cells_lines(cells, len).is_some_and(|r| t.replace_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_row_cells(
+    tbl: *mut FfiTable,
+    index: usize,
+    cells: *const FfiCellLines,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        row_from_cells(cells, len).is_some_and(|r| t.replace_row(index, r))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_remove_row(tbl: *mut FfiTable, index: usize) -> bool {
+    with_table(tbl, |t| t.remove_row(index))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_swap_rows(tbl: *mut FfiTable, a: usize, b: usize) -> bool {
+    with_table(tbl, |t| t.swap_rows(a, b))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_truncate_rows(tbl: *mut FfiTable, len: usize) {
+    with_table(tbl, |t| {
+        t.truncate_rows(len);
+        true
+    });
+}
+
+// Drops rows from every storage (header, widths and styles are kept).
+#[no_mangle]
+pub extern "C" fn ratatui_table_clear_rows(tbl: *mut FfiTable) {
+    with_table(tbl, |t| {
+        t.rows.clear();
+        t.rows_spans = None;
+        t.rows_cells_lines = None;
+        true
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_cell(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    text_utf8: *const c_char,
+) -> bool {
+    with_table(tbl, |t| {
+        cell_from_text(text_utf8).is_some_and(|c| t.put_cell(row, col, c, false))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_cell_spans(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        cell_from_spans(spans, len).is_some_and(|c| t.put_cell(row, col, c, false))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_cell_lines(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    lines: *const FfiLineSpans,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        cell_from_lines(lines, len).is_some_and(|c| t.put_cell(row, col, c, false))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_cell(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    text_utf8: *const c_char,
+) -> bool {
+    with_table(tbl, |t| {
+        cell_from_text(text_utf8).is_some_and(|c| t.put_cell(row, col, c, true))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_cell_spans(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    spans: *const FfiSpan,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        cell_from_spans(spans, len).is_some_and(|c| t.put_cell(row, col, c, true))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_cell_lines(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    lines: *const FfiLineSpans,
+    len: usize,
+) -> bool {
+    with_table(tbl, |t| {
+        cell_from_lines(lines, len).is_some_and(|c| t.put_cell(row, col, c, true))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_remove_cell(tbl: *mut FfiTable, row: usize, col: usize) -> bool {
+    with_table(tbl, |t| t.remove_cell(row, col))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_swap_cells(
+    tbl: *mut FfiTable,
+    row: usize,
+    a: usize,
+    b: usize,
+) -> bool {
+    with_table(tbl, |t| t.swap_cells(row, a, b))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_truncate_cells(tbl: *mut FfiTable, row: usize, len: usize) -> bool {
+    with_table(tbl, |t| t.truncate_cells(row, len))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_clear_cells(tbl: *mut FfiTable, row: usize) -> bool {
+    with_table(tbl, |t| t.truncate_cells(row, 0))
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_cell_markup(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    markup_utf8: *const c_char,
+) -> bool {
+    with_table(tbl, |t| {
+        markup_str_from_c(markup_utf8)
+            .is_some_and(|m| t.put_cell(row, col, CellData::Lines(parse_markup(m)), true))
+    })
+}
+
+// The cell follows later edits of the FfiText (the table switches to rich cell storage).
+#[no_mangle]
+pub extern "C" fn ratatui_table_replace_cell_text(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    txt: *const FfiText,
+) -> bool {
+    with_table(tbl, |t| {
+        shared_from_ffi(txt).is_some_and(|st| t.put_cell(row, col, CellData::Text(st), true))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_table_insert_cell_text(
+    tbl: *mut FfiTable,
+    row: usize,
+    col: usize,
+    txt: *const FfiText,
+) -> bool {
+    with_table(tbl, |t| {
+        shared_from_ffi(txt).is_some_and(|st| t.put_cell(row, col, CellData::Text(st), false))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    const PLAIN: FfiStyle = FfiStyle {
+        fg: 0,
+        bg: 0,
+        mods: 0,
+    };
+
+    fn table(rows: &[&str]) -> *mut FfiTable {
+        let t = ratatui_table_new();
+        for r in rows {
+            let tsv = CString::new(*r).unwrap();
+            ratatui_table_append_row(t, tsv.as_ptr());
+        }
+        t
+    }
+
+    // Cell text of every row in whichever storage is active.
+    fn cells(t: *mut FfiTable) -> Vec<Vec<String>> {
+        let tb = unsafe { &*t };
+        if let Some(rc) = &tb.rows_cells_lines {
+            rc.iter()
+                .map(|r| {
+                    r.iter()
+                        .map(|c| match c {
+                            TableCell::Lines(ls) => lines_to_plain(ls),
+                            TableCell::Text(t) => lines_to_plain(&read_text(t).lines),
+                        })
+                        .collect()
+                })
+                .collect()
+        } else if let Some(rs) = &tb.rows_spans {
+            rs.iter()
+                .map(|r| r.iter().map(|l| l.to_string()).collect())
+                .collect()
+        } else {
+            tb.rows.clone()
+        }
+    }
+
+    fn insert_row(t: *mut FfiTable, index: usize, tsv: &str) -> bool {
+        let tsv = CString::new(tsv).unwrap();
+        ratatui_table_insert_row(t, index, tsv.as_ptr())
+    }
+
+    #[test]
+    fn row_indices_at_and_past_the_end() {
+        let t = table(&["a", "b"]);
+        assert!(insert_row(t, 2, "c"));
+        assert!(!insert_row(t, 4, "x"));
+        assert!(insert_row(t, 0, "z"));
+        let tsv = CString::new("y").unwrap();
+        assert!(!ratatui_table_replace_row(t, 4, tsv.as_ptr()));
+        assert!(ratatui_table_replace_row(t, 3, tsv.as_ptr()));
+        assert!(!ratatui_table_remove_row(t, 4));
+        assert!(!ratatui_table_swap_rows(t, 0, 4));
+        assert!(ratatui_table_swap_rows(t, 1, 1));
+        assert_eq!(cells(t), [["z"], ["a"], ["b"], ["y"]]);
+        ratatui_table_truncate_rows(t, 10);
+        assert_eq!(ratatui_table_row_count(t), 4);
+        ratatui_table_truncate_rows(t, 1);
+        assert_eq!(cells(t), [["z"]]);
+        ratatui_table_clear_rows(t);
+        assert_eq!(ratatui_table_row_count(t), 0);
+        assert!(!ratatui_table_remove_row(t, 0));
+        assert!(insert_row(t, 0, "only"));
+        ratatui_table_free(t);
+    }
+
+    #[test]
+    fn cell_indices_at_and_past_the_end() {
+        let t = table(&["a\tb"]);
+        let x = CString::new("x").unwrap();
+        assert!(ratatui_table_insert_cell(t, 0, 2, x.as_ptr()));
+        assert!(!ratatui_table_insert_cell(t, 0, 4, x.as_ptr()));
+        assert!(!ratatui_table_insert_cell(t, 1, 0, x.as_ptr()));
+        assert!(!ratatui_table_replace_cell(t, 0, 3, x.as_ptr()));
+        assert!(!ratatui_table_remove_cell(t, 0, 3));
+        assert!(!ratatui_table_swap_cells(t, 0, 0, 3));
+        assert!(ratatui_table_swap_cells(t, 0, 0, 2));
+        assert_eq!(cells(t), [["x", "b", "a"]]);
+        assert!(ratatui_table_truncate_cells(t, 0, 9));
+        assert!(!ratatui_table_truncate_cells(t, 1, 0));
+        assert!(ratatui_table_truncate_cells(t, 0, 0));
+        assert!(ratatui_table_insert_cell(t, 0, 0, x.as_ptr()));
+        assert_eq!(cells(t), [["x"]]);
+        ratatui_table_free(t);
+    }
+
+    #[test]
+    fn richer_data_promotes_existing_rows() {
+        let t = table(&["a\tb", "c"]);
+        let text = CString::new("s").unwrap();
+        let span = FfiSpan {
+            text_utf8: text.as_ptr(),
+            style: PLAIN,
+        };
+        assert!(ratatui_table_replace_cell_spans(t, 1, 0, &span, 1));
+        assert!(unsafe { &*t }.rows_spans.is_some());
+        assert_eq!(cells(t), [vec!["a", "b"], vec!["s"]]);
+        let line = FfiLineSpans {
+            spans: &span,
+            len: 1,
+        };
+        assert!(ratatui_table_insert_cell_lines(t, 0, 1, &line, 1));
+        assert!(unsafe { &*t }.rows_cells_lines.is_some());
+        assert_eq!(cells(t), [vec!["a", "s", "b"], vec!["s"]]);
+        // plain text into richer storage keeps that storage
+        assert!(insert_row(t, 2, "p\tq"));
+        assert_eq!(cells(t)[2], ["p", "q"]);
+        ratatui_table_free(t);
+    }
+}
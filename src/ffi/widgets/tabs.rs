@@ -1,8 +1,10 @@
+use crate::ffi::markup::{markup_str_from_c, parse_markup_line};
+use crate::ffi::theme::StyleRef;
+use crate::ffi::widgets::block::BlockExtras;
 use crate::*;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::{Line, Span};
-use ratatui::style::Style;
 use ratatui::widgets::{Block, Tabs};
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
@@ -12,8 +14,9 @@ pub struct FfiTabs {
     pub titles: Vec<String>,
     pub selected: u16,
     pub block: Option<Block<'static>>,
-    pub unselected_style: Option<Style>,
-    pub selected_style: Option<Style>,
+    pub block_extras: BlockExtras,
+    pub unselected_style: Option<StyleRef>,
+    pub selected_style: Option<StyleRef>,
     pub divider: Option<String>,
     pub divider_span: Option<Span<'static>>,
     pub titles_spans: Option<Vec<Line<'static>>>,
@@ -31,6 +34,7 @@ pub extern "C" fn ratatui_tabs_new() -> *mut FfiTabs {
         titles: Vec::new(),
         selected: 0,
         block: None,
+        block_extras: BlockExtras::default(),
         unselected_style: None,
         selected_style: None,
         divider: None,
@@ -85,6 +89,49 @@ pub extern "C" fn ratatui_tabs_add_title_spans(t: *mut FfiTabs, spans: *const Ff
     }
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_tabs_add_title_spans_ex(
+    t: *mut FfiTabs,
+    spans: *const FfiSpanEx,
+    len: usize,
+) {
+    if t.is_null() || spans.is_null() {
+        return;
+    }
+    let tt = unsafe { &mut *t };
+    if let Some(sp) = spans_from_ffi_ex(spans, len) {
+        tt.titles_spans
+            .get_or_insert_with(Vec::new)
+            .push(Line::from(sp));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ratatui_tabs_add_title_markup(t: *mut FfiTabs, markup_utf8: *const c_char) {
+    if t.is_null() {
+        return;
+    }
+    let tt = unsafe { &mut *t };
+    if let Some(m) = markup_str_from_c(markup_utf8) {
+        tt.titles_spans
+            .get_or_insert_with(Vec::new)
+            .push(parse_markup_line(m));
+    }
+}
+
+// Set all tab titles from tab-separated markup, replacing any existing titles
+#[no_mangle]
+pub extern "C" fn ratatui_tabs_set_titles_markup(t: *mut FfiTabs, tsv_markup_utf8: *const c_char) {
+    if t.is_null() {
+        return;
+    }
+    let tt = unsafe { &mut *t };
+    if let Some(m) = markup_str_from_c(tsv_markup_utf8) {
+        tt.titles.clear();
+        tt.titles_spans = Some(m.split('\t').map(parse_markup_line).collect());
+    }
+}
+
 // Set all tab titles from lines (spans per title), replacing any existing titles
 #[no_mangle]
 pub extern "C" fn ratatui_tabs_set_titles_spans(
@@ -125,8 +172,44 @@ pub extern "C" fn ratatui_tabs_set_selected(t: *mut FfiTabs, selected: u16) {
 
 crate::ratatui_block_title_fn!(ratatui_tabs_set_block_title, FfiTabs);
 crate::ratatui_block_title_spans_fn!(ratatui_tabs_set_block_title_spans, FfiTabs);
+crate::ratatui_block_title_markup_fn!(ratatui_tabs_set_block_title_markup, FfiTabs);
+
+crate::ratatui_block_title_text_fn!(ratatui_tabs_set_block_title_text, FfiTabs);
 crate::ratatui_block_title_alignment_fn!(ratatui_tabs_set_block_title_alignment, FfiTabs);
 crate::ratatui_block_adv_fn!(ratatui_tabs_set_block_adv, FfiTabs);
+crate::ratatui_block_add_title_fn!(
+    ratatui_tabs_add_block_title_spans,
+    ratatui_tabs_add_block_title_markup,
+    FfiTabs
+);
+crate::ratatui_block_title_spans_ex_fn!(
+    ratatui_tabs_set_block_title_spans_ex,
+    ratatui_tabs_add_block_title_spans_ex,
+    FfiTabs
+);
+crate::ratatui_block_style_fn!(ratatui_tabs_set_block_style, FfiTabs, style);
+crate::ratatui_block_style_fn!(ratatui_tabs_set_block_border_style, FfiTabs, border_style);
+crate::ratatui_block_style_fn!(ratatui_tabs_set_block_title_style, FfiTabs, title_style);
+crate::ratatui_block_style_ex_fn!(ratatui_tabs_set_block_style_ex, FfiTabs, style);
+crate::ratatui_block_style_ex_fn!(
+    ratatui_tabs_set_block_border_style_ex,
+    FfiTabs,
+    border_style
+);
+crate::ratatui_block_style_ex_fn!(ratatui_tabs_set_block_title_style_ex, FfiTabs, title_style);
+crate::ratatui_block_style_slot_fn!(ratatui_tabs_set_block_style_slot, FfiTabs, style);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_tabs_set_block_border_style_slot,
+    FfiTabs,
+    border_style
+);
+crate::ratatui_block_style_slot_fn!(
+    ratatui_tabs_set_block_title_style_slot,
+    FfiTabs,
+    title_style
+);
+crate::ratatui_block_border_set_fn!(ratatui_tabs_set_block_border_set, FfiTabs);
+crate::ratatui_block_set_fn!(ratatui_tabs_set_block, FfiTabs);
 
 #[no_mangle]
 pub extern "C" fn ratatui_tabs_set_styles(
@@ -138,8 +221,33 @@ pub extern "C" fn ratatui_tabs_set_styles(
         return;
     }
     let tt = unsafe { &mut *t };
-    tt.unselected_style = Some(style_from_ffi(unselected));
-    tt.selected_style = Some(style_from_ffi(selected));
+    tt.unselected_style = Some(style_from_ffi(unselected).into());
+    tt.selected_style = Some(style_from_ffi(selected).into());
+}
+
+crate::ratatui_set_style_slot_fn!(
+    ratatui_tabs_set_unselected_style_slot,
+    FfiTabs,
+    unselected_style
+);
+crate::ratatui_set_style_slot_fn!(
+    ratatui_tabs_set_selected_style_slot,
+    FfiTabs,
+    selected_style
+);
+
+#[no_mangle]
+pub extern "C" fn ratatui_tabs_set_styles_ex(
+    t: *mut FfiTabs,
+    unselected: FfiStyleEx,
+    selected: FfiStyleEx,
+) {
+    if t.is_null() {
+        return;
+    }
+    let tt = unsafe { &mut *t };
+    tt.unselected_style = Some(style_from_ffi_ex(unselected).into());
+    tt.selected_style = Some(style_from_ffi_ex(selected).into());
 }
 
 #[no_mangle]
@@ -225,10 +333,10 @@ pub extern "C" fn ratatui_terminal_draw_tabs_in(
         };
         let mut widget = Tabs::new(titles).select(tabs.selected as usize);
         if let Some(sty) = &tabs.unselected_style {
-            widget = widget.style(sty.clone());
+            widget = widget.style(sty.resolve());
         }
         if let Some(hsty) = &tabs.selected_style {
-            widget = widget.highlight_style(hsty.clone());
+            widget = widget.highlight_style(hsty.resolve());
         }
         if let Some(dsp) = &tabs.divider_span {
             widget = widget.divider(dsp.clone());
@@ -237,8 +345,8 @@ pub extern "C" fn ratatui_terminal_draw_tabs_in(
                 widget = widget.divider(Span::raw(div.clone()));
             }
         }
-        if let Some(b) = &tabs.block {
-            widget = widget.block(b.clone());
+        if let Some(b) = tabs.block_extras.apply(&tabs.block) {
+            widget = widget.block(b);
         }
         let res = termi.terminal.draw(|frame| {
             frame.render_widget(widget.clone(), area);
@@ -276,10 +384,10 @@ pub extern "C" fn ratatui_headless_render_tabs(
     };
     let mut widget = Tabs::new(titles).select(tabs.selected as usize);
     if let Some(sty) = &tabs.unselected_style {
-        widget = widget.style(sty.clone());
+        widget = widget.style(sty.resolve());
     }
     if let Some(hsty) = &tabs.selected_style {
-        widget = widget.highlight_style(hsty.clone());
+        widget = widget.highlight_style(hsty.resolve());
     }
     if let Some(dsp) = &tabs.divider_span {
         widget = widget.divider(dsp.clone());
@@ -288,8 +396,8 @@ pub extern "C" fn ratatui_headless_render_tabs(
             widget = widget.divider(Span::raw(div.clone()));
         }
     }
-    if let Some(b) = &tabs.block {
-        widget = widget.block(b.clone());
+    if let Some(b) = tabs.block_extras.apply(&tabs.block) {
+        widget = widget.block(b);
     }
     ratatui::widgets::Widget::render(widget, area, &mut buf);
     let mut s = String::new();
@@ -10,6 +10,7 @@ use crossterm::event::{
     MouseButton as CtMouseButton, MouseEventKind as CtMouseKind,
 };
 // terminal enter/exit helpers live in ffi::terminal now
+use crate::ffi::backend::ColorModeBackend;
 use ratatui::backend::CrosstermBackend;
 use ratatui::prelude::*;
 mod ffi;
@@ -46,6 +47,22 @@ pub struct FfiCellLines {
     pub len: usize,
 }
 
+// Line of `FfiSpanEx` with its own style (patched under the spans) and alignment (< 0 inherits
+// from the widget, otherwise 0 = Left, 1 = Center, 2 = Right).
+#[repr(C)]
+pub struct FfiLineSpansEx {
+    pub spans: *const FfiSpanEx,
+    pub len: usize,
+    pub style: FfiStyleEx,
+    pub alignment: i32,
+}
+
+#[repr(C)]
+pub struct FfiCellLinesEx {
+    pub lines: *const FfiLineSpansEx,
+    pub len: usize,
+}
+
 #[repr(C)]
 pub struct FfiRowCellsLines {
     pub cells: *const FfiCellLines,
@@ -53,7 +70,7 @@ pub struct FfiRowCellsLines {
 }
 #[repr(C)]
 pub struct FfiTerminal {
-    terminal: Terminal<CrosstermBackend<Stdout>>,
+    terminal: Terminal<ColorModeBackend<CrosstermBackend<Stdout>>>,
     entered_alt: bool,
     raw_mode: bool,
 }
@@ -159,6 +176,34 @@ pub struct FfiSpan {
     pub style: FfiStyle,
 }
 
+// Full ratatui Style: modifiers use the FfiStyleMods bits (widened to u32), `sub_mods` removes
+// modifiers set by an enclosing style, `reset` is a FfiStyleReset mask forcing Color::Reset /
+// removing all modifiers. Stacks with `Style::patch` semantics (base < block < line < span).
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct FfiStyleEx {
+    pub fg: u32,
+    pub bg: u32,
+    pub underline_color: u32,
+    pub add_mods: u32,
+    pub sub_mods: u32,
+    pub reset: u32,
+}
+
+#[repr(C)]
+pub struct FfiSpanEx {
+    pub text_utf8: *const c_char,
+    pub style: FfiStyleEx,
+}
+
+// Span bound to a theme slot: `style` applies on top of the slot's style (slot 0 = unbound).
+#[repr(C)]
+pub struct FfiSpanSlot {
+    pub text_utf8: *const c_char,
+    pub style: FfiStyleEx,
+    pub slot: u32,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct FfiStr {
@@ -198,6 +243,7 @@ pub enum FfiWidgetKind {
     Clear = 11,
     RatatuiLogo = 12,
     Canvas = 13,
+    Block = 14,
 }
 
 #[repr(C)]
@@ -259,6 +305,16 @@ pub enum FfiSpacing {
     Overlap = 1,
 }
 
+#[repr(u32)]
+pub enum FfiTickFormat {
+    Fixed = 0,
+    Si = 1,
+    Percent = 2,
+    Time = 3,
+    Date = 4,
+    DateTime = 5,
+}
+
 #[repr(u32)]
 pub enum FfiClearType {
     All = 0,
@@ -494,6 +550,18 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    #[repr(transparent)]
+    pub struct FfiStyleReset: u32 {
+        const NONE            = 0;
+        const FG              = 1<<0;
+        const BG              = 1<<1;
+        const UNDERLINE_COLOR = 1<<2;
+        const MODS            = 1<<3;
+        const ALL             = 0xF;
+    }
+}
+
 const __RATATUI_BRAILLE_DOTS: [[u16; 2]; 4] = symbols::braille::DOTS;
 const __RATATUI_BRAILLE_DOTS_FLAT: [u16; 8] = [
     __RATATUI_BRAILLE_DOTS[0][0],
@@ -524,6 +592,22 @@ pub extern "C" fn ratatui_color_indexed(index: u8) -> u32 {
     0x4000_0000u32 | (index as u32)
 }
 
+#[no_mangle]
+pub extern "C" fn ratatui_style_ex_from_style(style: FfiStyle) -> FfiStyleEx {
+    FfiStyleEx {
+        fg: style.fg,
+        bg: style.bg,
+        add_mods: style.mods as u32,
+        ..FfiStyleEx::default()
+    }
+}
+
+// `patch` layered over `base` (ratatui `Style::patch`).
+#[no_mangle]
+pub extern "C" fn ratatui_style_patch(base: FfiStyleEx, patch: FfiStyleEx) -> FfiStyleEx {
+    style_ex_from_style(style_from_ffi_ex(base).patch(style_from_ffi_ex(patch)))
+}
+
 #[no_mangle]
 pub extern "C" fn ratatui_ffi_version(
     out_major: *mut u32,
@@ -567,6 +651,10 @@ bitflags::bitflags! {
         const AXIS_LABELS      = 1 << 6;
         // New span-based setters for labels/dividers/titles
         const SPAN_SETTERS     = 1 << 7;
+        // Callback-backed List/Table rows (ratatui_*_set_source)
+        const VIRTUAL_SOURCES  = 1 << 8;
+        // ratatui_paragraph_append_markdown (cargo feature "markdown")
+        const MARKDOWN         = 1 << 9;
     }
 }
 
@@ -584,6 +672,11 @@ pub extern "C" fn ratatui_ffi_feature_bits() -> u32 {
     bits |= FfiFeatures::COLOR_HELPERS;
     bits |= FfiFeatures::AXIS_LABELS;
     bits |= FfiFeatures::SPAN_SETTERS;
+    bits |= FfiFeatures::VIRTUAL_SOURCES;
+    #[cfg(feature = "markdown")]
+    {
+        bits |= FfiFeatures::MARKDOWN;
+    }
     // Paragraph and Tabs batching are lightweight; not explicitly flagged.
     bits.bits()
 }